@@ -0,0 +1,95 @@
+//! os-release parser benchmark
+//! 
+//! Run with `cargo bench --bench parse`
+
+use std::time::{Duration, Instant};
+
+use whatadistro::{Distro, DistroRef};
+
+const ITERATIONS: u32 = 100_000;
+
+const DEBIAN: &str = r#"PRETTY_NAME="Debian GNU/Linux 12 (bookworm)"
+NAME="Debian GNU/Linux"
+VERSION_ID="12"
+VERSION="12 (bookworm)"
+VERSION_CODENAME=bookworm
+ID=debian
+HOME_URL="https://www.debian.org/"
+SUPPORT_URL="https://www.debian.org/support"
+BUG_REPORT_URL="https://bugs.debian.org/"
+"#;
+
+const MINT: &str = r#"NAME="Linux Mint"
+VERSION="21.3 (Virginia)"
+ID=linuxmint
+ID_LIKE="ubuntu debian"
+PRETTY_NAME="Linux Mint 21.3"
+VERSION_ID="21.3"
+HOME_URL="https://www.linuxmint.com/"
+SUPPORT_URL="https://forums.linuxmint.com/"
+BUG_REPORT_URL="http://linuxmint-troubleshooting-guide.readthedocs.io/en/latest/"
+PRIVACY_POLICY_URL="https://www.linuxmint.com/"
+VERSION_CODENAME=virginia
+UBUNTU_CODENAME=jammy
+"#;
+
+const FEDORA: &str = r#"NAME="Fedora Linux"
+VERSION="40 (Workstation Edition)"
+ID=fedora
+VERSION_ID=40
+VERSION_CODENAME=""
+PLATFORM_ID="platform:f40"
+PRETTY_NAME="Fedora Linux 40 (Workstation Edition)"
+ANSI_COLOR="0;38;2;60;110;180"
+LOGO=fedora-logo-icon
+CPE_NAME="cpe:/o:fedoraproject:fedora:40"
+DEFAULT_HOSTNAME="fedora"
+HOME_URL="https://fedoraproject.org/"
+DOCUMENTATION_URL="https://docs.fedoraproject.org/en-US/fedora/f40/system-administrators-guide/"
+SUPPORT_URL="https://ask.fedoraproject.org/"
+BUG_REPORT_URL="https://bugzilla.redhat.com/"
+REDHAT_BUGZILLA_PRODUCT="Fedora"
+REDHAT_BUGZILLA_PRODUCT_VERSION=40
+REDHAT_SUPPORT_PRODUCT="Fedora"
+REDHAT_SUPPORT_PRODUCT_VERSION=40
+SUPPORT_END=2025-05-13
+VARIANT="Workstation Edition"
+VARIANT_ID=workstation
+"#;
+
+fn bench(name: &str, content: &str, parse: fn(&str) -> Option<usize>) {
+    let mut parsed = 0;
+
+    let started = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        if let Some(len) = parse(content) {
+            parsed += len;
+        }
+    }
+
+    let elapsed = started.elapsed();
+    let per_iter = elapsed / ITERATIONS;
+
+    println!("{name:<18} {per_iter:>10?}/iter ({:?} total, {parsed} bytes of names)", elapsed);
+
+    assert!(per_iter < Duration::from_secs(1));
+}
+
+fn owned(content: &str) -> Option<usize> {
+    Distro::from_os_release(content).map(|distro| distro.name().len())
+}
+
+fn borrowed(content: &str) -> Option<usize> {
+    DistroRef::from_os_release(content).map(|distro| distro.name().len())
+}
+
+fn main() {
+    bench("debian", DEBIAN, owned);
+    bench("mint", MINT, owned);
+    bench("fedora", FEDORA, owned);
+
+    bench("debian (borrowed)", DEBIAN, borrowed);
+    bench("mint (borrowed)", MINT, borrowed);
+    bench("fedora (borrowed)", FEDORA, borrowed);
+}
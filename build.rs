@@ -0,0 +1,112 @@
+use std::path::Path;
+
+/// Environment variables used to describe static target distro
+const VARIABLES: &[&str] = &[
+    "WHATADISTRO_STATIC_OS_RELEASE",
+    "WHATADISTRO_STATIC_ID",
+    "WHATADISTRO_STATIC_NAME",
+    "WHATADISTRO_STATIC_VERSION_ID"
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    if std::env::var_os("CARGO_FEATURE_STATIC_TARGET").is_none() {
+        return;
+    }
+
+    for variable in VARIABLES {
+        println!("cargo:rerun-if-env-changed={variable}");
+    }
+
+    let content = static_os_release();
+
+    match &content {
+        Some(content) => {
+            if let Err(err) = validate(content) {
+                panic!("Invalid static target distro: {err}");
+            }
+        }
+
+        None => println!("cargo:warning=static-target feature is enabled but WHATADISTRO_STATIC_ID is not set, runtime detection will be used")
+    }
+
+    let out_dir = std::env::var("OUT_DIR")
+        .expect("OUT_DIR variable is not set");
+
+    std::fs::write(
+        Path::new(&out_dir).join("static_target.rs"),
+        format!("pub(crate) const STATIC_OS_RELEASE: Option<&str> = {content:?};\n")
+    ).expect("Failed to write static target file");
+}
+
+/// Build os-release file content from the environment variables
+fn static_os_release() -> Option<String> {
+    if let Ok(path) = std::env::var("WHATADISTRO_STATIC_OS_RELEASE") {
+        println!("cargo:rerun-if-changed={path}");
+
+        let content = std::fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("Failed to read static os-release file {path}: {err}"));
+
+        return Some(content);
+    }
+
+    let id = std::env::var("WHATADISTRO_STATIC_ID").ok()?;
+
+    let mut content = format!("ID={id}\n");
+
+    if let Ok(name) = std::env::var("WHATADISTRO_STATIC_NAME") {
+        content.push_str(&format!("NAME={}\n", quote(&name)));
+    }
+
+    if let Ok(version_id) = std::env::var("WHATADISTRO_STATIC_VERSION_ID") {
+        content.push_str(&format!("VERSION_ID={}\n", quote(&version_id)));
+    }
+
+    Some(content)
+}
+
+/// Check that os-release content has a valid `ID` field
+/// 
+/// Build fails on malformed values so the embedded
+/// distro never falls back to runtime detection
+fn validate(content: &str) -> Result<(), String> {
+    // Later values override earlier ones
+    let id = content.lines()
+        .rev()
+        .find_map(|line| line.trim().strip_prefix("ID="))
+        .map(|id| id.trim().trim_matches(|char| char == '"' || char == '\''))
+        .ok_or_else(|| String::from("ID field is missing"))?;
+
+    if id.is_empty() {
+        return Err(String::from("ID field is empty"));
+    }
+
+    // Allowed characters according to the os-release spec
+    let is_valid = id.chars().all(|char| {
+        char.is_ascii_lowercase() || char.is_ascii_digit() || matches!(char, '.' | '_' | '-')
+    });
+
+    if !is_valid {
+        return Err(format!("ID value {id:?} must contain only 0-9, a-z, '.', '_' and '-' characters"));
+    }
+
+    Ok(())
+}
+
+/// Double quote os-release value
+fn quote(value: &str) -> String {
+    let mut quoted = String::from("\"");
+
+    for char in value.chars() {
+        if matches!(char, '"' | '\\' | '$' | '`') {
+            quoted.push('\\');
+        }
+
+        quoted.push(char);
+    }
+
+    quoted.push('"');
+
+    quoted
+}
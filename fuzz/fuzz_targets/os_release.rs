@@ -0,0 +1,42 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use whatadistro::{Distro, DistroId, AnsiColor, Cpe, Date, GraphicsApi, GpuVendor};
+use whatadistro::{cmdline, envfile, repos, security};
+
+fuzz_target!(|data: &[u8]| {
+    if let Some(distro) = Distro::from_os_release_bytes(data) {
+        // Accessors must never panic on parsed values
+        let _ = distro.name();
+        let _ = distro.similar_ids();
+        let _ = distro.vendor_fields().count();
+        let _ = distro.cpe();
+        let _ = distro.support_end();
+        let _ = distro.codename();
+        let _ = distro.release_channel();
+        let _ = distro.eol_date();
+        let _ = distro.warnings();
+        let _ = distro.version_number();
+        let _ = distro.release_model();
+        let _ = distro.family();
+        let _ = distro.platform_id();
+        let _ = distro.kernel_headers_package("6.1.0-13-amd64");
+        let _ = distro.graphics_packages(GraphicsApi::Vulkan, GpuVendor::Amd, true);
+    }
+
+    if let Ok(content) = std::str::from_utf8(data) {
+        let _ = Distro::from_lsb_release(content);
+        let _ = DistroId::from(content);
+        let _ = AnsiColor::parse(content);
+        let _ = Cpe::parse(content);
+        let _ = Date::parse(content);
+        let _ = envfile::parse(content);
+        let _ = cmdline::Cmdline::parse(content);
+        let _ = repos::parse_apt_sources(content);
+        let _ = repos::parse_apt_deb822(content);
+        let _ = repos::parse_repo_file(content);
+        let _ = repos::parse_pacman_conf(content);
+        let _ = security::SelinuxStatus::from_files(Some(content), Some(content));
+    }
+});
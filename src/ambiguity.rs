@@ -0,0 +1,114 @@
+use std::fmt::Display;
+use std::sync::Arc;
+
+use crate::{Distro, DistroId, Confidence, Source};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Why the identification result is ambiguous
+pub enum AmbiguityReason {
+    /// os-release and lsb-release files describe different distros
+    SourcesDisagree,
+
+    /// Distro was guessed from legacy release files
+    /// or package databases (see `Confidence::Heuristic`)
+    Heuristic
+}
+
+impl Display for AmbiguityReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SourcesDisagree => write!(f, "distro sources disagree"),
+            Self::Heuristic       => write!(f, "distro was guessed heuristically")
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Ambiguous identification result passed to the `IdentifyOptions::on_ambiguous` callback
+pub struct Ambiguity {
+    distro: Distro,
+    candidates: Vec<DistroId>,
+    reason: AmbiguityReason
+}
+
+impl Ambiguity {
+    #[inline]
+    /// Get distro which is returned if the callback doesn't pick another one
+    pub fn distro(&self) -> &Distro {
+        &self.distro
+    }
+
+    #[inline]
+    /// Get distros the system could be, the best guess first
+    pub fn candidates(&self) -> &[DistroId] {
+        &self.candidates
+    }
+
+    #[inline]
+    /// Get why the result is ambiguous
+    pub fn reason(&self) -> AmbiguityReason {
+        self.reason
+    }
+}
+
+/// Function called with ambiguous identification result
+pub(crate) type CallbackFn = dyn Fn(&Ambiguity) -> Option<DistroId> + Send + Sync;
+
+#[derive(Clone)]
+/// Ambiguity callback which can be stored in the derive-friendly options
+pub(crate) struct Callback(pub(crate) Arc<CallbackFn>);
+
+impl std::fmt::Debug for Callback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Callback")
+    }
+}
+
+impl PartialEq for Callback {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Callback {}
+
+/// Check if the identified distro is ambiguous
+/// 
+/// Sources are usually returned by `identify_all()`.
+/// Distro set by `set_override` is never ambiguous
+pub(crate) fn detect(distro: &Distro, sources: &[(Source, Distro)]) -> Option<Ambiguity> {
+    if sources.iter().any(|(source, _)| source == &Source::Override) {
+        return None;
+    }
+
+    let mut candidates = vec![distro.id().clone()];
+
+    for (_, source) in sources {
+        if !candidates.contains(source.id()) {
+            candidates.push(source.id().clone());
+        }
+    }
+
+    if candidates.len() > 1 {
+        trace!("distro sources disagree: {candidates:?}");
+
+        return Some(Ambiguity {
+            distro: distro.clone(),
+            candidates,
+            reason: AmbiguityReason::SourcesDisagree
+        });
+    }
+
+    if distro.confidence() == Confidence::Heuristic {
+        trace!("distro was guessed heuristically, asking for confirmation");
+
+        return Some(Ambiguity {
+            distro: distro.clone(),
+            candidates: distro.id().list_similar(),
+            reason: AmbiguityReason::Heuristic
+        });
+    }
+
+    None
+}
@@ -0,0 +1,168 @@
+use std::path::Path;
+
+use crate::{Distro, DistroFamily, FileSystem, RealFs};
+use crate::privilege::in_path_with_fs;
+use crate::repos::{self, Repository, read_dir_files};
+
+/// Directory with additional apt sources
+const SOURCES_DIR: &str = "/etc/apt/sources.list.d";
+
+/// Directory with apt configuration snippets
+const CONF_DIR: &str = "/etc/apt/apt.conf.d";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Debian family specific apt configuration info
+pub struct AptInfo {
+    deb822: bool,
+    one_line: bool,
+    add_apt_repository: bool,
+    default_release: Option<String>
+}
+
+impl AptInfo {
+    #[inline]
+    /// Check if enabled deb822 sources (`/etc/apt/sources.list.d/*.sources`)
+    /// are in use (default since Ubuntu 24.04 and Debian 13)
+    pub fn uses_deb822(&self) -> bool {
+        self.deb822
+    }
+
+    #[inline]
+    /// Check if enabled one-line sources (`/etc/apt/sources.list` and `*.list` files)
+    /// are in use
+    /// 
+    /// Commented `#deb` lines left after migrating to deb822 sources are not counted
+    pub fn uses_one_line(&self) -> bool {
+        self.one_line
+    }
+
+    #[inline]
+    /// Check if `add-apt-repository` is available in `PATH`
+    /// 
+    /// It's shipped with `software-properties-common` package
+    /// and is required to add Ubuntu PPAs
+    pub fn has_add_apt_repository(&self) -> bool {
+        self.add_apt_repository
+    }
+
+    #[inline]
+    /// Get configured default release (`APT::Default-Release` option)
+    pub fn default_release(&self) -> Option<&str> {
+        self.default_release.as_deref()
+    }
+}
+
+/// Find `APT::Default-Release` value in apt config content
+/// 
+/// The last value wins
+fn default_release(content: &str) -> Option<String> {
+    content.lines()
+        .rev()
+        .map(str::trim)
+        .filter(|line| !line.starts_with("//") && !line.starts_with('#'))
+        .find_map(|line| {
+            let (key, value) = line.split_once(char::is_whitespace)?;
+
+            if !key.eq_ignore_ascii_case("APT::Default-Release") {
+                return None;
+            }
+
+            let value = value.trim()
+                .trim_end_matches(';')
+                .trim()
+                .trim_matches('"');
+
+            Some(value.to_string()).filter(|value| !value.is_empty())
+        })
+}
+
+/// Read apt config files, in the order apt reads them
+fn config_files(fs: &impl FileSystem) -> Vec<String> {
+    let mut files = Vec::new();
+
+    if let Ok(content) = fs.read_to_string(Path::new("/etc/apt/apt.conf")) {
+        files.push(content);
+    }
+
+    if let Ok(mut paths) = fs.read_dir(Path::new(CONF_DIR)) {
+        paths.sort();
+
+        // Directories fail to be read and are skipped
+        files.extend(paths.into_iter().filter_map(|path| fs.read_to_string(&path).ok()));
+    }
+
+    files
+}
+
+impl Distro {
+    /// Get Debian family specific apt configuration info
+    /// 
+    /// Returns `None` for distros from other families
+    /// 
+    /// ```
+    /// if let Some(apt) = whatadistro::identify().and_then(|distro| distro.apt_info()) {
+    ///     if apt.has_add_apt_repository() {
+    ///         println!("sudo add-apt-repository ppa:example/ppa");
+    ///     }
+    /// 
+    ///     else if apt.uses_deb822() {
+    ///         println!("Create /etc/apt/sources.list.d/example.sources");
+    ///     }
+    /// 
+    ///     else {
+    ///         println!("Create /etc/apt/sources.list.d/example.list");
+    ///     }
+    /// }
+    /// ```
+    pub fn apt_info(&self) -> Option<AptInfo> {
+        self.apt_info_with_fs(&RealFs)
+    }
+
+    /// Get apt configuration info using files of the given filesystem
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, MockFs};
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Debian\nID=debian").unwrap();
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/etc/apt/sources.list.d/debian.sources", "Types: deb\nURIs: http://deb.debian.org/debian\nSuites: trixie\nComponents: main")
+    ///     .with_file("/etc/apt/apt.conf.d/99release", "APT::Default-Release \"trixie\";")
+    ///     .with_file("/etc/apt/sources.list", "#deb http://deb.debian.org/debian bookworm main");
+    /// 
+    /// let apt = distro.apt_info_with_fs(&fs).unwrap();
+    /// 
+    /// assert!(apt.uses_deb822());
+    /// assert!(!apt.uses_one_line());
+    /// assert_eq!(apt.default_release(), Some("trixie"));
+    /// ```
+    pub fn apt_info_with_fs(&self, fs: &impl FileSystem) -> Option<AptInfo> {
+        if self.family() != DistroFamily::Debian {
+            return None;
+        }
+
+        let deb822 = read_dir_files(fs, SOURCES_DIR, "sources")
+            .iter()
+            .any(|content| repos::parse_apt_deb822(content).iter().any(Repository::is_enabled));
+
+        let one_line = fs.read_to_string(Path::new("/etc/apt/sources.list")).into_iter()
+            .chain(read_dir_files(fs, SOURCES_DIR, "list"))
+            .any(|content| repos::parse_apt_sources(&content).iter().any(Repository::is_enabled));
+
+        let default_release = config_files(fs)
+            .iter()
+            .rev()
+            .find_map(|content| default_release(content));
+
+        if let Some(release) = &default_release {
+            trace!("apt default release: {release}");
+        }
+
+        Some(AptInfo {
+            deb822,
+            one_line,
+            add_apt_repository: in_path_with_fs(fs, "add-apt-repository"),
+            default_release
+        })
+    }
+}
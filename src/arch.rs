@@ -0,0 +1,159 @@
+use std::fmt::Display;
+use std::path::Path;
+
+use crate::{Distro, DistroFamily, FileSystem, RealFs};
+use crate::prefix::multilib_enabled;
+use crate::privilege::in_path_with_fs;
+
+/// Directories with pacman hooks, user hooks override packaged ones
+const HOOK_DIRS: &[&str] = &[
+    "/usr/share/libalpm/hooks",
+    "/etc/pacman.d/hooks"
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Helper used to build and install packages from the AUR
+pub enum AurHelper {
+    Yay,
+    Paru,
+    Pikaur,
+    Trizen,
+    Aura,
+
+    /// Pamac with AUR support (Manjaro)
+    Pamac
+}
+
+impl AurHelper {
+    /// Helpers in order of preference
+    pub const KNOWN: &'static [Self] = &[
+        Self::Paru,
+        Self::Yay,
+        Self::Pikaur,
+        Self::Trizen,
+        Self::Aura,
+        Self::Pamac
+    ];
+
+    /// Get binary name of the helper
+    pub const fn binary(&self) -> &'static str {
+        match self {
+            Self::Yay    => "yay",
+            Self::Paru   => "paru",
+            Self::Pikaur => "pikaur",
+            Self::Trizen => "trizen",
+            Self::Aura   => "aura",
+            Self::Pamac  => "pamac"
+        }
+    }
+}
+
+impl Display for AurHelper {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.binary())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Arch family specific system info
+pub struct ArchInfo {
+    aur_helper: Option<AurHelper>,
+    multilib_enabled: bool,
+    hooks: Vec<String>
+}
+
+impl ArchInfo {
+    #[inline]
+    /// Get installed AUR helper, most preferred first (see `AurHelper::KNOWN`)
+    pub fn aur_helper(&self) -> Option<AurHelper> {
+        self.aur_helper
+    }
+
+    #[inline]
+    /// Check if `multilib` repository is enabled in `/etc/pacman.conf`
+    pub fn multilib_enabled(&self) -> bool {
+        self.multilib_enabled
+    }
+
+    #[inline]
+    /// Get names of the installed pacman hooks (`nvidia.hook`), sorted
+    pub fn hooks(&self) -> &[String] {
+        &self.hooks
+    }
+
+    #[inline]
+    /// Check if pacman hook with given file name is installed
+    pub fn has_hook(&self, name: &str) -> bool {
+        self.hooks.iter().any(|hook| hook == name)
+    }
+}
+
+/// List `.hook` files in the pacman hook directories
+fn hooks(fs: &impl FileSystem) -> Vec<String> {
+    let mut hooks = HOOK_DIRS.iter()
+        .filter_map(|dir| fs.read_dir(Path::new(dir)).ok())
+        .flatten()
+        .filter(|entry| entry.extension().map(|extension| extension == "hook").unwrap_or(false))
+        .filter_map(|entry| Some(entry.file_name()?.to_string_lossy().into_owned()))
+        .collect::<Vec<_>>();
+
+    hooks.sort();
+    hooks.dedup();
+
+    hooks
+}
+
+impl Distro {
+    /// Get Arch family specific system info
+    /// 
+    /// Returns `None` for distros from other families. AUR helpers
+    /// are searched in `PATH`
+    /// 
+    /// ```
+    /// if let Some(arch) = whatadistro::identify().and_then(|distro| distro.arch_info()) {
+    ///     match arch.aur_helper() {
+    ///         Some(helper) => println!("Install with: {helper} -S package"),
+    ///         None => println!("Install from the AUR manually")
+    ///     }
+    /// }
+    /// ```
+    pub fn arch_info(&self) -> Option<ArchInfo> {
+        self.arch_info_with_fs(&RealFs)
+    }
+
+    /// Get Arch family specific system info using files of the given filesystem
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, MockFs};
+    /// 
+    /// let distro = Distro::from_os_release("NAME=\"Arch Linux\"\nID=arch").unwrap();
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/usr/share/libalpm/hooks/60-mkinitcpio-remove.hook", "[Trigger]\n");
+    /// 
+    /// let arch = distro.arch_info_with_fs(&fs).unwrap();
+    /// 
+    /// assert!(arch.has_hook("60-mkinitcpio-remove.hook"));
+    /// assert!(!arch.multilib_enabled());
+    /// ```
+    pub fn arch_info_with_fs(&self, fs: &impl FileSystem) -> Option<ArchInfo> {
+        if self.family() != DistroFamily::Arch {
+            return None;
+        }
+
+        let aur_helper = AurHelper::KNOWN.iter()
+            .find(|helper| in_path_with_fs(fs, helper.binary()))
+            .copied();
+
+        if let Some(helper) = aur_helper {
+            trace!("detected AUR helper: {helper}");
+        }
+
+        Some(ArchInfo {
+            aur_helper,
+            multilib_enabled: multilib_enabled(fs),
+            hooks: hooks(fs)
+        })
+    }
+}
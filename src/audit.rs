@@ -0,0 +1,478 @@
+//! Host diagnostic info for bug reports and support requests
+//! 
+//! ```
+//! use whatadistro::audit::{self, AuditOptions};
+//! 
+//! let bundle = audit::collect();
+//! 
+//! // Print human readable report
+//! println!("{bundle}");
+//! 
+//! // Or embed it into a JSON payload
+//! let json = bundle.to_json();
+//! 
+//! // Hostname and machine id are redacted by default
+//! assert!(bundle.hostname().is_none());
+//! 
+//! let bundle = audit::collect_with(&AuditOptions::default().hostname(true));
+//! ```
+
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::path::Path;
+
+use crate::{
+    InitSystem,
+    Virtualization,
+    Sandbox,
+    DisplayServer,
+    Runtime,
+    SystemInfo,
+    FileSystem,
+    RealFs,
+    detect_init_system,
+    detect_virtualization,
+    detect_sandbox,
+    detect_display_server,
+    identify,
+    SchemaError,
+    SCHEMA_VERSION
+};
+
+use crate::graph::escape;
+use crate::json::{self, Value};
+use crate::schema;
+use crate::security::{self, SelinuxMode};
+
+/// Files created by container engines
+const CONTAINER_FILES: &[(&str, &str)] = &[
+    ("/.dockerenv", "docker"),
+    ("/run/.containerenv", "podman")
+];
+
+/// File name prefix of the musl dynamic loader
+const MUSL_LOADER_PREFIX: &str = "ld-musl-";
+
+/// Init systems known to the schema
+const INIT_SYSTEMS: &[InitSystem] = &[
+    InitSystem::Systemd,
+    InitSystem::OpenRC,
+    InitSystem::Runit,
+    InitSystem::S6,
+    InitSystem::Dinit,
+    InitSystem::SysVinit
+];
+
+/// Hypervisors known to the schema
+const VIRTUALIZATIONS: &[Virtualization] = &[
+    Virtualization::BareMetal,
+    Virtualization::Kvm,
+    Virtualization::Qemu,
+    Virtualization::Amazon,
+    Virtualization::Google,
+    Virtualization::VMware,
+    Virtualization::HyperV,
+    Virtualization::VirtualBox,
+    Virtualization::Xen,
+    Virtualization::Bochs,
+    Virtualization::Parallels,
+    Virtualization::Bhyve,
+    Virtualization::Apple
+];
+
+/// Sandboxes known to the schema
+const SANDBOXES: &[Sandbox] = &[
+    Sandbox::Flatpak,
+    Sandbox::Snap,
+    Sandbox::AppImage
+];
+
+/// Display servers known to the schema
+const DISPLAY_SERVERS: &[DisplayServer] = &[
+    DisplayServer::Wayland,
+    DisplayServer::X11
+];
+
+/// SELinux modes known to the schema
+const SELINUX_MODES: &[SelinuxMode] = &[
+    SelinuxMode::Enforcing,
+    SelinuxMode::Permissive
+];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+/// Values included into the audit bundle
+/// 
+/// Identifying values (hostname and machine id) are
+/// excluded by default
+/// 
+/// ```
+/// use whatadistro::audit::{self, AuditOptions};
+/// 
+/// let options = AuditOptions::default()
+///     .hostname(true)
+///     .machine_id(true);
+/// 
+/// println!("{}", audit::collect_with(&options));
+/// ```
+pub struct AuditOptions {
+    hostname: bool,
+    machine_id: bool
+}
+
+impl AuditOptions {
+    #[inline]
+    /// Include hostname into the bundle
+    pub fn hostname(mut self, include: bool) -> Self {
+        self.hostname = include;
+
+        self
+    }
+
+    #[inline]
+    /// Include machine id into the bundle
+    pub fn machine_id(mut self, include: bool) -> Self {
+        self.machine_id = include;
+
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Diagnostic info about the current host
+pub struct AuditBundle {
+    distro: Option<String>,
+    distro_id: Option<String>,
+    version: Option<String>,
+    kernel: Option<String>,
+    arch: Cow<'static, str>,
+    init: InitSystem,
+    virtualization: Virtualization,
+    sandbox: Sandbox,
+    container: Option<String>,
+    libc: Option<String>,
+    desktop: Option<String>,
+    display_server: DisplayServer,
+    selinux: SelinuxMode,
+    apparmor: bool,
+    hostname: Option<String>,
+    machine_id: Option<String>
+}
+
+impl AuditBundle {
+    #[inline]
+    /// Get distro name (`PRETTY_NAME` or `NAME` entry)
+    pub fn distro(&self) -> Option<&str> {
+        self.distro.as_deref()
+    }
+
+    #[inline]
+    /// Get distro id (`ID` entry)
+    pub fn distro_id(&self) -> Option<&str> {
+        self.distro_id.as_deref()
+    }
+
+    #[inline]
+    /// Get distro version (`VERSION_ID` entry)
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    #[inline]
+    /// Get kernel release (e.g. `6.9.1-arch1-1`)
+    pub fn kernel(&self) -> Option<&str> {
+        self.kernel.as_deref()
+    }
+
+    #[inline]
+    /// Get CPU architecture the program is compiled for (e.g. `x86_64`)
+    pub fn arch(&self) -> &str {
+        &self.arch
+    }
+
+    #[inline]
+    /// Get init system
+    pub fn init(&self) -> InitSystem {
+        self.init
+    }
+
+    #[inline]
+    /// Get hypervisor the system is running in
+    pub fn virtualization(&self) -> Virtualization {
+        self.virtualization
+    }
+
+    #[inline]
+    /// Get sandbox the process is running in
+    pub fn sandbox(&self) -> Sandbox {
+        self.sandbox
+    }
+
+    #[inline]
+    /// Get container engine the process is running in (`docker`, `podman`)
+    pub fn container(&self) -> Option<&str> {
+        self.container.as_deref()
+    }
+
+    #[inline]
+    /// Get C library name and version (`glibc 2.39`, `musl`)
+    pub fn libc(&self) -> Option<&str> {
+        self.libc.as_deref()
+    }
+
+    #[inline]
+    /// Get current desktop environment (`XDG_CURRENT_DESKTOP` variable)
+    pub fn desktop(&self) -> Option<&str> {
+        self.desktop.as_deref()
+    }
+
+    #[inline]
+    /// Get display server of the current session
+    pub fn display_server(&self) -> DisplayServer {
+        self.display_server
+    }
+
+    #[inline]
+    /// Get SELinux mode
+    pub fn selinux(&self) -> SelinuxMode {
+        self.selinux
+    }
+
+    #[inline]
+    /// Check if AppArmor is enabled
+    pub fn apparmor(&self) -> bool {
+        self.apparmor
+    }
+
+    #[inline]
+    /// Get hostname, `None` if redacted
+    pub fn hostname(&self) -> Option<&str> {
+        self.hostname.as_deref()
+    }
+
+    #[inline]
+    /// Get machine id, `None` if redacted
+    pub fn machine_id(&self) -> Option<&str> {
+        self.machine_id.as_deref()
+    }
+
+    /// Get bundle values as key-value pairs
+    /// 
+    /// ```
+    /// let bundle = whatadistro::audit::collect();
+    /// 
+    /// for (key, value) in bundle.entries() {
+    ///     println!("{key} = {}", value.unwrap_or_default());
+    /// }
+    /// ```
+    pub fn entries(&self) -> Vec<(&'static str, Option<String>)> {
+        vec![
+            ("distro", self.distro.clone()),
+            ("distro_id", self.distro_id.clone()),
+            ("version", self.version.clone()),
+            ("kernel", self.kernel.clone()),
+            ("arch", Some(self.arch.to_string())),
+            ("init", Some(self.init.to_string())),
+            ("virtualization", Some(self.virtualization.to_string())),
+            ("sandbox", Some(self.sandbox.to_string())),
+            ("container", self.container.clone()),
+            ("libc", self.libc.clone()),
+            ("desktop", self.desktop.clone()),
+            ("display_server", Some(self.display_server.to_string())),
+            ("selinux", Some(self.selinux.to_string())),
+            ("apparmor", Some(self.apparmor.to_string())),
+            ("hostname", self.hostname.clone()),
+            ("machine_id", self.machine_id.clone())
+        ]
+    }
+
+    /// Serialize bundle into a JSON object
+    /// 
+    /// Missing and redacted values are `null`. Object contains
+    /// `schema_version` field and can be read back by `parse_versioned`
+    /// 
+    /// ```
+    /// let json = whatadistro::audit::collect().to_json();
+    /// 
+    /// assert!(json.starts_with("{\"schema_version\":1,"));
+    /// assert!(json.contains("\"hostname\":null"));
+    /// ```
+    pub fn to_json(&self) -> String {
+        let entries = self.entries()
+            .into_iter()
+            .map(|(key, value)| match (key, value) {
+                ("apparmor", _) => format!("\"{key}\":{}", self.apparmor),
+
+                (_, Some(value)) => format!("\"{key}\":\"{}\"", escape(&value)),
+                (_, None) => format!("\"{key}\":null")
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!("{{\"schema_version\":{SCHEMA_VERSION},{entries}}}")
+    }
+}
+
+impl Display for AuditBundle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let entries = self.entries();
+
+        let width = entries.iter()
+            .map(|(key, _)| key.len())
+            .max()
+            .unwrap_or_default();
+
+        for (i, (key, value)) in entries.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            write!(f, "{key:width$}  {}", value.as_deref().unwrap_or("unknown"))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Detect container engine the process is running in
+fn detect_container(fs: &impl FileSystem) -> Option<String> {
+    // Set by systemd-nspawn, podman, lxc and others
+    if let Ok(container) = std::env::var("container") {
+        if !container.is_empty() {
+            return Some(container);
+        }
+    }
+
+    CONTAINER_FILES.iter()
+        .find(|(path, _)| fs.exists(Path::new(path)))
+        .map(|(_, engine)| engine.to_string())
+}
+
+/// Detect C library name and version
+fn detect_libc(fs: &impl FileSystem) -> Option<String> {
+    let musl = ["/lib", "/usr/lib"].iter().any(|dir| {
+        fs.read_dir(Path::new(dir))
+            .map(|entries| {
+                entries.iter().any(|entry| {
+                    entry.file_name()
+                        .map(|name| name.to_string_lossy().starts_with(MUSL_LOADER_PREFIX))
+                        .unwrap_or(false)
+                })
+            })
+            .unwrap_or(false)
+    });
+
+    if musl {
+        return Some(String::from("musl"));
+    }
+
+    // Version of glibc the process is linked with is preferred
+    Runtime::Glibc.detect_version()
+        .map(|version| format!("glibc {version}"))
+}
+
+#[inline]
+/// Collect diagnostic info with default options
+pub fn collect() -> AuditBundle {
+    collect_with(&AuditOptions::default())
+}
+
+/// Collect diagnostic info about the current host
+/// 
+/// May spawn `ldd` to detect glibc version if the process is not linked with glibc
+pub fn collect_with(options: &AuditOptions) -> AuditBundle {
+    let distro = identify();
+
+    let fs = RealFs;
+
+    let kernel = fs.read_to_string(Path::new("/proc/sys/kernel/osrelease")).ok()
+        .map(|kernel| kernel.trim().to_string());
+
+    let desktop = std::env::var("XDG_CURRENT_DESKTOP").ok()
+        .or_else(|| std::env::var("DESKTOP_SESSION").ok())
+        .filter(|desktop| !desktop.is_empty());
+
+    AuditBundle {
+        distro: distro.as_ref().map(|distro| distro.pretty_name().unwrap_or(distro.name()).to_string()),
+        distro_id: distro.as_ref().map(|distro| distro.id().to_string()),
+        version: distro.as_ref().and_then(|distro| distro.version_id()).map(String::from),
+        kernel,
+        arch: Cow::Borrowed(std::env::consts::ARCH),
+        init: detect_init_system(),
+        virtualization: detect_virtualization(),
+        sandbox: detect_sandbox(),
+        container: detect_container(&fs),
+        libc: detect_libc(&fs),
+        desktop,
+        display_server: detect_display_server(),
+        selinux: security::selinux().mode(),
+        apparmor: security::apparmor().is_enabled(),
+        hostname: options.hostname.then(SystemInfo::hostname).flatten(),
+        machine_id: options.machine_id.then(SystemInfo::machine_id).flatten()
+    }
+}
+
+/// Find enum variant by its `Display` value, falling back to the default one
+/// 
+/// Values written by newer versions of the crate are read as the fallback
+fn parse_variant<T: Display + Copy>(value: Option<&str>, variants: &[T], fallback: T) -> T {
+    value.and_then(|value| variants.iter().find(|variant| variant.to_string() == value))
+        .copied()
+        .unwrap_or(fallback)
+}
+
+/// Read audit bundle serialized by `AuditBundle::to_json`
+/// 
+/// Bundles of all the schema versions up to `SCHEMA_VERSION`
+/// are supported. Values unknown to the current version of
+/// the crate are read as unknown
+/// 
+/// ```
+/// use whatadistro::audit;
+/// use whatadistro::{InitSystem, SchemaError};
+/// 
+/// let bundle = audit::collect();
+/// 
+/// assert_eq!(audit::parse_versioned(&bundle.to_json()), Ok(bundle));
+/// 
+/// let bundle = audit::parse_versioned(r#"{"schema_version":1,"distro":"Arch Linux","arch":"x86_64","init":"systemd","apparmor":false}"#).unwrap();
+/// 
+/// assert_eq!(bundle.init(), InitSystem::Systemd);
+/// 
+/// assert_eq!(audit::parse_versioned(r#"{"distro":"Arch Linux","arch":"x86_64"}"#), Err(SchemaError::Malformed));
+/// assert_eq!(audit::parse_versioned(r#"{"schema_version":999}"#), Err(SchemaError::UnsupportedVersion(999)));
+/// ```
+pub fn parse_versioned(json: &str) -> Result<AuditBundle, SchemaError> {
+    let bundle = json::parse(json).ok_or(SchemaError::Malformed)?;
+
+    if !matches!(bundle, Value::Object(_)) {
+        return Err(SchemaError::Malformed);
+    }
+
+    // Only the first schema version exists so far
+    schema::version(&bundle)?;
+
+    let string = |key: &str| bundle.get(key)
+        .and_then(Value::as_str)
+        .map(String::from);
+
+    let value = |key: &str| bundle.get(key).and_then(Value::as_str);
+
+    Ok(AuditBundle {
+        distro: string("distro"),
+        distro_id: string("distro_id"),
+        version: string("version"),
+        kernel: string("kernel"),
+        arch: Cow::Owned(string("arch").ok_or(SchemaError::Malformed)?),
+        init: parse_variant(value("init"), INIT_SYSTEMS, InitSystem::Unknown),
+        virtualization: parse_variant(value("virtualization"), VIRTUALIZATIONS, Virtualization::Unknown),
+        sandbox: parse_variant(value("sandbox"), SANDBOXES, Sandbox::None),
+        container: string("container"),
+        libc: string("libc"),
+        desktop: string("desktop"),
+        display_server: parse_variant(value("display_server"), DISPLAY_SERVERS, DisplayServer::Unknown),
+        selinux: parse_variant(value("selinux"), SELINUX_MODES, SelinuxMode::Disabled),
+        apparmor: bundle.get("apparmor").and_then(Value::as_bool).unwrap_or(false),
+        hostname: string("hostname"),
+        machine_id: string("machine_id")
+    })
+}
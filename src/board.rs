@@ -0,0 +1,198 @@
+use std::path::Path;
+
+use crate::{Distro, DistroId, FileSystem, RealFs};
+
+/// Device tree directories exposed by the kernel
+const DEVICE_TREE_DIRS: &[&str] = &[
+    "/proc/device-tree",
+    "/sys/firmware/devicetree/base"
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Single board computer info read from the device tree
+pub struct BoardInfo {
+    model: String,
+    compatible: Vec<String>
+}
+
+impl BoardInfo {
+    /// Make board info from the device tree `model` and `compatible` properties
+    /// 
+    /// Both properties are NUL-terminated strings, and `compatible`
+    /// contains a list of NUL-separated values
+    /// 
+    /// ```
+    /// use whatadistro::BoardInfo;
+    /// 
+    /// let board = BoardInfo::from_device_tree(
+    ///     b"Raspberry Pi 4 Model B Rev 1.4\0",
+    ///     b"raspberrypi,4-model-b\0brcm,bcm2711\0"
+    /// );
+    /// 
+    /// assert_eq!(board.model(), "Raspberry Pi 4 Model B Rev 1.4");
+    /// assert_eq!(board.vendor(), Some("raspberrypi"));
+    /// assert_eq!(board.raspberry_pi_generation(), Some(4));
+    /// ```
+    pub fn from_device_tree(model: &[u8], compatible: &[u8]) -> Self {
+        let model = String::from_utf8_lossy(model)
+            .trim_end_matches('\0')
+            .trim()
+            .to_string();
+
+        let compatible = compatible.split(|byte| *byte == 0)
+            .filter(|value| !value.is_empty())
+            .map(|value| String::from_utf8_lossy(value).into_owned())
+            .collect();
+
+        Self {
+            model,
+            compatible
+        }
+    }
+
+    #[inline]
+    /// Get board model name (e.g. `Raspberry Pi 5 Model B Rev 1.0`)
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    #[inline]
+    /// Get device tree compatible strings, most specific first
+    /// (e.g. `["radxa,rockpi4b", "rockchip,rk3399"]`)
+    pub fn compatible(&self) -> &[String] {
+        &self.compatible
+    }
+
+    /// Get board vendor from the most specific compatible string
+    /// (e.g. `raspberrypi`, `radxa`, `xunlong`)
+    pub fn vendor(&self) -> Option<&str> {
+        self.compatible.first()?
+            .split_once(',')
+            .map(|(vendor, _)| vendor)
+    }
+
+    /// Get system on chip name from the least specific compatible string
+    /// (e.g. `bcm2711`, `rk3399`)
+    pub fn soc(&self) -> Option<&str> {
+        self.compatible.last()?
+            .split_once(',')
+            .map(|(_, soc)| soc)
+    }
+
+    #[inline]
+    /// Check if the board is a Raspberry Pi
+    pub fn is_raspberry_pi(&self) -> bool {
+        self.model.starts_with("Raspberry Pi")
+    }
+
+    /// Get Raspberry Pi generation (e.g. 4 for Raspberry Pi 4 and Compute Module 4)
+    /// 
+    /// Returns `None` for other boards and for Raspberry Pi Zero
+    /// 
+    /// ```
+    /// use whatadistro::BoardInfo;
+    /// 
+    /// let board = BoardInfo::from_device_tree(b"Raspberry Pi Compute Module 5 Rev 1.0\0", b"");
+    /// 
+    /// assert_eq!(board.raspberry_pi_generation(), Some(5));
+    /// ```
+    pub fn raspberry_pi_generation(&self) -> Option<u8> {
+        let model = self.model.strip_prefix("Raspberry Pi ")?;
+        let model = model.strip_prefix("Compute Module ").unwrap_or(model);
+
+        let generation = model.split(' ').next()?;
+
+        // Raspberry Pi 1 is reported as "Raspberry Pi Model B"
+        if generation == "Model" {
+            return Some(1);
+        }
+
+        generation.parse().ok()
+    }
+}
+
+/// Detect single board computer the system is running on
+/// 
+/// Returns `None` on systems without device tree (e.g. x86 PCs)
+/// 
+/// ```
+/// if let Some(board) = whatadistro::detect_board() {
+///     println!("Running on {}", board.model());
+/// }
+/// ```
+pub fn detect_board() -> Option<BoardInfo> {
+    detect_board_with_fs(&RealFs)
+}
+
+/// Detect single board computer using files of the given filesystem
+/// 
+/// ```
+/// use whatadistro::MockFs;
+/// 
+/// let fs = MockFs::default()
+///     .with_file("/proc/device-tree/model", "Raspberry Pi 4 Model B Rev 1.4\0")
+///     .with_file("/proc/device-tree/compatible", "raspberrypi,4-model-b\0brcm,bcm2711\0");
+/// 
+/// let board = whatadistro::detect_board_with_fs(&fs).unwrap();
+/// 
+/// assert_eq!(board.model(), "Raspberry Pi 4 Model B Rev 1.4");
+/// assert!(whatadistro::detect_board_with_fs(&MockFs::default()).is_none());
+/// ```
+pub fn detect_board_with_fs(fs: &impl FileSystem) -> Option<BoardInfo> {
+    for dir in DEVICE_TREE_DIRS {
+        let dir = Path::new(dir);
+
+        let Ok(model) = fs.read_to_string(&dir.join("model")) else {
+            continue;
+        };
+
+        let compatible = fs.read_to_string(&dir.join("compatible"))
+            .unwrap_or_default();
+
+        trace!("board model read from {dir:?}");
+
+        return Some(BoardInfo::from_device_tree(model.as_bytes(), compatible.as_bytes()));
+    }
+
+    None
+}
+
+impl Distro {
+    /// Check if the distro is Raspberry Pi OS
+    /// 
+    /// 32-bit images report `ID=raspbian`, while 64-bit ones report
+    /// `ID=debian` and are recognized by the `/etc/rpi-issue` file
+    /// 
+    /// ```
+    /// use whatadistro::Distro;
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Raspbian\nID=raspbian").unwrap();
+    /// 
+    /// assert!(distro.is_raspberry_pi_os());
+    /// ```
+    pub fn is_raspberry_pi_os(&self) -> bool {
+        self.is_raspberry_pi_os_with_fs(&RealFs)
+    }
+
+    /// Check if the distro is Raspberry Pi OS using files of the given filesystem
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, MockFs};
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Debian\nID=debian").unwrap();
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/etc/rpi-issue", "Raspberry Pi reference 2024-07-04");
+    /// 
+    /// assert!(distro.is_raspberry_pi_os_with_fs(&fs));
+    /// assert!(!distro.is_raspberry_pi_os_with_fs(&MockFs::default()));
+    /// ```
+    pub fn is_raspberry_pi_os_with_fs(&self, fs: &impl FileSystem) -> bool {
+        match self.id() {
+            DistroId::Raspbian => true,
+            DistroId::Debian   => fs.exists(Path::new("/etc/rpi-issue")),
+
+            _ => false
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use std::fmt::Display;
+use std::path::Path;
+
+use crate::{SystemInfo, FileSystem, RealFs};
+
+/// EFI system partition mount points, most common first
+const ESP_MOUNT_POINTS: &[&str] = &[
+    "/boot/efi",
+    "/efi",
+    "/boot"
+];
+
+/// `LoaderInfo` EFI variable set by systemd-boot
+const LOADER_INFO: &str = "/sys/firmware/efi/efivars/LoaderInfo-4a67b082-0a4c-41cf-b6c7-440b29bb8c4f";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// System firmware type
+pub enum Firmware {
+    Uefi,
+    Bios
+}
+
+impl Display for Firmware {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Uefi => write!(f, "uefi"),
+            Self::Bios => write!(f, "bios")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Bootloader of the system
+pub enum Bootloader {
+    Grub,
+    SystemdBoot,
+    Limine,
+    Refind,
+    Syslinux
+}
+
+impl Display for Bootloader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Grub        => write!(f, "grub"),
+            Self::SystemdBoot => write!(f, "systemd-boot"),
+            Self::Limine      => write!(f, "limine"),
+            Self::Refind      => write!(f, "refind"),
+            Self::Syslinux    => write!(f, "syslinux")
+        }
+    }
+}
+
+/// Find filesystem type of the given mount point in `/proc/mounts` content
+/// 
+/// The last mount entry wins because it hides the previous ones
+fn mount_fs_type<'a>(mounts: &'a str, mount_point: &str) -> Option<&'a str> {
+    mounts.lines()
+        .rev()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+
+            let _device = fields.next()?;
+            let target = fields.next()?;
+            let fs_type = fields.next()?;
+
+            Some((target, fs_type))
+        })
+        .find(|(target, _)| *target == mount_point)
+        .map(|(_, fs_type)| fs_type)
+}
+
+impl SystemInfo {
+    /// Get filesystem type of the root partition (e.g. `ext4`, `btrfs`, `zfs`)
+    /// 
+    /// Value is taken from the `/proc/mounts` file
+    /// 
+    /// ```
+    /// use whatadistro::SystemInfo;
+    /// 
+    /// if SystemInfo::root_filesystem().as_deref() == Some("btrfs") {
+    ///     println!("Snapshots can be used");
+    /// }
+    /// ```
+    pub fn root_filesystem() -> Option<String> {
+        Self::root_filesystem_with_fs(&RealFs)
+    }
+
+    /// Get filesystem type of the root partition using files of the given filesystem
+    /// 
+    /// ```
+    /// use whatadistro::{SystemInfo, MockFs};
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/proc/mounts", "/dev/nvme0n1p2 / btrfs rw,subvol=/@ 0 0");
+    /// 
+    /// assert_eq!(SystemInfo::root_filesystem_with_fs(&fs).as_deref(), Some("btrfs"));
+    /// ```
+    pub fn root_filesystem_with_fs(fs: &impl FileSystem) -> Option<String> {
+        let mounts = fs.read_to_string(Path::new("/proc/mounts")).ok();
+
+        if mounts.is_none() {
+            trace!("failed to read /proc/mounts");
+        }
+
+        mount_fs_type(&mounts?, "/").map(String::from)
+    }
+
+    /// Get firmware type the system was booted with
+    /// 
+    /// ```
+    /// use whatadistro::{SystemInfo, Firmware};
+    /// 
+    /// match SystemInfo::firmware() {
+    ///     Firmware::Uefi => println!("Booted in UEFI mode"),
+    ///     Firmware::Bios => println!("Booted in legacy BIOS mode")
+    /// }
+    /// ```
+    pub fn firmware() -> Firmware {
+        Self::firmware_with_fs(&RealFs)
+    }
+
+    /// Get firmware type using files of the given filesystem
+    /// 
+    /// ```
+    /// use whatadistro::{SystemInfo, Firmware, MockFs};
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/sys/firmware/efi/fw_platform_size", "64");
+    /// 
+    /// assert_eq!(SystemInfo::firmware_with_fs(&fs), Firmware::Uefi);
+    /// assert_eq!(SystemInfo::firmware_with_fs(&MockFs::default()), Firmware::Bios);
+    /// ```
+    pub fn firmware_with_fs(fs: &impl FileSystem) -> Firmware {
+        if fs.read_dir(Path::new("/sys/firmware/efi")).is_ok() {
+            Firmware::Uefi
+        }
+
+        else {
+            Firmware::Bios
+        }
+    }
+
+    /// Guess bootloader of the system
+    /// 
+    /// systemd-boot is detected by the `LoaderInfo` EFI variable or its
+    /// directory on the EFI system partition, other bootloaders by their configs.
+    /// Returns `None` if nothing is found (e.g. `/boot` is not mounted)
+    /// 
+    /// ```
+    /// use whatadistro::{SystemInfo, Bootloader};
+    /// 
+    /// if SystemInfo::bootloader() == Some(Bootloader::Grub) {
+    ///     println!("Run update-grub after changing kernel parameters");
+    /// }
+    /// ```
+    pub fn bootloader() -> Option<Bootloader> {
+        Self::bootloader_with_fs(&RealFs)
+    }
+
+    /// Guess bootloader of the system using files of the given filesystem
+    /// 
+    /// ```
+    /// use whatadistro::{SystemInfo, Bootloader, MockFs};
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/boot/efi/EFI/systemd/systemd-bootx64.efi", "")
+    ///     .with_file("/boot/grub/grub.cfg", "");
+    /// 
+    /// assert_eq!(SystemInfo::bootloader_with_fs(&fs), Some(Bootloader::SystemdBoot));
+    /// assert_eq!(SystemInfo::bootloader_with_fs(&MockFs::default()), None);
+    /// ```
+    pub fn bootloader_with_fs(fs: &impl FileSystem) -> Option<Bootloader> {
+        if fs.exists(Path::new(LOADER_INFO)) {
+            return Some(Bootloader::SystemdBoot);
+        }
+
+        for esp in ESP_MOUNT_POINTS {
+            let esp = Path::new(esp);
+
+            if fs.read_dir(&esp.join("EFI/systemd")).is_ok() || fs.exists(&esp.join("loader/loader.conf")) {
+                return Some(Bootloader::SystemdBoot);
+            }
+
+            if fs.read_dir(&esp.join("EFI/refind")).is_ok() {
+                return Some(Bootloader::Refind);
+            }
+        }
+
+        const CONFIGS: &[(&str, Bootloader)] = &[
+            ("/boot/grub/grub.cfg",          Bootloader::Grub),
+            ("/boot/grub2/grub.cfg",         Bootloader::Grub),
+            ("/boot/limine.conf",            Bootloader::Limine),
+            ("/boot/limine/limine.conf",     Bootloader::Limine),
+            ("/boot/syslinux/syslinux.cfg",  Bootloader::Syslinux),
+            ("/boot/extlinux/extlinux.conf", Bootloader::Syslinux)
+        ];
+
+        let bootloader = CONFIGS.iter()
+            .find(|(path, _)| fs.exists(Path::new(path)))
+            .map(|(_, bootloader)| *bootloader);
+
+        if bootloader.is_none() {
+            trace!("bootloader is not detected");
+        }
+
+        bootloader
+    }
+}
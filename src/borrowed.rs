@@ -0,0 +1,209 @@
+use std::borrow::Cow;
+
+use crate::{Distro, DistroId, DistroFamily, Confidence, os_release, warnings};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Distro info borrowed from the os-release file content
+/// 
+/// Unlike `Distro` the content is not copied into a map, so parsing
+/// doesn't allocate. Fields are looked up by scanning the content and
+/// allocate only if they contain escape sequences. Useful when scanning
+/// lots of files, e.g. container image layers
+/// 
+/// ```
+/// use whatadistro::{DistroRef, DistroId};
+/// 
+/// let content = String::from("NAME=\"Rocky Linux\"\nID=rocky\nID_LIKE=\"rhel centos fedora\"\nVERSION_ID=9.3");
+/// 
+/// let distro = DistroRef::from_os_release(&content).unwrap();
+/// 
+/// assert_eq!(distro.name(), "Rocky Linux");
+/// assert_eq!(distro.raw_id(), "rocky");
+/// assert_eq!(distro.version_id().as_deref(), Some("9.3"));
+/// assert!(distro.is_similar(DistroId::RHEL));
+/// ```
+pub struct DistroRef<'a> {
+    content: &'a str,
+    id: Cow<'a, str>,
+    name: Cow<'a, str>,
+    id_like: Cow<'a, str>
+}
+
+impl<'a> DistroRef<'a> {
+    /// Parse distro from the os-release file content
+    /// 
+    /// Returns `None` if `ID` or `NAME` fields are missing
+    pub fn from_os_release(content: &'a str) -> Option<Self> {
+        let mut id = None;
+        let mut name = None;
+        let mut id_like = None;
+
+        // Later values override earlier ones like in the owned parser
+        for (key, value) in os_release::fields(content) {
+            match key {
+                "ID"      => id = Some(value),
+                "NAME"    => name = Some(value),
+                "ID_LIKE" => id_like = Some(value),
+
+                _ => ()
+            }
+        }
+
+        if id.is_none() || name.is_none() {
+            trace!("ID or NAME field is missing, identification failed");
+        }
+
+        Some(Self {
+            content,
+            id: id?,
+            name: name?,
+            id_like: id_like.unwrap_or_default()
+        })
+    }
+
+    #[inline]
+    /// Get original os-release file content
+    pub fn content(&self) -> &'a str {
+        self.content
+    }
+
+    #[inline]
+    /// Get distro name (`NAME` entry)
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    /// Get original `ID` value
+    pub fn raw_id(&self) -> &str {
+        &self.id
+    }
+
+    /// Get distro id (`ID` entry)
+    /// 
+    /// Only unknown ids allocate (`DistroId::Other`)
+    pub fn id(&self) -> DistroId {
+        self.known_id()
+            .unwrap_or_else(|| DistroId::from(self.id.as_ref()))
+    }
+
+    /// Get known distro id without allocating
+    fn known_id(&self) -> Option<DistroId> {
+        match DistroId::from_ascii_lowercase(&self.id)? {
+            // LMDE uses the same ID as Linux Mint but is based on Debian
+            DistroId::Mint if self.name.starts_with("LMDE") => Some(DistroId::LMDE),
+
+            id => Some(id)
+        }
+    }
+
+    #[inline]
+    /// Iterate over similar distros (`ID_LIKE` entry)
+    /// 
+    /// Only unknown ids allocate (`DistroId::Other`), use
+    /// `raw_similar_ids` to iterate over the original values
+    pub fn similar_ids(&self) -> impl Iterator<Item = DistroId> + '_ {
+        self.raw_similar_ids().map(DistroId::from)
+    }
+
+    #[inline]
+    /// Iterate over original `ID_LIKE` values
+    /// 
+    /// ```
+    /// use whatadistro::DistroRef;
+    /// 
+    /// let distro = DistroRef::from_os_release("NAME=Nobara\nID=nobara\nID_LIKE=\"fedora\"").unwrap();
+    /// 
+    /// assert_eq!(distro.raw_similar_ids().collect::<Vec<_>>(), ["fedora"]);
+    /// ```
+    pub fn raw_similar_ids(&self) -> impl Iterator<Item = &str> + '_ {
+        self.id_like.split_whitespace()
+    }
+
+    /// Get value of the os-release field
+    /// 
+    /// Last value is returned if the field is set multiple times
+    pub fn field(&self, key: impl AsRef<str>) -> Option<Cow<'a, str>> {
+        let key = key.as_ref();
+
+        os_release::fields(self.content)
+            .filter(|(name, _)| *name == key)
+            .map(|(_, value)| value)
+            .last()
+    }
+
+    #[inline]
+    /// Iterate over all the os-release fields in the file order
+    pub fn fields(&self) -> impl Iterator<Item = (&'a str, Cow<'a, str>)> {
+        os_release::fields(self.content)
+    }
+
+    #[inline]
+    /// Get `PRETTY_NAME` field value
+    pub fn pretty_name(&self) -> Option<Cow<'a, str>> {
+        self.field("PRETTY_NAME")
+    }
+
+    #[inline]
+    /// Get `VERSION` field value
+    pub fn version(&self) -> Option<Cow<'a, str>> {
+        self.field("VERSION")
+    }
+
+    #[inline]
+    /// Get `VERSION_ID` field value
+    pub fn version_id(&self) -> Option<Cow<'a, str>> {
+        self.field("VERSION_ID")
+    }
+
+    #[inline]
+    /// Get `VERSION_CODENAME` field value
+    pub fn version_codename(&self) -> Option<Cow<'a, str>> {
+        self.field("VERSION_CODENAME")
+    }
+
+    /// Compare current distro with some another
+    /// 
+    /// Raw `ID` and `ID_LIKE` values are compared, so nothing is allocated
+    pub fn is_similar<T: Into<DistroId>>(&self, other: T) -> bool {
+        let other = other.into();
+
+        if self.raw_similar_ids().any(|id| raw_id_matches(id, &other)) {
+            return true;
+        }
+
+        match self.known_id() {
+            Some(id) => id == other || (id.family() != DistroFamily::Other && id.family() == other.family()),
+            None => raw_id_matches(&self.id, &other)
+        }
+    }
+}
+
+/// Check if raw `ID` value is parsed into the given distro id
+fn raw_id_matches(raw: &str, id: &DistroId) -> bool {
+    match DistroId::from_ascii_lowercase(raw) {
+        Some(known) => &known == id,
+        None => matches!(id, DistroId::Other(other) if other.as_str() == raw)
+    }
+}
+
+impl From<&DistroRef<'_>> for Distro {
+    fn from(distro: &DistroRef<'_>) -> Self {
+        Self {
+            id: distro.id(),
+            similar_ids: distro.similar_ids().collect(),
+            id_like: crate::parse_id_like(&distro.id_like),
+            fields: os_release::parse(distro.content),
+            confidence: Confidence::Exact,
+            source: None,
+            warnings: warnings::lint(distro.content)
+        }
+    }
+}
+
+impl From<DistroRef<'_>> for Distro {
+    #[inline]
+    fn from(distro: DistroRef<'_>) -> Self {
+        Self::from(&distro)
+    }
+}
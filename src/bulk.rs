@@ -0,0 +1,91 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{Distro, RealFs, identify_file, os_release};
+
+/// Resolve symlink relative to the given root filesystem
+/// 
+/// Absolute symlinks point to the root's files, not the host ones
+fn resolve_in_root(root: &Path, path: &Path) -> PathBuf {
+    let Ok(target) = std::fs::read_link(path) else {
+        return path.to_path_buf();
+    };
+
+    match target.strip_prefix("/") {
+        Ok(relative) => root.join(relative),
+        Err(_) => path.parent().map(|parent| parent.join(&target)).unwrap_or(target)
+    }
+}
+
+/// Identify distro installed to the given root filesystem
+fn identify_root(root: &Path) -> io::Result<Distro> {
+    if !root.is_dir() {
+        return Err(io::Error::new(io::ErrorKind::NotFound, format!("{root:?} is not a directory")));
+    }
+
+    let etc = resolve_in_root(root, &root.join("etc/os-release"));
+
+    let distro = identify_file(&RealFs, &etc)
+        .or_else(|| identify_file(&RealFs, root.join("usr/lib/os-release")))
+        .or_else(|| {
+            let path = root.join("etc/lsb-release");
+
+            os_release::read_lossy(&path).ok()
+                .and_then(|lsb_release| Distro::from_lsb_release(&lsb_release))
+                .map(|distro| distro.with_source(path))
+        });
+
+    distro.ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("no distro info found in {root:?}")))
+}
+
+/// Identify distros installed to multiple root filesystems
+/// 
+/// Roots can be mounted snapshots, chroots or unpacked images.
+/// Absolute symlinks are resolved relative to the root. With `threads`
+/// feature roots are inspected by one scoped thread per available CPU core.
+/// Results are returned in the same order as roots
+/// 
+/// ```
+/// use std::path::PathBuf;
+/// 
+/// let roots = vec![PathBuf::from("/"), PathBuf::from("/mnt/snapshot")];
+/// 
+/// for (root, distro) in whatadistro::identify_many(&roots) {
+///     match distro {
+///         Ok(distro) => println!("{root:?}: {}", distro.name()),
+///         Err(err) => println!("{root:?}: {err}")
+///     }
+/// }
+/// ```
+pub fn identify_many(roots: &[PathBuf]) -> Vec<(PathBuf, io::Result<Distro>)> {
+    #[cfg(feature = "threads")]
+    {
+        let threads = std::thread::available_parallelism()
+            .map(|threads| threads.get())
+            .unwrap_or(1);
+
+        // One chunk per thread at most
+        let chunk_size = roots.len() / threads + 1;
+
+        std::thread::scope(|scope| {
+            let handles = roots.chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || identify_sequential(chunk)))
+                .collect::<Vec<_>>();
+
+            handles.into_iter()
+                .flat_map(|handle| {
+                    handle.join().unwrap_or_else(|err| std::panic::resume_unwind(err))
+                })
+                .collect()
+        })
+    }
+
+    #[cfg(not(feature = "threads"))]
+    identify_sequential(roots)
+}
+
+fn identify_sequential(roots: &[PathBuf]) -> Vec<(PathBuf, io::Result<Distro>)> {
+    roots.iter()
+        .map(|root| (root.clone(), identify_root(root)))
+        .collect()
+}
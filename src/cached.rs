@@ -0,0 +1,91 @@
+use std::sync::RwLock;
+
+use crate::{Distro, DistroId, identify};
+use crate::overrides::get_override;
+
+/// Distro identified by the first one-liner call
+static CACHED: RwLock<Option<Option<Distro>>> = RwLock::new(None);
+
+/// Get cached distro, identifying it on the first call
+/// 
+/// Override set by `set_override` is always used if present
+fn cached() -> Option<Distro> {
+    if let Some(distro) = get_override() {
+        return Some(distro);
+    }
+
+    let cached = match CACHED.read() {
+        Ok(cached) => cached.clone(),
+        Err(err) => err.into_inner().clone()
+    };
+
+    if let Some(distro) = cached {
+        return distro;
+    }
+
+    let distro = identify();
+
+    match CACHED.write() {
+        Ok(mut cached) => *cached = Some(distro.clone()),
+        Err(err) => *err.into_inner() = Some(distro.clone())
+    }
+
+    distro
+}
+
+#[inline]
+/// Get id of the current distro
+/// 
+/// Distro is identified once and cached for the whole process
+/// 
+/// ```
+/// if let Some(id) = whatadistro::id() {
+///     println!("Distro id: {id}");
+/// }
+/// ```
+pub fn id() -> Option<DistroId> {
+    cached().map(|distro| distro.id().clone())
+}
+
+#[inline]
+/// Get name of the current distro (`NAME` field)
+/// 
+/// Distro is identified once and cached for the whole process
+/// 
+/// ```
+/// if let Some(name) = whatadistro::name() {
+///     println!("Distro name: {name}");
+/// }
+/// ```
+pub fn name() -> Option<String> {
+    cached().map(|distro| distro.name().to_string())
+}
+
+#[inline]
+/// Get version of the current distro (`VERSION_ID` field)
+/// 
+/// Distro is identified once and cached for the whole process
+/// 
+/// ```
+/// if let Some(version) = whatadistro::version() {
+///     println!("Distro version: {version}");
+/// }
+/// ```
+pub fn version() -> Option<String> {
+    cached()?.version_id().map(String::from)
+}
+
+#[inline]
+/// Check if the current distro is similar to the given one
+/// 
+/// Distro is identified once and cached for the whole process.
+/// Returns `false` if the distro can't be identified
+/// 
+/// ```
+/// if whatadistro::is("debian") {
+///     println!("Use apt to install packages");
+/// }
+/// ```
+pub fn is(id: impl Into<DistroId>) -> bool {
+    cached().map(|distro| distro.is_similar(id)).unwrap_or(false)
+}
@@ -0,0 +1,109 @@
+use crate::{DistroId, SCHEMA_VERSION};
+
+/// Cargo features of the crate and whether they were compiled in
+const FEATURES: &[(&str, bool)] = &[
+    ("detect-exec",    cfg!(feature = "detect-exec")),
+    ("packages",       cfg!(feature = "packages")),
+    ("system-info",    cfg!(feature = "system-info")),
+    ("desktop",        cfg!(feature = "desktop")),
+    ("containers",     cfg!(feature = "containers")),
+    ("audit",          cfg!(feature = "audit")),
+    ("i18n",           cfg!(feature = "i18n")),
+    ("trace-hook",     cfg!(feature = "trace-hook")),
+    ("url-fields",     cfg!(feature = "url-fields")),
+    ("oci",            cfg!(feature = "oci")),
+    ("static-target",  cfg!(feature = "static-target")),
+    ("threads",        cfg!(feature = "threads")),
+    ("os-info-names",  cfg!(feature = "os-info-names")),
+    ("ostree",         cfg!(feature = "ostree"))
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Detectors and features the crate was compiled with
+/// 
+/// Useful for consumers which can't see the crate's cfg
+/// (plugins, dylibs, FFI bindings)
+pub struct CrateCapabilities {
+    version: &'static str,
+    known_distros: usize,
+    schema_version: u32
+}
+
+impl CrateCapabilities {
+    #[inline]
+    /// Get version of the crate
+    pub fn version(&self) -> &'static str {
+        self.version
+    }
+
+    #[inline]
+    /// Get amount of distros known to the crate (`DistroId::ALL`)
+    pub fn known_distros(&self) -> usize {
+        self.known_distros
+    }
+
+    #[inline]
+    /// Get version of the JSON outputs schema (`SCHEMA_VERSION`)
+    pub fn schema_version(&self) -> u32 {
+        self.schema_version
+    }
+
+    #[inline]
+    /// Check if detectors can spawn external commands (`detect-exec` feature)
+    /// 
+    /// Commands are still spawned only if allowed by `IdentifyOptions::no_exec`
+    pub fn supports_exec(&self) -> bool {
+        self.has_feature("detect-exec")
+    }
+
+    #[inline]
+    /// Check if container images can be identified (`oci` feature)
+    pub fn supports_oci(&self) -> bool {
+        self.has_feature("oci")
+    }
+
+    #[inline]
+    /// Check if the crate provides async API
+    /// 
+    /// The crate has no async API, everything is blocking
+    pub fn supports_async(&self) -> bool {
+        false
+    }
+
+    /// List cargo features the crate was compiled with
+    pub fn features(&self) -> impl Iterator<Item = &'static str> {
+        FEATURES.iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| *name)
+    }
+
+    #[inline]
+    /// Check if the cargo feature was compiled in
+    /// 
+    /// Returns `false` for unknown features
+    pub fn has_feature(&self, feature: &str) -> bool {
+        FEATURES.iter().any(|(name, enabled)| *enabled && *name == feature)
+    }
+}
+
+/// Get detectors and features the crate was compiled with
+/// 
+/// ```
+/// let capabilities = whatadistro::capabilities();
+/// 
+/// assert_eq!(capabilities.schema_version(), whatadistro::SCHEMA_VERSION);
+/// assert_eq!(capabilities.supports_oci(), cfg!(feature = "oci"));
+/// 
+/// println!("whatadistro {} knows {} distros", capabilities.version(), capabilities.known_distros());
+/// 
+/// for feature in capabilities.features() {
+///     println!("Compiled with {feature}");
+/// }
+/// ```
+pub fn capabilities() -> CrateCapabilities {
+    CrateCapabilities {
+        version: env!("CARGO_PKG_VERSION"),
+        known_distros: DistroId::ALL.len(),
+        schema_version: SCHEMA_VERSION
+    }
+}
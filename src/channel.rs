@@ -0,0 +1,100 @@
+use std::fmt::Display;
+
+use crate::{Distro, DistroId};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Release channel of the installed distro
+pub enum ReleaseChannel {
+    /// Regular point release
+    Stable,
+
+    /// Pre-release of the next stable version
+    /// (Debian testing, Ubuntu development branch, Fedora branched)
+    Testing,
+
+    /// Development branch (Debian sid, Fedora Rawhide)
+    Unstable,
+
+    /// Rolling release distro without versions (Arch, Gentoo, openSUSE Tumbleweed)
+    Rolling
+}
+
+impl Display for ReleaseChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stable   => write!(f, "stable"),
+            Self::Testing  => write!(f, "testing"),
+            Self::Unstable => write!(f, "unstable"),
+            Self::Rolling  => write!(f, "rolling")
+        }
+    }
+}
+
+impl Distro {
+    /// Get release channel of the distro
+    /// 
+    /// Debian testing and sid ship the same os-release file without `VERSION_ID`
+    /// so they're told apart only by `VERSION_CODENAME=sid`. Fedora Rawhide is
+    /// recognized by the `rawhide` product version
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, ReleaseChannel};
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     PRETTY_NAME="Debian GNU/Linux trixie/sid"
+    ///     NAME="Debian GNU/Linux"
+    ///     VERSION_CODENAME=sid
+    ///     ID=debian
+    /// "#).unwrap();
+    /// 
+    /// assert_eq!(distro.release_channel(), ReleaseChannel::Unstable);
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME="Fedora Linux"
+    ///     VERSION="42 (Workstation Edition Prerelease)"
+    ///     ID=fedora
+    ///     VERSION_ID=42
+    ///     REDHAT_BUGZILLA_PRODUCT_VERSION=rawhide
+    /// "#).unwrap();
+    /// 
+    /// assert_eq!(distro.release_channel(), ReleaseChannel::Unstable);
+    /// 
+    /// let distro = Distro::from_os_release("NAME=\"Arch Linux\"\nID=arch\nBUILD_ID=rolling").unwrap();
+    /// 
+    /// assert_eq!(distro.release_channel(), ReleaseChannel::Rolling);
+    /// ```
+    pub fn release_channel(&self) -> ReleaseChannel {
+        let version = self.version().unwrap_or_default();
+
+        match self.id() {
+            DistroId::Debian if self.version_codename() == Some("sid") => ReleaseChannel::Unstable,
+            DistroId::Debian if self.version_id().is_none() => ReleaseChannel::Testing,
+
+            DistroId::Fedora if self.is_rawhide() => ReleaseChannel::Unstable,
+            DistroId::Fedora if version.contains("Prerelease") => ReleaseChannel::Testing,
+
+            DistroId::Ubuntu if version.contains("development branch") => ReleaseChannel::Testing,
+
+            DistroId::OpenSUSE if self.field("ID") == Some("opensuse-tumbleweed") => ReleaseChannel::Rolling,
+
+            id if id.is_rolling() => ReleaseChannel::Rolling,
+
+            _ if self.field("BUILD_ID") == Some("rolling") => ReleaseChannel::Rolling,
+
+            _ => ReleaseChannel::Stable
+        }
+    }
+
+    /// Check if Fedora product version fields contain `rawhide`
+    fn is_rawhide(&self) -> bool {
+        const FIELDS: &[&str] = &[
+            "VERSION_ID",
+            "REDHAT_BUGZILLA_PRODUCT_VERSION",
+            "REDHAT_SUPPORT_PRODUCT_VERSION"
+        ];
+
+        FIELDS.iter()
+            .filter_map(|key| self.field(key))
+            .any(|value| value.eq_ignore_ascii_case("rawhide"))
+    }
+}
@@ -0,0 +1,115 @@
+//! Kernel command line (`/proc/cmdline`) parser
+//! 
+//! ```
+//! use whatadistro::cmdline::Cmdline;
+//! 
+//! let cmdline = Cmdline::parse("BOOT_IMAGE=/vmlinuz root=UUID=1234 ro quiet splash");
+//! 
+//! assert_eq!(cmdline.get("root"), Some("UUID=1234"));
+//! assert!(cmdline.has_flag("quiet"));
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Parsed kernel command line
+pub struct Cmdline {
+    flags: HashSet<String>,
+    values: HashMap<String, String>
+}
+
+impl Cmdline {
+    /// Read and parse current kernel command line from `/proc/cmdline`
+    pub fn current() -> Option<Self> {
+        let cmdline = std::fs::read_to_string("/proc/cmdline").ok();
+
+        if cmdline.is_none() {
+            trace!("failed to read /proc/cmdline");
+        }
+
+        cmdline.map(|cmdline| Self::parse(&cmdline))
+    }
+
+    /// Parse kernel command line
+    /// 
+    /// Parameters are separated by whitespaces. Double quotes can be used
+    /// to include whitespaces in values (`key="some value"`). If the same key
+    /// is specified multiple times then the last value is kept
+    /// 
+    /// ```
+    /// use whatadistro::cmdline::Cmdline;
+    /// 
+    /// let cmdline = Cmdline::parse("console=tty0 console=ttyS0 title=\"My Linux\" rd.live.image");
+    /// 
+    /// assert_eq!(cmdline.get("console"), Some("ttyS0"));
+    /// assert_eq!(cmdline.get("title"), Some("My Linux"));
+    /// assert!(cmdline.has_flag("rd.live.image"));
+    /// ```
+    pub fn parse(cmdline: &str) -> Self {
+        let mut result = Self::default();
+
+        let mut param = String::new();
+        let mut in_quotes = false;
+
+        for char in cmdline.chars().chain(std::iter::once(' ')) {
+            match char {
+                '"' => in_quotes = !in_quotes,
+
+                char if char.is_whitespace() && !in_quotes => {
+                    if !param.is_empty() {
+                        result.insert(std::mem::take(&mut param));
+                    }
+                }
+
+                char => param.push(char)
+            }
+        }
+
+        result
+    }
+
+    fn insert(&mut self, param: String) {
+        match param.split_once('=') {
+            Some((key, value)) => {
+                self.values.insert(key.to_string(), value.to_string());
+            }
+
+            None => {
+                self.flags.insert(param);
+            }
+        }
+    }
+
+    #[inline]
+    /// Get value of the `key=value` parameter
+    pub fn get(&self, key: impl AsRef<str>) -> Option<&str> {
+        self.values.get(key.as_ref()).map(String::as_str)
+    }
+
+    #[inline]
+    /// Check if the command line contains given flag (parameter without value)
+    pub fn has_flag(&self, flag: impl AsRef<str>) -> bool {
+        self.flags.contains(flag.as_ref())
+    }
+
+    #[inline]
+    /// Check if the command line contains given parameter, either as a flag
+    /// or as a `key=value` pair
+    pub fn contains(&self, param: impl AsRef<str>) -> bool {
+        let param = param.as_ref();
+
+        self.flags.contains(param) || self.values.contains_key(param)
+    }
+
+    #[inline]
+    /// Get all flags (parameters without values)
+    pub fn flags(&self) -> &HashSet<String> {
+        &self.flags
+    }
+
+    #[inline]
+    /// Get all `key=value` parameters
+    pub fn values(&self) -> &HashMap<String, String> {
+        &self.values
+    }
+}
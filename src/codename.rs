@@ -0,0 +1,187 @@
+use crate::{Distro, DistroId};
+
+/// Release codenames and versions they correspond to
+const CODENAMES: &[(DistroId, Option<&str>, &str)] = &[
+    (DistroId::Debian, Some("8"),  "jessie"),
+    (DistroId::Debian, Some("9"),  "stretch"),
+    (DistroId::Debian, Some("10"), "buster"),
+    (DistroId::Debian, Some("11"), "bullseye"),
+    (DistroId::Debian, Some("12"), "bookworm"),
+    (DistroId::Debian, Some("13"), "trixie"),
+    (DistroId::Debian, Some("14"), "forky"),
+    (DistroId::Debian, None,       "sid"),
+
+    (DistroId::Ubuntu, Some("16.04"), "xenial"),
+    (DistroId::Ubuntu, Some("18.04"), "bionic"),
+    (DistroId::Ubuntu, Some("20.04"), "focal"),
+    (DistroId::Ubuntu, Some("22.04"), "jammy"),
+    (DistroId::Ubuntu, Some("23.04"), "lunar"),
+    (DistroId::Ubuntu, Some("23.10"), "mantic"),
+    (DistroId::Ubuntu, Some("24.04"), "noble"),
+    (DistroId::Ubuntu, Some("24.10"), "oracular"),
+    (DistroId::Ubuntu, Some("25.04"), "plucky"),
+    (DistroId::Ubuntu, Some("25.10"), "questing")
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Known distro release identified by its version and codename
+pub struct DistroVersion {
+    id: DistroId,
+    version_id: Option<&'static str>,
+    codename: &'static str
+}
+
+impl DistroVersion {
+    /// Find known release by its codename
+    /// 
+    /// Comparison is ASCII case-insensitive. Rolling releases like
+    /// Debian sid don't have a version
+    /// 
+    /// ```
+    /// use whatadistro::{DistroId, DistroVersion};
+    /// 
+    /// let version = DistroVersion::from_codename("bookworm").unwrap();
+    /// 
+    /// assert_eq!(version.id(), &DistroId::Debian);
+    /// assert_eq!(version.version_id(), Some("12"));
+    /// 
+    /// assert_eq!(DistroVersion::from_codename("sid").unwrap().version_id(), None);
+    /// ```
+    pub fn from_codename(codename: impl AsRef<str>) -> Option<Self> {
+        let codename = codename.as_ref().trim();
+
+        CODENAMES.iter()
+            .find(|(_, _, known)| known.eq_ignore_ascii_case(codename))
+            .map(Self::from_entry)
+    }
+
+    /// Find known release by the distro id and its version
+    /// 
+    /// ```
+    /// use whatadistro::{DistroId, DistroVersion};
+    /// 
+    /// let version = DistroVersion::from_version_id(DistroId::Ubuntu, "24.04").unwrap();
+    /// 
+    /// assert_eq!(version.codename(), "noble");
+    /// ```
+    pub fn from_version_id(id: impl Into<DistroId>, version_id: impl AsRef<str>) -> Option<Self> {
+        let id = id.into();
+        let version_id = version_id.as_ref().trim();
+
+        CODENAMES.iter()
+            .find(|(known_id, known_version, _)| known_id == &id && known_version == &Some(version_id))
+            .map(Self::from_entry)
+    }
+
+    fn from_entry(entry: &(DistroId, Option<&'static str>, &'static str)) -> Self {
+        let (id, version_id, codename) = entry;
+
+        Self {
+            id: id.clone(),
+            version_id: *version_id,
+            codename
+        }
+    }
+
+    #[inline]
+    /// Get id of the distro this release belongs to
+    pub fn id(&self) -> &DistroId {
+        &self.id
+    }
+
+    #[inline]
+    /// Get `VERSION_ID` of the release
+    pub fn version_id(&self) -> Option<&'static str> {
+        self.version_id
+    }
+
+    #[inline]
+    /// Get release codename (e.g. `bookworm`)
+    pub fn codename(&self) -> &'static str {
+        self.codename
+    }
+}
+
+/// Split `VERSION` value into the version number and the text in parentheses
+/// (`12 (bookworm)` -> `12`, `bookworm`)
+fn split_version(version: &str) -> (Option<&str>, Option<&str>) {
+    let version = version.trim();
+
+    let number = version.split(|char: char| char.is_whitespace() || char == '(' || char == ',')
+        .next()
+        .filter(|number| number.starts_with(|char: char| char.is_ascii_digit()));
+
+    let comment = version.split_once('(')
+        .and_then(|(_, comment)| comment.split_once(')'))
+        .map(|(comment, _)| comment.trim())
+        .filter(|comment| !comment.is_empty());
+
+    (number, comment)
+}
+
+impl Distro {
+    /// Get version number of the distro
+    /// 
+    /// `VERSION_ID` field is used first, then the number
+    /// at the beginning of the `VERSION` field
+    /// 
+    /// ```
+    /// use whatadistro::Distro;
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME="Debian GNU/Linux"
+    ///     ID=debian
+    ///     VERSION="12 (bookworm)"
+    /// "#).unwrap();
+    /// 
+    /// assert_eq!(distro.version_number(), Some("12"));
+    /// ```
+    pub fn version_number(&self) -> Option<&str> {
+        self.version_id().or_else(|| split_version(self.version()?).0)
+    }
+
+    /// Get release codename
+    /// 
+    /// `VERSION_CODENAME` and `UBUNTU_CODENAME` fields are used first, then
+    /// the text in parentheses of the `VERSION` field (`12 (bookworm)`) if it's
+    /// a single word or a known release name (`22.04.3 LTS (Jammy Jellyfish)`).
+    /// Otherwise codename is looked up by the version number in the list
+    /// of known Debian and Ubuntu releases
+    /// 
+    /// ```
+    /// use whatadistro::Distro;
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Debian\nID=debian\nVERSION_ID=12").unwrap();
+    /// 
+    /// assert_eq!(distro.codename(), Some("bookworm"));
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Ubuntu\nID=ubuntu\nVERSION=\"22.04.3 LTS (Jammy Jellyfish)\"").unwrap();
+    /// 
+    /// assert_eq!(distro.codename(), Some("jammy"));
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Fedora\nID=fedora\nVERSION=\"40 (Workstation Edition)\"").unwrap();
+    /// 
+    /// assert_eq!(distro.codename(), None);
+    /// ```
+    pub fn codename(&self) -> Option<&str> {
+        if let Some(codename) = self.version_codename().or_else(|| self.field("UBUNTU_CODENAME")) {
+            return Some(codename);
+        }
+
+        if let Some(comment) = self.version().and_then(|version| split_version(version).1) {
+            let first_word = comment.split_whitespace().next().unwrap_or_default();
+
+            if let Some(version) = DistroVersion::from_codename(first_word) {
+                return Some(version.codename());
+            }
+
+            if !comment.contains(char::is_whitespace) {
+                return Some(comment);
+            }
+        }
+
+        let version = DistroVersion::from_version_id(self.id().clone(), self.version_number()?)?;
+
+        Some(version.codename())
+    }
+}
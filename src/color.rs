@@ -0,0 +1,148 @@
+use std::fmt::Display;
+
+/// xterm colors for standard (30-37) and bright (90-97) SGR codes
+const PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255)
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Terminal color described by the SGR escape sequence parameters (`ANSI_COLOR` entry)
+pub struct AnsiColor {
+    sgr: String,
+    rgb: Option<(u8, u8, u8)>
+}
+
+impl AnsiColor {
+    /// Parse SGR parameters (e.g. `0;38;2;126;186;228` or `1;34`)
+    /// 
+    /// Returns `None` if parameters are not `;` separated numbers
+    /// 
+    /// ```
+    /// use whatadistro::AnsiColor;
+    /// 
+    /// assert_eq!(AnsiColor::parse("0;38;2;126;186;228").unwrap().rgb(), Some((126, 186, 228)));
+    /// assert_eq!(AnsiColor::parse("1;31").unwrap().rgb(), Some((205, 0, 0)));
+    /// assert_eq!(AnsiColor::parse("38;5;196").unwrap().rgb(), Some((255, 0, 0)));
+    /// 
+    /// assert_eq!(AnsiColor::parse("red"), None);
+    /// ```
+    pub fn parse(sgr: impl AsRef<str>) -> Option<Self> {
+        let sgr = sgr.as_ref().trim();
+
+        let params = sgr.split(';')
+            .map(|param| param.parse::<u8>().ok())
+            .collect::<Option<Vec<_>>>()?;
+
+        let mut rgb = None;
+        let mut i = 0;
+
+        while i < params.len() {
+            match params[i] {
+                code @ 30..=37 => rgb = Some(PALETTE[(code - 30) as usize]),
+                code @ 90..=97 => rgb = Some(PALETTE[(code - 90 + 8) as usize]),
+
+                38 => match params.get(i + 1) {
+                    Some(2) => {
+                        if let [r, g, b] = params.get(i + 2..i + 5)? {
+                            rgb = Some((*r, *g, *b));
+                        }
+
+                        i += 4;
+                    }
+
+                    Some(5) => {
+                        rgb = Some(xterm_256(*params.get(i + 2)?));
+
+                        i += 2;
+                    }
+
+                    _ => ()
+                }
+
+                _ => ()
+            }
+
+            i += 1;
+        }
+
+        Some(Self {
+            sgr: sgr.to_string(),
+            rgb
+        })
+    }
+
+    #[inline]
+    /// Get SGR parameters
+    pub fn sgr(&self) -> &str {
+        &self.sgr
+    }
+
+    #[inline]
+    /// Get foreground color as RGB values if it's specified
+    pub fn rgb(&self) -> Option<(u8, u8, u8)> {
+        self.rgb
+    }
+
+    #[inline]
+    /// Get escape sequence enabling the color (e.g. `\x1b[1;34m`)
+    pub fn escape(&self) -> String {
+        format!("\x1b[{}m", self.sgr)
+    }
+
+    #[inline]
+    /// Wrap text in escape sequences enabling and resetting the color
+    /// 
+    /// ```
+    /// use whatadistro::AnsiColor;
+    /// 
+    /// let color = AnsiColor::parse("1;34").unwrap();
+    /// 
+    /// assert_eq!(color.paint("Arch Linux"), "\x1b[1;34mArch Linux\x1b[0m");
+    /// ```
+    pub fn paint(&self, text: impl Display) -> String {
+        format!("\x1b[{}m{text}\x1b[0m", self.sgr)
+    }
+}
+
+impl Display for AnsiColor {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.sgr)
+    }
+}
+
+/// Convert xterm 256 colors palette index into RGB values
+fn xterm_256(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => PALETTE[index as usize],
+
+        16..=231 => {
+            let index = index - 16;
+
+            let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+
+            (level(index / 36), level(index / 6 % 6), level(index % 6))
+        }
+
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+
+            (gray, gray, gray)
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::Path;
+
+use crate::{Distro, DistroId, FileSystem};
+
+/// Legacy release files, distro they belong to and
+/// the field their first line is stored to
+/// 
+/// Derivatives ship their parents files as well
+/// so more specific files go first
+pub(crate) const LEGACY_FILES: &[(&str, DistroId, &str)] = &[
+    ("/etc/fedora-release", DistroId::Fedora,   "PRETTY_NAME"),
+    ("/etc/redhat-release", DistroId::RHEL,     "PRETTY_NAME"),
+    ("/etc/gentoo-release", DistroId::Gentoo,   "PRETTY_NAME"),
+    ("/etc/SuSE-release",   DistroId::OpenSUSE, "PRETTY_NAME"),
+    ("/etc/arch-release",   DistroId::Arch,     "PRETTY_NAME"),
+    ("/etc/debian_version", DistroId::Debian,   "VERSION_ID")
+];
+
+/// Package database files and root distros of the families using them
+/// 
+/// Used as the last resort in minimal chroots without any release files
+const PACKAGE_DATABASES: &[(&str, DistroId)] = &[
+    ("/var/lib/dpkg/status",                  DistroId::Debian),
+    ("/var/lib/pacman/local/ALPM_DB_VERSION", DistroId::Arch),
+    ("/usr/lib/sysimage/rpm/rpmdb.sqlite",    DistroId::Fedora),
+    ("/var/lib/rpm/rpmdb.sqlite",             DistroId::Fedora),
+    ("/var/lib/rpm/Packages",                 DistroId::Fedora),
+    ("/lib/apk/db/installed",                 DistroId::Alpine),
+    ("/var/lib/portage/world",                DistroId::Gentoo),
+    ("/nix/var/nix/db/db.sqlite",             DistroId::NixOS)
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// How much the identification result can be trusted
+/// 
+/// Levels are ordered, so `confidence >= Confidence::High`
+/// can be used to filter out guesses
+pub enum Confidence {
+    /// Distro is guessed from a legacy release file
+    /// without any fields (e.g. `/etc/arch-release`)
+    /// or from the package database (e.g. `/var/lib/dpkg/status`)
+    Heuristic,
+
+    /// Distro is parsed from the `/etc/lsb-release` file
+    High,
+
+    /// Distro is parsed from the os-release file
+    Exact
+}
+
+impl Display for Confidence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Heuristic => write!(f, "heuristic"),
+            Self::High      => write!(f, "high"),
+            Self::Exact     => write!(f, "exact")
+        }
+    }
+}
+
+impl Distro {
+    #[inline]
+    /// Get confidence of the identification result
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, Confidence};
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Debian\nID=debian").unwrap();
+    /// assert_eq!(distro.confidence(), Confidence::Exact);
+    /// 
+    /// let distro = Distro::from_lsb_release("DISTRIB_ID=Ubuntu").unwrap();
+    /// assert_eq!(distro.confidence(), Confidence::High);
+    /// ```
+    pub fn confidence(&self) -> Confidence {
+        self.confidence
+    }
+}
+
+/// Guess distro using legacy release files
+pub(crate) fn identify_legacy(fs: &impl FileSystem) -> Option<Distro> {
+    LEGACY_FILES.iter().find_map(|(path, id, key)| {
+        if !fs.exists(Path::new(path)) {
+            return None;
+        }
+
+        trace!("guessing distro from {path}");
+
+        let mut fields = HashMap::new();
+
+        fields.insert(String::from("ID"), id.to_string());
+        fields.insert(String::from("NAME"), id.name().to_string());
+
+        let first_line = fs.read_to_string(Path::new(path)).ok()
+            .and_then(|content| {
+                content.lines()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty())
+                    .map(String::from)
+            });
+
+        if let Some(line) = first_line {
+            fields.insert(key.to_string(), line);
+        }
+
+        Some(Distro {
+            id: id.clone(),
+            similar_ids: Default::default(),
+            id_like: Vec::new(),
+            fields,
+            confidence: Confidence::Heuristic,
+            source: None,
+            warnings: Vec::new()
+        }.with_source_in(fs, path))
+    })
+}
+
+/// Guess distro family using package database files
+/// 
+/// Root distro of the family is returned (Debian for dpkg, Fedora for rpm)
+pub(crate) fn identify_package_db(fs: &impl FileSystem) -> Option<Distro> {
+    let (path, id) = PACKAGE_DATABASES.iter()
+        .find(|(path, _)| fs.exists(Path::new(path)))?;
+
+    trace!("guessing distro family from {path}");
+
+    let mut fields = HashMap::new();
+
+    fields.insert(String::from("ID"), id.to_string());
+    fields.insert(String::from("NAME"), id.name().to_string());
+
+    Some(Distro {
+        id: id.clone(),
+        similar_ids: Default::default(),
+        id_like: Vec::new(),
+        fields,
+        confidence: Confidence::Heuristic,
+        source: None,
+        warnings: Vec::new()
+    }.with_source_in(fs, path))
+}
+
+#[inline]
+/// Check if any of the known package databases exists
+pub(crate) fn has_package_db(fs: &impl FileSystem) -> bool {
+    PACKAGE_DATABASES.iter().any(|(path, _)| fs.exists(Path::new(path)))
+}
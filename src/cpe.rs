@@ -0,0 +1,216 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Part of the CPE identifier describing the kind of the platform
+pub enum CpePart {
+    /// `a` - application
+    Application,
+
+    /// `o` - operating system
+    OperatingSystem,
+
+    /// `h` - hardware
+    Hardware
+}
+
+impl CpePart {
+    fn parse(part: &str) -> Option<Self> {
+        match part {
+            "a" => Some(Self::Application),
+            "o" => Some(Self::OperatingSystem),
+            "h" => Some(Self::Hardware),
+
+            _ => None
+        }
+    }
+}
+
+impl Display for CpePart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Application     => write!(f, "a"),
+            Self::OperatingSystem => write!(f, "o"),
+            Self::Hardware        => write!(f, "h")
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Common Platform Enumeration identifier (`CPE_NAME` entry)
+/// 
+/// Both CPE 2.2 URI (`cpe:/o:fedoraproject:fedora:40`) and CPE 2.3
+/// formatted string (`cpe:2.3:o:amazon:amazon_linux:2023`) bindings are supported
+pub struct Cpe {
+    raw: String,
+    part: CpePart,
+    vendor: String,
+    product: String,
+    version: Option<String>,
+    update: Option<String>,
+    edition: Option<String>
+}
+
+impl Cpe {
+    /// Parse CPE identifier
+    /// 
+    /// ```
+    /// use whatadistro::{Cpe, CpePart};
+    /// 
+    /// let cpe = Cpe::parse("cpe:/o:redhat:enterprise_linux:9::baseos").unwrap();
+    /// 
+    /// assert_eq!(cpe.part(), CpePart::OperatingSystem);
+    /// assert_eq!(cpe.vendor(), "redhat");
+    /// assert_eq!(cpe.product(), "enterprise_linux");
+    /// assert_eq!(cpe.version(), Some("9"));
+    /// assert_eq!(cpe.update(), None);
+    /// assert_eq!(cpe.edition(), Some("baseos"));
+    /// 
+    /// let cpe = Cpe::parse("cpe:2.3:o:amazon:amazon_linux:2023").unwrap();
+    /// 
+    /// assert_eq!(cpe.product(), "amazon_linux");
+    /// assert_eq!(cpe.version(), Some("2023"));
+    /// ```
+    pub fn parse(cpe: impl AsRef<str>) -> Option<Self> {
+        let raw = cpe.as_ref().trim();
+
+        let components = if let Some(components) = raw.strip_prefix("cpe:2.3:") {
+            split_escaped(components)
+        }
+
+        else {
+            raw.strip_prefix("cpe:/")?
+                .split(':')
+                .map(percent_decode)
+                .collect()
+        };
+
+        let mut components = components.into_iter();
+
+        let part = CpePart::parse(&components.next()?)?;
+        let vendor = components.next().and_then(normalize)?;
+        let product = components.next().and_then(normalize)?;
+
+        Some(Self {
+            raw: raw.to_string(),
+            part,
+            vendor,
+            product,
+            version: components.next().and_then(normalize),
+            update: components.next().and_then(normalize),
+            edition: components.next().and_then(normalize)
+        })
+    }
+
+    #[inline]
+    /// Get original CPE string
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    #[inline]
+    /// Get kind of the platform
+    pub fn part(&self) -> CpePart {
+        self.part
+    }
+
+    #[inline]
+    /// Get vendor name (e.g. `fedoraproject`)
+    pub fn vendor(&self) -> &str {
+        &self.vendor
+    }
+
+    #[inline]
+    /// Get product name (e.g. `fedora`)
+    pub fn product(&self) -> &str {
+        &self.product
+    }
+
+    #[inline]
+    /// Get product version (e.g. `40`)
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    #[inline]
+    /// Get product update
+    pub fn update(&self) -> Option<&str> {
+        self.update.as_deref()
+    }
+
+    #[inline]
+    /// Get product edition (e.g. `baseos`)
+    pub fn edition(&self) -> Option<&str> {
+        self.edition.as_deref()
+    }
+}
+
+impl Display for Cpe {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+/// Remove empty and "any" / "not applicable" values
+fn normalize(value: String) -> Option<String> {
+    match value.as_str() {
+        "" | "*" | "-" => None,
+
+        _ => Some(value)
+    }
+}
+
+/// Split CPE 2.3 formatted string by unescaped colons
+fn split_escaped(components: &str) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut current = String::new();
+
+    let mut chars = components.chars();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => current.extend(chars.next()),
+            ':'  => result.push(std::mem::take(&mut current)),
+
+            char => current.push(char)
+        }
+    }
+
+    result.push(current);
+
+    result
+}
+
+/// Decode percent-encoded characters from CPE 2.2 URI
+fn percent_decode(component: &str) -> String {
+    let mut bytes = Vec::with_capacity(component.len());
+    let mut iter = component.bytes();
+
+    while let Some(byte) = iter.next() {
+        if byte != b'%' {
+            bytes.push(byte);
+
+            continue;
+        }
+
+        let hex = [iter.next(), iter.next()];
+
+        let decoded = match hex {
+            [Some(high), Some(low)] => std::str::from_utf8(&[high, low]).ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok()),
+
+            _ => None
+        };
+
+        match decoded {
+            Some(decoded) => bytes.push(decoded),
+
+            None => {
+                bytes.push(b'%');
+                bytes.extend(hex.iter().flatten());
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
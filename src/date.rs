@@ -0,0 +1,161 @@
+use std::fmt::Display;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Calendar date used in os-release fields (e.g. `SUPPORT_END`)
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8
+}
+
+impl Date {
+    /// Create new date. Returns `None` if the date doesn't exist
+    /// 
+    /// ```
+    /// use whatadistro::Date;
+    /// 
+    /// assert!(Date::new(2024, 2, 29).is_some());
+    /// assert!(Date::new(2023, 2, 29).is_none());
+    /// ```
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        Some(Self {
+            year,
+            month,
+            day
+        })
+    }
+
+    /// Parse date in `YYYY-MM-DD` format
+    /// 
+    /// ```
+    /// use whatadistro::Date;
+    /// 
+    /// assert_eq!(Date::parse("2024-05-14"), Date::new(2024, 5, 14));
+    /// assert_eq!(Date::parse("2024-13-01"), None);
+    /// ```
+    pub fn parse(date: &str) -> Option<Self> {
+        let mut parts = date.trim().splitn(3, '-');
+
+        let year = parts.next()?;
+        let month = parts.next()?;
+        let day = parts.next()?;
+
+        if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return None;
+        }
+
+        Self::new(year.parse().ok()?, month.parse().ok()?, day.parse().ok()?)
+    }
+
+    /// Get current date (UTC)
+    pub fn today() -> Self {
+        let days = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs() / 86400)
+            .unwrap_or_default();
+
+        Self::from_days_since_epoch(days as i64)
+    }
+
+    #[inline]
+    /// Get year number
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    #[inline]
+    /// Get month number (1-12)
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    #[inline]
+    /// Get day of month (1-31)
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Get number of days since `1970-01-01`
+    pub fn days_since_epoch(&self) -> i64 {
+        // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let year = self.year as i64 - (self.month <= 2) as i64;
+        let month = self.month as i64;
+        let day = self.day as i64;
+
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146097 + day_of_era - 719468
+    }
+
+    /// Create date from the number of days since `1970-01-01`
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = days + 719468;
+
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+        let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+        Self {
+            year: year.clamp(0, u16::MAX as i64) as u16,
+            month: month as u8,
+            day: day as u8
+        }
+    }
+
+    #[inline]
+    /// Get number of days from the current date until the given one.
+    /// Negative if the given date is in the past
+    /// 
+    /// ```
+    /// use whatadistro::Date;
+    /// 
+    /// let a = Date::new(2024, 2, 28).unwrap();
+    /// let b = Date::new(2024, 3, 1).unwrap();
+    /// 
+    /// assert_eq!(a.days_until(&b), 2);
+    /// assert_eq!(b.days_until(&a), -2);
+    /// ```
+    pub fn days_until(&self, other: &Self) -> i64 {
+        other.days_since_epoch() - self.days_since_epoch()
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    let is_leap = match (year % 4, year % 100, year % 400) {
+        (_, _, 0) => true,
+        (_, 0, _) => false,
+        (0, _, _) => true,
+
+        _ => false
+    };
+
+    match month {
+        2 if is_leap => 29,
+        2 => 28,
+
+        4 | 6 | 9 | 11 => 30,
+
+        _ => 31
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+
+use crate::{Distro, DistroId};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Differences between two distro infos
+/// 
+/// Changed values are stored as `(old, new)` pairs
+pub struct DistroDiff {
+    id: Option<(DistroId, DistroId)>,
+    version_id: Option<(Option<String>, Option<String>)>,
+    version_codename: Option<(Option<String>, Option<String>)>,
+    added_similar_ids: HashSet<DistroId>,
+    removed_similar_ids: HashSet<DistroId>
+}
+
+impl DistroDiff {
+    #[inline]
+    /// Get old and new distro ids if they're different
+    pub fn id(&self) -> Option<(&DistroId, &DistroId)> {
+        self.id.as_ref().map(|(old, new)| (old, new))
+    }
+
+    #[inline]
+    /// Get old and new `VERSION_ID` values if they're different
+    pub fn version_id(&self) -> Option<(Option<&str>, Option<&str>)> {
+        self.version_id.as_ref().map(|(old, new)| (old.as_deref(), new.as_deref()))
+    }
+
+    #[inline]
+    /// Get old and new `VERSION_CODENAME` values if they're different
+    pub fn version_codename(&self) -> Option<(Option<&str>, Option<&str>)> {
+        self.version_codename.as_ref().map(|(old, new)| (old.as_deref(), new.as_deref()))
+    }
+
+    #[inline]
+    /// Get similar distro ids which are present only in the new distro info
+    pub fn added_similar_ids(&self) -> &HashSet<DistroId> {
+        &self.added_similar_ids
+    }
+
+    #[inline]
+    /// Get similar distro ids which are present only in the old distro info
+    pub fn removed_similar_ids(&self) -> &HashSet<DistroId> {
+        &self.removed_similar_ids
+    }
+
+    #[inline]
+    /// Check if nothing has changed
+    pub fn is_empty(&self) -> bool {
+        self.id.is_none() &&
+        self.version_id.is_none() &&
+        self.version_codename.is_none() &&
+        self.added_similar_ids.is_empty() &&
+        self.removed_similar_ids.is_empty()
+    }
+}
+
+/// Store `(old, new)` pair if values are different
+fn changed<T: PartialEq>(old: T, new: T) -> Option<(T, T)> {
+    if old == new {
+        None
+    }
+
+    else {
+        Some((old, new))
+    }
+}
+
+impl Distro {
+    /// Compare current distro info with another one
+    /// 
+    /// Current distro is treated as the old one
+    /// 
+    /// ```
+    /// use whatadistro::Distro;
+    /// 
+    /// let old = Distro::from_os_release("NAME=Debian\nID=debian\nVERSION_ID=11\nVERSION_CODENAME=bullseye").unwrap();
+    /// let new = Distro::from_os_release("NAME=Debian\nID=debian\nVERSION_ID=12\nVERSION_CODENAME=bookworm").unwrap();
+    /// 
+    /// let diff = old.diff(&new);
+    /// 
+    /// assert_eq!(diff.id(), None);
+    /// assert_eq!(diff.version_id(), Some((Some("11"), Some("12"))));
+    /// assert_eq!(diff.version_codename(), Some((Some("bullseye"), Some("bookworm"))));
+    /// 
+    /// assert!(old.diff(&old).is_empty());
+    /// ```
+    pub fn diff(&self, other: &Distro) -> DistroDiff {
+        let string = |value: Option<&str>| value.map(String::from);
+
+        DistroDiff {
+            id: changed(&self.id, &other.id)
+                .map(|(old, new)| (old.clone(), new.clone())),
+
+            version_id: changed(string(self.version_id()), string(other.version_id())),
+            version_codename: changed(string(self.version_codename()), string(other.version_codename())),
+
+            added_similar_ids: other.similar_ids.difference(&self.similar_ids)
+                .cloned()
+                .collect(),
+
+            removed_similar_ids: self.similar_ids.difference(&other.similar_ids)
+                .cloned()
+                .collect()
+        }
+    }
+}
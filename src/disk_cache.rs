@@ -0,0 +1,254 @@
+use std::fmt::Write;
+use std::io;
+use std::path::PathBuf;
+use std::time::UNIX_EPOCH;
+
+use crate::{Distro, Confidence, os_release, overrides, platform};
+
+/// Version of the cache file format
+const CACHE_VERSION: &str = "1";
+
+/// Prefix of the cache metadata keys
+const META_PREFIX: &str = "WHATADISTRO_CACHE_";
+
+/// Files the identification result depends on
+const SOURCE_FILES: &[&str] = &[
+    "/etc/os-release",
+    "/etc/os-release.d",
+    "/usr/lib/os-release",
+    "/etc/lsb-release",
+    "/etc/fedora-release",
+    "/etc/redhat-release",
+    "/etc/gentoo-release",
+    "/etc/SuSE-release",
+    "/etc/arch-release",
+    "/etc/debian_version"
+];
+
+/// Get path to the cache file
+/// 
+/// `$XDG_CACHE_HOME/whatadistro.cache` or `~/.cache/whatadistro.cache`
+fn cache_path() -> Option<PathBuf> {
+    let dir = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| {
+            let home = PathBuf::from(std::env::var_os("HOME")?);
+
+            Some(home.join(".cache"))
+        })?;
+
+    Some(dir.join("whatadistro.cache"))
+}
+
+/// Get modification times and sizes of the source files
+fn fingerprint() -> String {
+    SOURCE_FILES.iter()
+        .map(|path| {
+            let state = std::fs::metadata(path).ok()
+                .and_then(|metadata| {
+                    let modified = metadata.modified().ok()?
+                        .duration_since(UNIX_EPOCH).ok()?
+                        .as_nanos();
+
+                    Some(format!("{modified}:{}", metadata.len()))
+                })
+                .unwrap_or_else(|| String::from("-"));
+
+            format!("{path}={state}")
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Quote value for the os-release format
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+
+    quoted.push('"');
+
+    for char in value.chars() {
+        if matches!(char, '"' | '\\' | '$' | '`') {
+            quoted.push('\\');
+        }
+
+        quoted.push(char);
+    }
+
+    quoted.push('"');
+
+    quoted
+}
+
+/// Serialize distro and fingerprint of its sources
+fn serialize(distro: &Distro, fingerprint: &str) -> String {
+    let mut content = String::from("# whatadistro identification cache, safe to remove\n");
+
+    let mut meta = vec![
+        ("VERSION", String::from(CACHE_VERSION)),
+        ("FINGERPRINT", fingerprint.to_string()),
+        ("CONFIDENCE", distro.confidence.to_string())
+    ];
+
+    if let Some(source) = &distro.source {
+        meta.push(("SOURCE", source.path().to_string_lossy().into_owned()));
+
+        if !source.drop_ins().is_empty() {
+            let drop_ins = source.drop_ins().iter()
+                .map(|path| path.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(":");
+
+            meta.push(("DROP_INS", drop_ins));
+        }
+    }
+
+    for (key, value) in meta {
+        let _ = writeln!(content, "{META_PREFIX}{key}={}", quote(&value));
+    }
+
+    let mut fields = distro.fields.iter()
+        .filter(|(key, _)| !key.starts_with(META_PREFIX))
+        .collect::<Vec<_>>();
+
+    fields.sort();
+
+    for (key, value) in fields {
+        let _ = writeln!(content, "{key}={}", quote(value));
+    }
+
+    content
+}
+
+/// Parse cached distro if it has the given fingerprint
+fn deserialize(content: &str, fingerprint: &str) -> Option<Distro> {
+    let mut distro = Distro::from_os_release(content)?;
+
+    let mut meta = |key: &str| distro.fields.remove(&format!("{META_PREFIX}{key}"));
+
+    if meta("VERSION").as_deref() != Some(CACHE_VERSION) {
+        trace!("cache file has unsupported version");
+
+        return None;
+    }
+
+    if meta("FINGERPRINT").as_deref() != Some(fingerprint) {
+        trace!("source files were changed, cache is outdated");
+
+        return None;
+    }
+
+    let confidence = match meta("CONFIDENCE")?.as_str() {
+        "exact"     => Confidence::Exact,
+        "high"      => Confidence::High,
+        "heuristic" => Confidence::Heuristic,
+
+        _ => return None
+    };
+
+    let source = meta("SOURCE");
+
+    let drop_ins = meta("DROP_INS")
+        .map(|drop_ins| drop_ins.split(':').map(PathBuf::from).collect())
+        .unwrap_or_default();
+
+    distro.confidence = confidence;
+    distro.warnings = Vec::new();
+
+    match source {
+        Some(source) => Some(distro.with_source(source).with_drop_ins(drop_ins)),
+        None => Some(distro)
+    }
+}
+
+/// Write cache file atomically
+fn write_cache(path: &PathBuf, content: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let temp = path.with_extension(format!("cache.{}", std::process::id()));
+
+    std::fs::write(&temp, content)?;
+
+    if let Err(err) = std::fs::rename(&temp, path) {
+        let _ = std::fs::remove_file(&temp);
+
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+/// Identify current linux distro using a persistent cache file
+/// 
+/// Works like `identify()` but stores the result in
+/// `$XDG_CACHE_HOME/whatadistro.cache` (`~/.cache/whatadistro.cache`).
+/// Cache is invalidated when modification time or size of any
+/// os-release, lsb-release or legacy release file changes.
+/// Useful for short-lived tools invoked very often, like shell
+/// prompt generators. Parse warnings are not cached
+/// 
+/// ```
+/// if let Some(distro) = whatadistro::identify_with_disk_cache() {
+///     println!("{}", distro.name());
+/// }
+/// ```
+pub fn identify_with_disk_cache() -> Option<Distro> {
+    if let Some(distro) = overrides::get_override() {
+        trace!("using distro override");
+
+        return Some(distro);
+    }
+
+    #[cfg(feature = "static-target")]
+    if let Some(distro) = crate::static_target::static_distro() {
+        trace!("using static target distro");
+
+        return Some(distro);
+    }
+
+    let Some(path) = cache_path() else {
+        trace!("cache directory is unknown, identifying without cache");
+
+        return platform::identify_system();
+    };
+
+    let fingerprint = fingerprint();
+
+    let cached = os_release::read_lossy(&path).ok()
+        .and_then(|content| deserialize(&content, &fingerprint));
+
+    if let Some(distro) = cached {
+        trace!("using cached distro from {path:?}");
+
+        return Some(distro);
+    }
+
+    let distro = platform::identify_system()?;
+
+    if let Err(err) = write_cache(&path, &serialize(&distro, &fingerprint)) {
+        trace!("failed to write cache file {path:?}: {err}");
+    }
+
+    Some(distro)
+}
+
+/// Remove the persistent cache file used by `identify_with_disk_cache`
+/// 
+/// ```
+/// if let Err(err) = whatadistro::clear_disk_cache() {
+///     eprintln!("Failed to remove cache: {err}");
+/// }
+/// ```
+pub fn clear_disk_cache() -> io::Result<()> {
+    let Some(path) = cache_path() else {
+        return Ok(());
+    };
+
+    match std::fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+
+        _ => Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{Distro, DistroFamily};
+
+impl Distro {
+    /// Get family of the distro
+    /// 
+    /// If the distro itself is unknown then families
+    /// of the `ID_LIKE` entries are used
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, DistroFamily};
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME="Zorin OS"
+    ///     ID=zorin
+    ///     ID_LIKE="ubuntu debian"
+    /// "#).unwrap();
+    /// 
+    /// assert_eq!(distro.family(), DistroFamily::Debian);
+    /// ```
+    pub fn family(&self) -> DistroFamily {
+        let family = self.id().family();
+
+        if family != DistroFamily::Other {
+            return family;
+        }
+
+        self.similar_ids()
+            .iter()
+            .map(|id| id.family())
+            .find(|family| family != &DistroFamily::Other)
+            .unwrap_or(DistroFamily::Other)
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+/// Get `DistroFamily` variant for the `distro_match!` arm name
+macro_rules! __distro_match_family {
+    (arch_like)   => { $crate::DistroFamily::Arch };
+    (debian_like) => { $crate::DistroFamily::Debian };
+    (redhat_like) => { $crate::DistroFamily::RedHat };
+    (gentoo_like) => { $crate::DistroFamily::Gentoo };
+    (nixos_like)  => { $crate::DistroFamily::NixOS };
+    (alpine_like) => { $crate::DistroFamily::Alpine };
+
+    ($other:ident) => {
+        compile_error!(concat!(
+            "unknown distro family `", stringify!($other), "`, expected one of ",
+            "arch_like, debian_like, redhat_like, gentoo_like, nixos_like, alpine_like"
+        ))
+    };
+}
+
+#[macro_export]
+/// Run code depending on the current distro family
+/// 
+/// Family is detected at runtime using `identify()`. Arms are
+/// `arch_like`, `debian_like`, `redhat_like`, `gentoo_like`, `nixos_like`
+/// and `alpine_like`, and can be combined with `|`. The last `_` arm is
+/// used for other families and when the distro can't be identified
+/// 
+/// ```
+/// let command = whatadistro::distro_match! {
+///     debian_like => "apt install git",
+///     arch_like => "pacman -S git",
+///     redhat_like | alpine_like => {
+///         "dnf install git or apk add git"
+///     },
+///     _ => "install git manually"
+/// };
+/// 
+/// println!("Run: {command}");
+/// ```
+macro_rules! distro_match {
+    ($($($family:ident)|+ => $body:expr),+ , _ => $default:expr $(,)?) => {
+        match $crate::identify().map(|distro| distro.family()) {
+            $(
+                Some($($crate::__distro_match_family!($family))|+) => $body,
+            )+
+
+            _ => $default
+        }
+    };
+}
@@ -0,0 +1,197 @@
+use std::path::Path;
+
+use crate::{Distro, DistroId, DistroFamily, FileSystem, RealFs};
+use crate::privilege::in_path_with_fs;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Availability of DKMS and kernel headers needed to build kernel modules
+pub struct DkmsSupport {
+    kernel_release: Option<String>,
+    has_dkms: bool,
+    has_headers: bool,
+    dkms_package: Option<&'static str>,
+    headers_package: Option<String>
+}
+
+impl DkmsSupport {
+    #[inline]
+    /// Get running kernel release (`uname -r`)
+    pub fn kernel_release(&self) -> Option<&str> {
+        self.kernel_release.as_deref()
+    }
+
+    #[inline]
+    /// Check if `dkms` binary is available
+    pub fn has_dkms(&self) -> bool {
+        self.has_dkms
+    }
+
+    #[inline]
+    /// Check if headers of the running kernel are installed
+    /// (`/lib/modules/<release>/build` exists)
+    pub fn has_headers(&self) -> bool {
+        self.has_headers
+    }
+
+    #[inline]
+    /// Check if kernel modules can be built
+    pub fn is_ready(&self) -> bool {
+        self.has_dkms && self.has_headers
+    }
+
+    #[inline]
+    /// Get name of the dkms package for the distro
+    pub fn dkms_package(&self) -> Option<&'static str> {
+        self.dkms_package
+    }
+
+    #[inline]
+    /// Get name of the kernel headers package for the running kernel
+    pub fn headers_package(&self) -> Option<&str> {
+        self.headers_package.as_deref()
+    }
+
+    /// List packages which should be installed to build kernel modules
+    /// 
+    /// Packages which names are unknown for the distro are not listed
+    pub fn missing_packages(&self) -> Vec<&str> {
+        let mut packages = Vec::new();
+
+        if !self.has_dkms {
+            packages.extend(self.dkms_package);
+        }
+
+        if !self.has_headers {
+            packages.extend(self.headers_package());
+        }
+
+        packages
+    }
+}
+
+impl Distro {
+    /// Get name of the dkms package
+    fn dkms_package(&self) -> Option<&'static str> {
+        match self.family() {
+            DistroFamily::Gentoo => Some("sys-kernel/dkms"),
+
+            // Alpine uses its own akms tool instead
+            DistroFamily::Alpine | DistroFamily::NixOS | DistroFamily::Other => None,
+
+            _ => Some("dkms")
+        }
+    }
+
+    /// Get name of the kernel headers package for the given kernel release
+    /// 
+    /// ```
+    /// use whatadistro::Distro;
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Ubuntu\nID=ubuntu").unwrap();
+    /// 
+    /// assert_eq!(distro.kernel_headers_package("6.8.0-45-generic").as_deref(), Some("linux-headers-6.8.0-45-generic"));
+    /// 
+    /// let distro = Distro::from_os_release("NAME=\"Arch Linux\"\nID=arch").unwrap();
+    /// 
+    /// assert_eq!(distro.kernel_headers_package("6.10.10-arch1-1").as_deref(), Some("linux-headers"));
+    /// assert_eq!(distro.kernel_headers_package("6.6.52-1-lts").as_deref(), Some("linux-lts-headers"));
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Fedora\nID=fedora").unwrap();
+    /// 
+    /// assert_eq!(distro.kernel_headers_package("6.10.11-200.fc40.x86_64").as_deref(), Some("kernel-devel-6.10.11-200.fc40.x86_64"));
+    /// ```
+    pub fn kernel_headers_package(&self, kernel_release: &str) -> Option<String> {
+        match self.id() {
+            DistroId::OpenSUSE => return Some(String::from("kernel-default-devel")),
+
+            // Manjaro kernels are named by their version (linux66)
+            DistroId::Manjaro => {
+                let mut version = kernel_release.split('.');
+
+                let major = version.next()?;
+                let minor = version.next()?;
+
+                return Some(format!("linux{major}{minor}-headers"));
+            }
+
+            _ => ()
+        }
+
+        match self.family() {
+            DistroFamily::Debian => Some(format!("linux-headers-{kernel_release}")),
+            DistroFamily::RedHat => Some(format!("kernel-devel-{kernel_release}")),
+            DistroFamily::Gentoo => Some(String::from("sys-kernel/gentoo-sources")),
+
+            DistroFamily::Arch => {
+                let flavor = ["lts", "zen", "hardened", "rt"].iter()
+                    .find(|flavor| kernel_release.ends_with(&format!("-{flavor}")));
+
+                match flavor {
+                    Some(flavor) => Some(format!("linux-{flavor}-headers")),
+                    None => Some(String::from("linux-headers"))
+                }
+            }
+
+            DistroFamily::Alpine => {
+                let flavor = kernel_release.rsplit('-').next()?;
+
+                Some(format!("linux-{flavor}-dev"))
+            }
+
+            DistroFamily::NixOS | DistroFamily::Other => None
+        }
+    }
+
+    /// Check if kernel modules can be built using DKMS
+    /// 
+    /// ```
+    /// if let Some(distro) = whatadistro::identify() {
+    ///     let support = distro.dkms_support();
+    /// 
+    ///     if !support.is_ready() {
+    ///         println!("Install packages: {}", support.missing_packages().join(" "));
+    ///     }
+    /// }
+    /// ```
+    pub fn dkms_support(&self) -> DkmsSupport {
+        self.dkms_support_with_fs(&RealFs)
+    }
+
+    /// Check if kernel modules can be built using DKMS
+    /// using files of the given filesystem
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, MockFs};
+    /// 
+    /// let distro = Distro::from_os_release("NAME=\"Arch Linux\"\nID=arch").unwrap();
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/proc/sys/kernel/osrelease", "6.9.7-arch1-1\n")
+    ///     .with_file("/lib/modules/6.9.7-arch1-1/build/Makefile", "");
+    /// 
+    /// let support = distro.dkms_support_with_fs(&fs);
+    /// 
+    /// assert_eq!(support.kernel_release(), Some("6.9.7-arch1-1"));
+    /// assert!(support.has_headers());
+    /// ```
+    pub fn dkms_support_with_fs(&self, fs: &impl FileSystem) -> DkmsSupport {
+        let kernel_release = fs.read_to_string(Path::new("/proc/sys/kernel/osrelease")).ok()
+            .map(|release| release.trim().to_string());
+
+        if kernel_release.is_none() {
+            trace!("failed to read kernel release");
+        }
+
+        let has_headers = kernel_release.as_ref()
+            .map(|release| fs.exists(&Path::new("/lib/modules").join(release).join("build")))
+            .unwrap_or(false);
+
+        DkmsSupport {
+            has_dkms: in_path_with_fs(fs, "dkms"),
+            has_headers,
+            dkms_package: self.dkms_package(),
+            headers_package: kernel_release.as_deref().and_then(|release| self.kernel_headers_package(release)),
+            kernel_release
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use std::path::Path;
+
+use crate::{SystemInfo, FileSystem, RealFs};
+
+/// Directory with DMI (SMBIOS) data exported by the kernel
+const DMI_DIR: &str = "/sys/class/dmi/id";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+/// Hardware vendor and model info from the firmware DMI tables
+/// 
+/// Values are returned as reported by the firmware, with
+/// surrounding whitespace trimmed. Placeholders like
+/// `To Be Filled By O.E.M.` are kept as is
+pub struct DmiInfo {
+    sys_vendor: Option<String>,
+    product_name: Option<String>,
+    product_version: Option<String>,
+    board_vendor: Option<String>,
+    board_name: Option<String>,
+    bios_vendor: Option<String>
+}
+
+impl DmiInfo {
+    /// Read DMI info from the DMI directory
+    fn read(fs: &impl FileSystem, dir: &Path) -> Self {
+        let read = |name: &str| {
+            let path = dir.join(name);
+
+            match fs.read_to_string(&path) {
+                Ok(value) => Some(value.trim().to_string())
+                    .filter(|value| !value.is_empty()),
+
+                // Some fields are readable only by root on hardened kernels
+                Err(err) => {
+                    trace!("failed to read {path:?}: {err}");
+
+                    None
+                }
+            }
+        };
+
+        Self {
+            sys_vendor: read("sys_vendor"),
+            product_name: read("product_name"),
+            product_version: read("product_version"),
+            board_vendor: read("board_vendor"),
+            board_name: read("board_name"),
+            bios_vendor: read("bios_vendor")
+        }
+    }
+
+    #[inline]
+    /// Get system manufacturer (`LENOVO`, `Valve`, `QEMU`)
+    pub fn sys_vendor(&self) -> Option<&str> {
+        self.sys_vendor.as_deref()
+    }
+
+    #[inline]
+    /// Get product name (`20XW0055US`, `Jupiter`)
+    pub fn product_name(&self) -> Option<&str> {
+        self.product_name.as_deref()
+    }
+
+    #[inline]
+    /// Get product version (`ThinkPad X1 Carbon Gen 9`)
+    pub fn product_version(&self) -> Option<&str> {
+        self.product_version.as_deref()
+    }
+
+    #[inline]
+    /// Get motherboard manufacturer
+    pub fn board_vendor(&self) -> Option<&str> {
+        self.board_vendor.as_deref()
+    }
+
+    #[inline]
+    /// Get motherboard name
+    pub fn board_name(&self) -> Option<&str> {
+        self.board_name.as_deref()
+    }
+
+    #[inline]
+    /// Get firmware vendor (`American Megatrends Inc.`, `SeaBIOS`)
+    pub fn bios_vendor(&self) -> Option<&str> {
+        self.bios_vendor.as_deref()
+    }
+}
+
+impl SystemInfo {
+    #[inline]
+    /// Get hardware vendor and model info
+    /// 
+    /// Returns `None` if DMI data is not available
+    /// (e.g. on most ARM boards or inside of sandboxes)
+    /// 
+    /// ```
+    /// use whatadistro::SystemInfo;
+    /// 
+    /// if let Some(dmi) = SystemInfo::dmi() {
+    ///     println!("{} {}", dmi.sys_vendor().unwrap_or("Unknown"), dmi.product_name().unwrap_or("Unknown"));
+    /// }
+    /// ```
+    pub fn dmi() -> Option<DmiInfo> {
+        Self::dmi_with_fs(&RealFs)
+    }
+
+    /// Get hardware vendor and model info from the given filesystem
+    /// 
+    /// ```
+    /// use whatadistro::{SystemInfo, MockFs};
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/sys/class/dmi/id/sys_vendor", "Valve\n")
+    ///     .with_file("/sys/class/dmi/id/product_name", "Jupiter\n");
+    /// 
+    /// let dmi = SystemInfo::dmi_with_fs(&fs).unwrap();
+    /// 
+    /// assert_eq!(dmi.sys_vendor(), Some("Valve"));
+    /// assert_eq!(dmi.product_name(), Some("Jupiter"));
+    /// ```
+    pub fn dmi_with_fs(fs: &impl FileSystem) -> Option<DmiInfo> {
+        let dmi = DmiInfo::read(fs, Path::new(DMI_DIR));
+
+        if dmi == DmiInfo::default() {
+            trace!("{DMI_DIR} is not available");
+
+            return None;
+        }
+
+        Some(dmi)
+    }
+}
@@ -0,0 +1,61 @@
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+use crate::FileSystem;
+
+/// Get directory with drop-in files of the os-release file
+/// (`/etc/os-release` -> `/etc/os-release.d`)
+fn drop_ins_dir(path: &Path) -> Option<PathBuf> {
+    let name = path.file_name()?.to_str()?;
+
+    Some(path.with_file_name(format!("{name}.d")))
+}
+
+/// List `*.conf` drop-in files of the os-release file sorted by name
+pub(crate) fn list(fs: &impl FileSystem, path: &Path) -> Vec<PathBuf> {
+    let Some(dir) = drop_ins_dir(path) else {
+        return Vec::new();
+    };
+
+    let Ok(entries) = fs.read_dir(&dir) else {
+        return Vec::new();
+    };
+
+    let mut drop_ins = entries.into_iter()
+        .filter(|entry| entry.extension().map(|ext| ext == "conf").unwrap_or(false))
+        .collect::<Vec<_>>();
+
+    drop_ins.sort();
+
+    drop_ins
+}
+
+/// Append drop-in files to the os-release file content
+/// 
+/// Later files win per key because the last value of
+/// a key is used. Returns merged content and read drop-ins
+pub(crate) fn merge<'a>(fs: &impl FileSystem, path: &Path, content: &'a str) -> (Cow<'a, str>, Vec<PathBuf>) {
+    let mut merged = Cow::Borrowed(content);
+    let mut drop_ins = Vec::new();
+
+    for drop_in in list(fs, path) {
+        match fs.read_to_string(&drop_in) {
+            Ok(drop_in_content) => {
+                trace!("merging drop-in {drop_in:?}");
+
+                let merged = merged.to_mut();
+
+                merged.push('\n');
+                merged.push_str(&drop_in_content);
+
+                drop_ins.push(drop_in);
+            }
+
+            Err(err) => {
+                trace!("failed to read drop-in {drop_in:?}: {err}");
+            }
+        }
+    }
+
+    (merged, drop_ins)
+}
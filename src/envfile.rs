@@ -0,0 +1,66 @@
+//! Parser of env-file style configs (`KEY=VALUE` lines with shell-like quoting)
+//! 
+//! It's the same parser used for os-release files, so it can read
+//! `/etc/default/*`, `/etc/locale.conf` and other similar files
+//! 
+//! ```
+//! use whatadistro::envfile;
+//! 
+//! let config = envfile::parse(r#"
+//!     LANG="en_US.UTF-8"
+//!     LC_TIME='en_GB.UTF-8'
+//! "#);
+//! 
+//! assert_eq!(config["LANG"], "en_US.UTF-8");
+//! assert_eq!(config["LC_TIME"], "en_GB.UTF-8");
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::os_release;
+
+/// Parse env-file content into key-value pairs
+/// 
+/// Values can be unquoted, single quoted or double quoted with
+/// shell-like escape sequences (`\"`, `\\`, `\$`, `` \` ``).
+/// Empty lines, comments and lines with invalid keys are skipped.
+/// If the same key is specified multiple times then the last value is kept
+/// 
+/// ```
+/// use whatadistro::envfile;
+/// 
+/// let config = envfile::parse(r#"
+///     #GRUB_TIMEOUT=10
+///     GRUB_TIMEOUT=5
+///     GRUB_CMDLINE_LINUX_DEFAULT="quiet splash \"nomodeset\""
+///     GRUB_TIMEOUT=0
+/// "#);
+/// 
+/// assert_eq!(config["GRUB_TIMEOUT"], "0");
+/// assert_eq!(config["GRUB_CMDLINE_LINUX_DEFAULT"], "quiet splash \"nomodeset\"");
+/// assert_eq!(config.len(), 2);
+/// ```
+pub fn parse(content: &str) -> BTreeMap<String, String> {
+    os_release::fields(content)
+        .map(|(key, value)| (key.to_string(), value.into_owned()))
+        .collect()
+}
+
+/// Read and parse env-file
+/// 
+/// Invalid UTF-8 sequences are replaced and only first
+/// megabyte of the file is read
+/// 
+/// ```no_run
+/// use whatadistro::envfile;
+/// 
+/// let locale = envfile::read("/etc/locale.conf").unwrap();
+/// 
+/// if let Some(lang) = locale.get("LANG") {
+///     println!("System language: {lang}");
+/// }
+/// ```
+pub fn read(path: impl AsRef<Path>) -> std::io::Result<BTreeMap<String, String>> {
+    Ok(parse(&os_release::read_lossy(path)?))
+}
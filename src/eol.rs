@@ -0,0 +1,183 @@
+use std::fmt::Display;
+
+use crate::{Distro, DistroId, Date};
+
+/// End of standard security support dates of major distro releases
+/// 
+/// Versions are matched against `VERSION_ID` either exactly or as a major
+/// version prefix, so `9` matches `VERSION_ID="9.3"`
+const EOL_DATES: &[(DistroId, &str, (u16, u8, u8))] = &[
+    (DistroId::Ubuntu, "16.04", (2021, 4, 30)),
+    (DistroId::Ubuntu, "18.04", (2023, 5, 31)),
+    (DistroId::Ubuntu, "20.04", (2025, 5, 31)),
+    (DistroId::Ubuntu, "22.04", (2027, 4, 30)),
+    (DistroId::Ubuntu, "23.10", (2024, 7, 11)),
+    (DistroId::Ubuntu, "24.04", (2029, 4, 30)),
+    (DistroId::Ubuntu, "24.10", (2025, 7, 10)),
+    (DistroId::Ubuntu, "25.04", (2026, 1, 15)),
+    (DistroId::Ubuntu, "25.10", (2026, 7, 9)),
+    (DistroId::Ubuntu, "26.04", (2031, 4, 30)),
+
+    (DistroId::Debian, "9",  (2020, 7, 6)),
+    (DistroId::Debian, "10", (2022, 9, 10)),
+    (DistroId::Debian, "11", (2024, 8, 14)),
+    (DistroId::Debian, "12", (2026, 6, 10)),
+    (DistroId::Debian, "13", (2028, 8, 9)),
+
+    (DistroId::Fedora, "36", (2023, 5, 16)),
+    (DistroId::Fedora, "37", (2023, 12, 5)),
+    (DistroId::Fedora, "38", (2024, 5, 21)),
+    (DistroId::Fedora, "39", (2024, 11, 26)),
+    (DistroId::Fedora, "40", (2025, 5, 13)),
+    (DistroId::Fedora, "41", (2025, 12, 15)),
+    (DistroId::Fedora, "42", (2026, 5, 13)),
+    (DistroId::Fedora, "43", (2026, 12, 9)),
+    (DistroId::Fedora, "44", (2027, 5, 19)),
+
+    (DistroId::RHEL, "7", (2024, 6, 30)),
+    (DistroId::RHEL, "8", (2029, 5, 31)),
+    (DistroId::RHEL, "9", (2032, 5, 31))
+];
+
+/// Releases and versions they should be upgraded to
+/// 
+/// Ubuntu LTS releases are upgraded to the next LTS,
+/// interim releases to the next release
+const UPGRADE_PATHS: &[(DistroId, &str, &str)] = &[
+    (DistroId::Ubuntu, "16.04", "18.04"),
+    (DistroId::Ubuntu, "18.04", "20.04"),
+    (DistroId::Ubuntu, "20.04", "22.04"),
+    (DistroId::Ubuntu, "22.04", "24.04"),
+    (DistroId::Ubuntu, "23.10", "24.04"),
+    (DistroId::Ubuntu, "24.04", "26.04"),
+    (DistroId::Ubuntu, "24.10", "25.04"),
+    (DistroId::Ubuntu, "25.04", "25.10"),
+    (DistroId::Ubuntu, "25.10", "26.04"),
+
+    (DistroId::Debian, "9",  "10"),
+    (DistroId::Debian, "10", "11"),
+    (DistroId::Debian, "11", "12"),
+    (DistroId::Debian, "12", "13"),
+
+    (DistroId::Fedora, "36", "37"),
+    (DistroId::Fedora, "37", "38"),
+    (DistroId::Fedora, "38", "39"),
+    (DistroId::Fedora, "39", "40"),
+    (DistroId::Fedora, "40", "41"),
+    (DistroId::Fedora, "41", "42"),
+    (DistroId::Fedora, "42", "43"),
+    (DistroId::Fedora, "43", "44")
+];
+
+/// Check if `VERSION_ID` value belongs to the given release
+fn matches_version(version_id: &str, version: &str) -> bool {
+    match version_id.strip_prefix(version) {
+        Some(rest) => rest.is_empty() || rest.starts_with('.'),
+        None => false
+    }
+}
+
+/// Find end of life date of the given release in the built-in table
+fn lookup(id: &DistroId, version_id: &str) -> Option<Date> {
+    EOL_DATES.iter()
+        .find(|(distro, version, _)| distro == id && matches_version(version_id, version))
+        .and_then(|(_, _, (year, month, day))| Date::new(*year, *month, *day))
+}
+
+impl Distro {
+    /// Get date when the current distro release stops receiving security updates
+    /// 
+    /// `SUPPORT_END` field is used if available, otherwise date is taken
+    /// from the built-in table of major Ubuntu, Debian, Fedora and RHEL releases
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, Date};
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Ubuntu\nID=ubuntu\nVERSION_ID=\"22.04\"").unwrap();
+    /// 
+    /// assert_eq!(distro.eol_date(), Date::new(2027, 4, 30));
+    /// ```
+    pub fn eol_date(&self) -> Option<Date> {
+        self.support_end().or_else(|| lookup(self.id(), self.version_id()?))
+    }
+
+    #[inline]
+    /// Check if the current distro release is out of support at the given date
+    /// 
+    /// Returns `None` if the end of life date is unknown
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, Date};
+    /// 
+    /// let distro = Distro::from_os_release("NAME=RHEL\nID=rhel\nVERSION_ID=\"8.9\"").unwrap();
+    /// 
+    /// assert_eq!(distro.is_eol(Date::new(2030, 1, 1).unwrap()), Some(true));
+    /// assert_eq!(distro.is_eol(Date::new(2024, 1, 1).unwrap()), Some(false));
+    /// ```
+    pub fn is_eol(&self, today: Date) -> Option<bool> {
+        self.eol_date().map(|eol| eol <= today)
+    }
+
+    #[inline]
+    /// Get number of days until the current distro release stops being supported.
+    /// Negative if support has already ended
+    /// 
+    /// Returns `None` if the end of life date is unknown
+    pub fn days_until_eol(&self) -> Option<i64> {
+        self.eol_date().map(|eol| Date::today().days_until(&eol))
+    }
+
+    /// Get release the current distro should be upgraded to
+    /// 
+    /// Taken from the built-in table of Ubuntu, Debian and Fedora releases.
+    /// Fedora is upgraded to the next release, Ubuntu LTS releases to
+    /// the next LTS. Returns `None` for the latest and unknown releases
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, DistroId};
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Ubuntu\nID=ubuntu\nVERSION_ID=\"22.04\"").unwrap();
+    /// let upgrade = distro.suggested_upgrade().unwrap();
+    /// 
+    /// assert_eq!(upgrade.id(), &DistroId::Ubuntu);
+    /// assert_eq!(upgrade.version_id(), "24.04");
+    /// assert_eq!(upgrade.to_string(), "Ubuntu 24.04");
+    /// ```
+    pub fn suggested_upgrade(&self) -> Option<SuggestedUpgrade> {
+        let version_id = self.version_id()?;
+
+        UPGRADE_PATHS.iter()
+            .find(|(distro, version, _)| distro == self.id() && matches_version(version_id, version))
+            .map(|(id, _, next)| SuggestedUpgrade {
+                id: id.clone(),
+                version_id: next
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Release the distro can be upgraded to (see `Distro::suggested_upgrade`)
+pub struct SuggestedUpgrade {
+    id: DistroId,
+    version_id: &'static str
+}
+
+impl SuggestedUpgrade {
+    #[inline]
+    /// Get id of the distro this release belongs to
+    pub fn id(&self) -> &DistroId {
+        &self.id
+    }
+
+    #[inline]
+    /// Get `VERSION_ID` of the release
+    pub fn version_id(&self) -> &'static str {
+        self.version_id
+    }
+}
+
+impl Display for SuggestedUpgrade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.id.name(), self.version_id)
+    }
+}
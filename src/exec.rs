@@ -0,0 +1,224 @@
+use std::cell::Cell;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "detect-exec")]
+use std::io::Read;
+
+#[cfg(feature = "detect-exec")]
+use std::process::Stdio;
+
+#[cfg(feature = "detect-exec")]
+use std::sync::mpsc;
+
+
+/// Time given to external commands before they're killed
+/// if no shorter budget is set by `with_timeout`
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(2);
+
+thread_local! {
+    /// Deadline of the innermost `with_timeout` call
+    static DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+
+    /// Whether commands are forbidden by a `without_exec` call
+    static DISABLED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Restore previous deadline even if the function panicked
+struct DeadlineGuard(Option<Instant>);
+
+impl Drop for DeadlineGuard {
+    fn drop(&mut self) {
+        DEADLINE.with(|deadline| deadline.set(self.0));
+    }
+}
+
+/// Limit time spent on external commands spawned inside of the function
+/// 
+/// Commands share the budget and are killed once it runs out, commands
+/// started after that are not spawned at all. Nested calls can only
+/// shorten the budget. `IdentifyOptions::timeout` uses it for detectors
+/// of `identify_with`. Without a budget commands are killed after 2 seconds
+/// 
+/// ```
+/// use std::time::Duration;
+/// 
+/// use whatadistro::IdentifyOptions;
+/// 
+/// // lsb_release command is killed after 300 ms
+/// let distro = whatadistro::with_timeout(Duration::from_millis(300), || {
+///     whatadistro::identify_with(&IdentifyOptions::default().no_exec(false))
+/// });
+/// ```
+pub fn with_timeout<T>(timeout: Duration, f: impl FnOnce() -> T) -> T {
+    let previous = DEADLINE.with(Cell::get);
+
+    // Too large timeouts can't be represented and mean no limit
+    let deadline = match (previous, Instant::now().checked_add(timeout)) {
+        (Some(previous), Some(deadline)) => Some(previous.min(deadline)),
+        (previous, deadline) => deadline.or(previous)
+    };
+
+    DEADLINE.with(|cell| cell.set(deadline));
+
+    let _guard = DeadlineGuard(previous);
+
+    f()
+}
+
+/// Restore previous exec switch even if the function panicked
+struct DisabledGuard(bool);
+
+impl Drop for DisabledGuard {
+    fn drop(&mut self) {
+        DISABLED.with(|disabled| disabled.set(self.0));
+    }
+}
+
+/// Never spawn external commands inside of the function
+/// 
+/// Detectors which need `lsb_release`, `ldd`, `python3`, `dpkg` or `rpm-ostree`
+/// commands report unknown values instead. Commands can't be allowed again
+/// by nested calls. `IdentifyOptions::no_exec` uses it for detectors of `identify_with`
+/// 
+/// ```
+/// use whatadistro::IdentifyOptions;
+/// 
+/// // lsb_release command is not run even though options allow it
+/// let distro = whatadistro::without_exec(|| {
+///     whatadistro::identify_with(&IdentifyOptions::default().no_exec(false))
+/// });
+/// ```
+pub fn without_exec<T>(f: impl FnOnce() -> T) -> T {
+    let previous = DISABLED.with(|disabled| disabled.replace(true));
+
+    let _guard = DisabledGuard(previous);
+
+    f()
+}
+
+/// Get time left for the next external command
+pub(crate) fn remaining() -> Duration {
+    DEADLINE.with(Cell::get)
+        .map(|deadline| deadline.saturating_duration_since(Instant::now()).min(DEFAULT_TIMEOUT))
+        .unwrap_or(DEFAULT_TIMEOUT)
+}
+
+#[cfg(feature = "system-info")]
+#[inline]
+/// Check if external commands can be spawned right now
+/// 
+/// Detectors use it to tell "missing" from "unknown" results
+pub(crate) fn is_allowed() -> bool {
+    cfg!(feature = "detect-exec") && !DISABLED.with(Cell::get) && !remaining().is_zero()
+}
+
+#[cfg(feature = "detect-exec")]
+/// Delay between checks of the child process status
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+#[cfg(feature = "detect-exec")]
+/// Run command and get its stdout if it succeeded in time
+/// 
+/// Command is killed once the timeout is reached. Output is read in a
+/// separate thread so the child can't block on a full pipe, and the thread
+/// is left detached if grandchildren keep the pipe open after the timeout
+pub(crate) fn stdout(command: &mut Command, timeout: Duration) -> Option<String> {
+    let program = command.get_program().to_string_lossy().into_owned();
+
+    if DISABLED.with(Cell::get) {
+        trace!("exec is disabled, {program} is not run");
+
+        return None;
+    }
+
+    if timeout.is_zero() {
+        trace!("time budget is exhausted, {program} is not run");
+
+        return None;
+    }
+
+    let mut child = match command.stdin(Stdio::null()).stdout(Stdio::piped()).stderr(Stdio::null()).spawn() {
+        Ok(child) => child,
+
+        Err(err) => {
+            trace!("failed to run {program}: {err}");
+
+            return None;
+        }
+    };
+
+    let mut pipe = child.stdout.take()?;
+
+    let (sender, receiver) = mpsc::channel();
+
+    let reader = std::thread::Builder::new().spawn(move || {
+        let mut stdout = Vec::new();
+
+        if pipe.read_to_end(&mut stdout).is_ok() {
+            let _ = sender.send(stdout);
+        }
+    });
+
+    if reader.is_err() {
+        trace!("failed to spawn {program} output reader");
+
+        let _ = child.kill();
+        let _ = child.wait();
+
+        return None;
+    }
+
+    // Too large timeouts can't be represented and mean no timeout
+    let deadline = Instant::now().checked_add(timeout);
+
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+
+            Ok(None) if deadline.map(|deadline| Instant::now() < deadline).unwrap_or(true) => std::thread::sleep(POLL_INTERVAL),
+
+            Ok(None) => {
+                trace!("{program} didn't finish in {timeout:?}, killing it");
+
+                let _ = child.kill();
+                let _ = child.wait();
+
+                return None;
+            }
+
+            Err(err) => {
+                trace!("failed to wait for {program}: {err}");
+
+                return None;
+            }
+        }
+    };
+
+    if !status.success() {
+        trace!("{program} failed with {status}");
+
+        return None;
+    }
+
+    let stdout = match deadline {
+        Some(deadline) => receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())).ok(),
+        None => receiver.recv().ok()
+    };
+
+    let Some(stdout) = stdout else {
+        trace!("{program} output wasn't closed in {timeout:?}");
+
+        return None;
+    };
+
+    Some(String::from_utf8_lossy(&stdout).into_owned())
+}
+
+#[cfg(not(feature = "detect-exec"))]
+/// Commands are never spawned without the `detect-exec` feature
+pub(crate) fn stdout(command: &mut Command, _timeout: Duration) -> Option<String> {
+    trace!("{} is not run because detect-exec feature is disabled", command.get_program().to_string_lossy());
+
+    None
+}
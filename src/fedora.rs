@@ -0,0 +1,173 @@
+use std::fmt::Display;
+
+use crate::{Distro, DistroId};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Fedora edition, spin or atomic desktop (`VARIANT_ID` field)
+pub enum FedoraVariant {
+    /// GNOME desktop edition
+    Workstation,
+
+    /// Server edition
+    Server,
+
+    /// KDE Plasma desktop
+    Kde,
+
+    /// Atomic GNOME desktop
+    Silverblue,
+
+    /// Atomic KDE Plasma desktop
+    Kinoite,
+
+    /// Atomic Sway desktop
+    Sericea,
+
+    /// Atomic Budgie desktop
+    Onyx,
+
+    /// Atomic COSMIC desktop
+    CosmicAtomic,
+
+    /// Auto-updating container host
+    CoreOS,
+
+    /// Image for IoT and edge devices
+    IoT,
+
+    /// Cloud image
+    Cloud,
+
+    /// Base container image
+    Container
+}
+
+impl FedoraVariant {
+    /// All known variants
+    pub const ALL: &'static [Self] = &[
+        Self::Workstation,
+        Self::Server,
+        Self::Kde,
+        Self::Silverblue,
+        Self::Kinoite,
+        Self::Sericea,
+        Self::Onyx,
+        Self::CosmicAtomic,
+        Self::CoreOS,
+        Self::IoT,
+        Self::Cloud,
+        Self::Container
+    ];
+
+    /// Get variant by its `VARIANT_ID` value
+    /// 
+    /// ```
+    /// use whatadistro::FedoraVariant;
+    /// 
+    /// assert_eq!(FedoraVariant::from_variant_id("kinoite"), Some(FedoraVariant::Kinoite));
+    /// assert_eq!(FedoraVariant::from_variant_id("CoreOS"), Some(FedoraVariant::CoreOS));
+    /// assert_eq!(FedoraVariant::from_variant_id("unknown"), None);
+    /// ```
+    pub fn from_variant_id(variant_id: &str) -> Option<Self> {
+        Self::ALL.iter()
+            .find(|variant| variant.variant_id().eq_ignore_ascii_case(variant_id.trim()))
+            .copied()
+    }
+
+    /// Get `VARIANT_ID` value of the variant
+    pub const fn variant_id(&self) -> &'static str {
+        match self {
+            Self::Workstation  => "workstation",
+            Self::Server       => "server",
+            Self::Kde          => "kde",
+            Self::Silverblue   => "silverblue",
+            Self::Kinoite      => "kinoite",
+            Self::Sericea      => "sericea",
+            Self::Onyx         => "onyx",
+            Self::CosmicAtomic => "cosmic-atomic",
+            Self::CoreOS       => "coreos",
+            Self::IoT          => "iot",
+            Self::Cloud        => "cloud",
+            Self::Container    => "container"
+        }
+    }
+
+    /// Check if the variant is shipped as an immutable image
+    /// updated by rpm-ostree or bootc instead of dnf
+    /// 
+    /// ```
+    /// use whatadistro::FedoraVariant;
+    /// 
+    /// assert!(FedoraVariant::Silverblue.is_image_based());
+    /// assert!(FedoraVariant::CoreOS.is_image_based());
+    /// assert!(!FedoraVariant::Workstation.is_image_based());
+    /// ```
+    pub const fn is_image_based(&self) -> bool {
+        matches!(self,
+            Self::Silverblue |
+            Self::Kinoite |
+            Self::Sericea |
+            Self::Onyx |
+            Self::CosmicAtomic |
+            Self::CoreOS |
+            Self::IoT
+        )
+    }
+}
+
+impl Display for FedoraVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Workstation  => write!(f, "Fedora Workstation"),
+            Self::Server       => write!(f, "Fedora Server"),
+            Self::Kde          => write!(f, "Fedora KDE Plasma Desktop"),
+            Self::Silverblue   => write!(f, "Fedora Silverblue"),
+            Self::Kinoite      => write!(f, "Fedora Kinoite"),
+            Self::Sericea      => write!(f, "Fedora Sway Atomic"),
+            Self::Onyx         => write!(f, "Fedora Budgie Atomic"),
+            Self::CosmicAtomic => write!(f, "Fedora COSMIC Atomic"),
+            Self::CoreOS       => write!(f, "Fedora CoreOS"),
+            Self::IoT          => write!(f, "Fedora IoT"),
+            Self::Cloud        => write!(f, "Fedora Cloud"),
+            Self::Container    => write!(f, "Fedora Container Image")
+        }
+    }
+}
+
+impl Distro {
+    /// Get Fedora variant of the distro
+    /// 
+    /// Returns `None` for other distros and unknown variants
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, FedoraVariant};
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME="Fedora Linux"
+    ///     VERSION="40.20240806.3.0 (CoreOS)"
+    ///     ID=fedora
+    ///     VARIANT="CoreOS"
+    ///     VARIANT_ID=coreos
+    /// "#).unwrap();
+    /// 
+    /// assert_eq!(distro.fedora_variant(), Some(FedoraVariant::CoreOS));
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Debian\nID=debian\nVARIANT_ID=kde").unwrap();
+    /// 
+    /// assert_eq!(distro.fedora_variant(), None);
+    /// ```
+    pub fn fedora_variant(&self) -> Option<FedoraVariant> {
+        if self.id() != &DistroId::Fedora {
+            return None;
+        }
+
+        let variant_id = self.field("VARIANT_ID")?;
+        let variant = FedoraVariant::from_variant_id(variant_id);
+
+        if variant.is_none() {
+            trace!("unknown fedora variant: {variant_id}");
+        }
+
+        variant
+    }
+}
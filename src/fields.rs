@@ -0,0 +1,246 @@
+use std::fmt::Display;
+
+use crate::{Distro, Date, Cpe, AnsiColor};
+
+#[cfg(feature = "url-fields")]
+use crate::Url;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Name of the icon from the freedesktop icon theme (`LOGO` field)
+pub struct IconName(String);
+
+impl IconName {
+    #[inline]
+    /// Get icon name
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for IconName {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Platform identifier (`PLATFORM_ID` entry), e.g. `platform:el9` or `platform:f40`
+pub struct PlatformId(String);
+
+impl PlatformId {
+    #[inline]
+    /// Get full platform identifier
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    #[inline]
+    /// Get platform name without `platform:` prefix (e.g. `el9`)
+    pub fn name(&self) -> &str {
+        self.0.strip_prefix("platform:").unwrap_or(&self.0)
+    }
+
+    /// Get major version number from the platform name
+    /// 
+    /// ```
+    /// use whatadistro::Distro;
+    /// 
+    /// let distro = Distro::from_os_release("NAME=RHEL\nID=rhel\nPLATFORM_ID=\"platform:el9\"").unwrap();
+    /// let platform = distro.platform_id().unwrap();
+    /// 
+    /// assert_eq!(platform.name(), "el9");
+    /// assert_eq!(platform.major_version(), Some(9));
+    /// ```
+    pub fn major_version(&self) -> Option<u32> {
+        let name = self.name();
+
+        let digits_start = name.find(|char: char| char.is_ascii_digit())?;
+        let digits = &name[digits_start..];
+
+        let digits_end = digits.find(|char: char| !char.is_ascii_digit())
+            .unwrap_or(digits.len());
+
+        digits[..digits_end].parse().ok()
+    }
+}
+
+impl Display for PlatformId {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Distro {
+    #[inline]
+    /// Get pretty distro name (`PRETTY_NAME` entry)
+    pub fn pretty_name(&self) -> Option<&str> {
+        self.field("PRETTY_NAME")
+    }
+
+    #[inline]
+    /// Get distro version (`VERSION` entry)
+    pub fn version(&self) -> Option<&str> {
+        self.field("VERSION")
+    }
+
+    #[inline]
+    /// Get distro version identifier (`VERSION_ID` entry)
+    pub fn version_id(&self) -> Option<&str> {
+        self.field("VERSION_ID")
+    }
+
+    #[inline]
+    /// Get distro release codename (`VERSION_CODENAME` entry)
+    pub fn version_codename(&self) -> Option<&str> {
+        self.field("VERSION_CODENAME")
+    }
+
+    #[inline]
+    /// Get identifier of the system image (`IMAGE_ID` entry)
+    pub fn image_id(&self) -> Option<&str> {
+        self.field("IMAGE_ID")
+    }
+
+    #[inline]
+    /// Get version of the system image (`IMAGE_VERSION` entry)
+    pub fn image_version(&self) -> Option<&str> {
+        self.field("IMAGE_VERSION")
+    }
+
+    #[inline]
+    /// Get hostname used when no other hostname is configured (`DEFAULT_HOSTNAME` entry)
+    pub fn default_hostname(&self) -> Option<&str> {
+        self.field("DEFAULT_HOSTNAME")
+    }
+
+    #[inline]
+    /// Get raw SGR parameters of the distro color (`ANSI_COLOR` entry)
+    pub fn ansi_color(&self) -> Option<&str> {
+        self.field("ANSI_COLOR")
+    }
+
+    /// Get distro brand color (`ANSI_COLOR` entry)
+    /// 
+    /// ```
+    /// use whatadistro::Distro;
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME=NixOS
+    ///     ID=nixos
+    ///     ANSI_COLOR="0;38;2;126;186;228"
+    ///     IMAGE_ID=nixos-appliance
+    ///     IMAGE_VERSION=24.05.1
+    ///     DEFAULT_HOSTNAME=nixos
+    /// "#).unwrap();
+    /// 
+    /// let color = distro.brand_color().unwrap();
+    /// 
+    /// assert_eq!(color.rgb(), Some((126, 186, 228)));
+    /// assert_eq!(distro.image_version(), Some("24.05.1"));
+    /// 
+    /// println!("{}", color.paint(distro.name()));
+    /// ```
+    pub fn brand_color(&self) -> Option<AnsiColor> {
+        self.ansi_color().and_then(AnsiColor::parse)
+    }
+
+    /// Get distro logo icon name (`LOGO` entry)
+    /// 
+    /// ```
+    /// use whatadistro::Distro;
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Fedora\nID=fedora\nLOGO=fedora-logo-icon").unwrap();
+    /// 
+    /// assert_eq!(distro.logo().unwrap().as_str(), "fedora-logo-icon");
+    /// ```
+    pub fn logo(&self) -> Option<IconName> {
+        self.field("LOGO")
+            .filter(|logo| !logo.is_empty())
+            .map(|logo| IconName(logo.to_string()))
+    }
+
+    /// Get date when the distro release stops being supported (`SUPPORT_END` entry)
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, Date};
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Fedora\nID=fedora\nSUPPORT_END=2024-05-14").unwrap();
+    /// 
+    /// if let Some(support_end) = distro.support_end() {
+    ///     if support_end < Date::today() {
+    ///         println!("Your distro is out of support since {support_end}");
+    ///     }
+    /// }
+    /// ```
+    pub fn support_end(&self) -> Option<Date> {
+        self.field("SUPPORT_END").and_then(Date::parse)
+    }
+
+    #[inline]
+    /// Get CPE identifier of the distro release (`CPE_NAME` entry)
+    /// 
+    /// ```
+    /// use whatadistro::Distro;
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME="Fedora Linux"
+    ///     ID=fedora
+    ///     CPE_NAME="cpe:/o:fedoraproject:fedora:40"
+    /// "#).unwrap();
+    /// 
+    /// assert_eq!(distro.cpe().unwrap().version(), Some("40"));
+    /// ```
+    pub fn cpe(&self) -> Option<Cpe> {
+        self.field("CPE_NAME").and_then(Cpe::parse)
+    }
+
+    #[inline]
+    /// Get platform identifier (`PLATFORM_ID` entry)
+    pub fn platform_id(&self) -> Option<PlatformId> {
+        self.field("PLATFORM_ID")
+            .filter(|platform_id| !platform_id.is_empty())
+            .map(|platform_id| PlatformId(platform_id.to_string()))
+    }
+
+    #[cfg(feature = "url-fields")]
+    #[inline]
+    /// Get distro homepage (`HOME_URL` entry)
+    /// 
+    /// ```
+    /// use whatadistro::Distro;
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME="Debian GNU/Linux"
+    ///     ID=debian
+    ///     HOME_URL="https://www.debian.org/"
+    /// "#).unwrap();
+    /// 
+    /// assert_eq!(distro.home_url().unwrap().host(), "www.debian.org");
+    /// ```
+    pub fn home_url(&self) -> Option<Url> {
+        self.field("HOME_URL").and_then(Url::parse)
+    }
+
+    #[cfg(feature = "url-fields")]
+    #[inline]
+    /// Get distro documentation page (`DOCUMENTATION_URL` entry)
+    pub fn documentation_url(&self) -> Option<Url> {
+        self.field("DOCUMENTATION_URL").and_then(Url::parse)
+    }
+
+    #[cfg(feature = "url-fields")]
+    #[inline]
+    /// Get distro support page (`SUPPORT_URL` entry)
+    pub fn support_url(&self) -> Option<Url> {
+        self.field("SUPPORT_URL").and_then(Url::parse)
+    }
+
+    #[cfg(feature = "url-fields")]
+    #[inline]
+    /// Get distro bug tracker page (`BUG_REPORT_URL` entry)
+    pub fn bug_report_url(&self) -> Option<Url> {
+        self.field("BUG_REPORT_URL").and_then(Url::parse)
+    }
+}
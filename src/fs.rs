@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::os_release;
+
+/// Maximal amount of symlinks followed when resolving a path
+const MAX_SYMLINKS: usize = 40;
+
+/// Files access used by the distro identification
+/// 
+/// Implement this trait to identify distros from virtual filesystems,
+/// or use `MockFs` to test distro-dependent code hermetically
+/// 
+/// ```
+/// use whatadistro::{MockFs, DistroId};
+/// 
+/// let fs = MockFs::default()
+///     .with_file("/usr/lib/os-release", "NAME=Alpine\nID=alpine")
+///     .with_link("/etc/os-release", "../usr/lib/os-release");
+/// 
+/// let distro = whatadistro::identify_with_fs(&fs).unwrap();
+/// 
+/// assert_eq!(distro.id(), &DistroId::Alpine);
+/// assert!(distro.detection_source().unwrap().is_symlink());
+/// ```
+pub trait FileSystem {
+    /// Read file content, following symlinks
+    /// 
+    /// Invalid UTF-8 sequences should be replaced by `U+FFFD`
+    fn read_to_string(&self, path: &Path) -> io::Result<String>;
+
+    /// Check if the file exists, following symlinks
+    fn exists(&self, path: &Path) -> bool;
+
+    /// Get symlink target
+    /// 
+    /// Returns an error if the path is not a symlink
+    fn read_link(&self, path: &Path) -> io::Result<PathBuf>;
+
+    /// List paths of the directory entries, following symlinks
+    /// 
+    /// Used to find drop-in files and probe directories. Returns an error by default
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        Err(io::Error::new(io::ErrorKind::Unsupported, format!("{path:?} can't be listed")))
+    }
+
+    /// Get file modification time, following symlinks
+    /// 
+    /// Returns an error by default
+    fn modified(&self, path: &Path) -> io::Result<SystemTime> {
+        Err(io::Error::new(io::ErrorKind::Unsupported, format!("{path:?} modification time is unknown")))
+    }
+
+    /// Check if the file has no write permission bits, following symlinks
+    /// 
+    /// Returns an error by default
+    fn is_read_only(&self, path: &Path) -> io::Result<bool> {
+        Err(io::Error::new(io::ErrorKind::Unsupported, format!("{path:?} permissions are unknown")))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// Filesystem of the current system
+/// 
+/// Files are read like os-release files: only first megabyte
+/// is read and invalid UTF-8 sequences are replaced
+pub struct RealFs;
+
+impl FileSystem for RealFs {
+    #[inline]
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        os_release::read_lossy(path)
+    }
+
+    #[inline]
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    #[inline]
+    fn read_link(&self, path: &Path) -> io::Result<PathBuf> {
+        std::fs::read_link(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        std::fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect()
+    }
+
+    #[inline]
+    fn modified(&self, path: &Path) -> io::Result<SystemTime> {
+        std::fs::metadata(path)?.modified()
+    }
+
+    #[inline]
+    fn is_read_only(&self, path: &Path) -> io::Result<bool> {
+        Ok(std::fs::metadata(path)?.permissions().readonly())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// In-memory filesystem for tests
+/// 
+/// Relative symlink targets are resolved from the symlink parent directory.
+/// All files are writable
+pub struct MockFs {
+    files: HashMap<PathBuf, String>,
+    links: HashMap<PathBuf, PathBuf>,
+    modified: HashMap<PathBuf, SystemTime>
+}
+
+impl MockFs {
+    #[inline]
+    /// Add file with given content
+    pub fn with_file(mut self, path: impl Into<PathBuf>, content: impl ToString) -> Self {
+        self.files.insert(path.into(), content.to_string());
+
+        self
+    }
+
+    #[inline]
+    /// Add symlink pointing to the target path
+    pub fn with_link(mut self, path: impl Into<PathBuf>, target: impl Into<PathBuf>) -> Self {
+        self.links.insert(path.into(), target.into());
+
+        self
+    }
+
+    #[inline]
+    /// Set modification time of the file
+    pub fn with_modified(mut self, path: impl Into<PathBuf>, time: SystemTime) -> Self {
+        self.modified.insert(path.into(), time);
+
+        self
+    }
+
+    /// Follow symlinks until a non-symlink path is reached
+    fn resolve(&self, path: &Path) -> io::Result<PathBuf> {
+        let mut path = normalize(path);
+
+        for _ in 0..MAX_SYMLINKS {
+            let Some(target) = self.links.get(&path) else {
+                return Ok(path);
+            };
+
+            path = match path.parent() {
+                Some(parent) => normalize(&parent.join(target)),
+                None => normalize(target)
+            };
+        }
+
+        Err(io::Error::new(io::ErrorKind::InvalidInput, format!("too many levels of symbolic links: {path:?}")))
+    }
+}
+
+impl FileSystem for MockFs {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        self.files.get(&self.resolve(path)?)
+            .cloned()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("{path:?} not found")))
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.resolve(path)
+            .map(|path| self.files.keys().any(|file| file.starts_with(&path)))
+            .unwrap_or(false)
+    }
+
+    fn read_link(&self, path: &Path) -> io::Result<PathBuf> {
+        self.links.get(&normalize(path))
+            .cloned()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("{path:?} is not a symlink")))
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let dir = self.resolve(path)?;
+
+        // Subdirectories exist implicitly when they contain files
+        let mut entries = self.files.keys()
+            .chain(self.links.keys())
+            .filter_map(|entry| entry.strip_prefix(&dir).ok()?.components().next())
+            .map(|name| path.join(name))
+            .collect::<Vec<_>>();
+
+        entries.sort();
+        entries.dedup();
+
+        if entries.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::NotFound, format!("{path:?} not found")));
+        }
+
+        Ok(entries)
+    }
+
+    fn modified(&self, path: &Path) -> io::Result<SystemTime> {
+        self.modified.get(&self.resolve(path)?)
+            .copied()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("{path:?} modification time is not set")))
+    }
+
+    fn is_read_only(&self, path: &Path) -> io::Result<bool> {
+        if !self.exists(path) {
+            return Err(io::Error::new(io::ErrorKind::NotFound, format!("{path:?} not found")));
+        }
+
+        Ok(false)
+    }
+}
+
+/// Remove `.` and `..` components from the path
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => (),
+
+            Component::ParentDir => {
+                normalized.pop();
+            }
+
+            _ => normalized.push(component)
+        }
+    }
+
+    normalized
+}
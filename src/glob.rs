@@ -0,0 +1,75 @@
+use crate::Distro;
+
+/// Match text against a glob pattern
+/// 
+/// `*` matches any sequence of characters, `?` matches a single character.
+/// Comparison is ASCII case-insensitive
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let text = text.chars().collect::<Vec<_>>();
+
+    let mut p = 0;
+    let mut t = 0;
+
+    // Position of the last `*` in the pattern and text
+    // position it started to match from
+    let mut star = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+
+                p += 1;
+            }
+
+            Some(char) if *char == '?' || char.eq_ignore_ascii_case(&text[t]) => {
+                p += 1;
+                t += 1;
+            }
+
+            // Let the last `*` consume one more character
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    star = Some((star_p, star_t + 1));
+
+                    p = star_p + 1;
+                    t = star_t + 1;
+                }
+
+                None => return false
+            }
+        }
+    }
+
+    pattern[p.min(pattern.len())..].iter().all(|char| *char == '*')
+}
+
+impl Distro {
+    /// Match raw `ID` and `ID_LIKE` values against a glob pattern
+    /// 
+    /// `*` matches any sequence of characters, `?` matches a single character.
+    /// Comparison is ASCII case-insensitive. Useful for families of ids
+    /// which are not known to the crate
+    /// 
+    /// ```
+    /// use whatadistro::Distro;
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME="openSUSE Tumbleweed"
+    ///     ID="opensuse-tumbleweed"
+    ///     ID_LIKE="opensuse suse"
+    /// "#).unwrap();
+    /// 
+    /// assert!(distro.matches_glob("opensuse*"));
+    /// assert!(distro.matches_glob("*-tumbleweed"));
+    /// assert!(distro.matches_glob("sus?"));
+    /// assert!(!distro.matches_glob("rhel*"));
+    /// ```
+    pub fn matches_glob(&self, pattern: &str) -> bool {
+        self.field("ID")
+            .into_iter()
+            .chain(self.field("ID_LIKE").into_iter().flat_map(str::split_whitespace))
+            .any(|id| glob_match(pattern, id))
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt::Write;
+
+use crate::{DistroId, DistroFamily, SCHEMA_VERSION};
+
+/// Escape string for DOT and JSON string literals
+pub(crate) fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for char in value.chars() {
+        match char {
+            '"'  => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+
+            _ if char.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", u32::from(char));
+            }
+
+            _ => escaped.push(char)
+        }
+    }
+
+    escaped
+}
+
+/// Get known distros of the family
+fn members(family: DistroFamily) -> impl Iterator<Item = &'static DistroId> {
+    DistroId::ALL.iter().filter(move |id| id.family() == family)
+}
+
+impl DistroFamily {
+    /// Export family graph in the graphviz DOT format
+    /// 
+    /// Every family is a cluster of its distros, edges
+    /// point from a distro to its parent (`DistroId::parent`)
+    /// 
+    /// ```
+    /// use whatadistro::DistroFamily;
+    /// 
+    /// let dot = DistroFamily::to_dot();
+    /// 
+    /// assert!(dot.starts_with("digraph whatadistro {"));
+    /// assert!(dot.contains("\"linuxmint\" -> \"ubuntu\";"));
+    /// ```
+    pub fn to_dot() -> String {
+        let mut dot = String::from("digraph whatadistro {\n");
+
+        for family in Self::ALL {
+            let indent = if *family == Self::Other { "    " } else { "        " };
+
+            if *family != Self::Other {
+                let _ = writeln!(dot, "    subgraph \"cluster_{family}\" {{");
+                let _ = writeln!(dot, "        label=\"{}\";", escape(family.name()));
+            }
+
+            for id in members(*family) {
+                let _ = writeln!(dot, "{indent}\"{}\" [label=\"{}\"];", escape(id.as_str()), escape(id.name()));
+            }
+
+            if *family != Self::Other {
+                dot.push_str("    }\n");
+            }
+        }
+
+        for id in DistroId::ALL {
+            if let Some(parent) = id.parent() {
+                let _ = writeln!(dot, "    \"{}\" -> \"{}\";", escape(id.as_str()), escape(parent.as_str()));
+            }
+        }
+
+        dot.push_str("}\n");
+
+        dot
+    }
+
+    /// Export family graph in the JSON format
+    /// 
+    /// ```json
+    /// {
+    ///     "schema_version": 1,
+    ///     "families": [{
+    ///         "family": "debian",
+    ///         "name": "Debian-based",
+    ///         "distros": [{ "id": "ubuntu", "name": "Ubuntu", "parent": "debian" }]
+    ///     }]
+    /// }
+    /// ```
+    /// 
+    /// ```
+    /// use whatadistro::DistroFamily;
+    /// 
+    /// let json = DistroFamily::to_json();
+    /// 
+    /// assert!(json.starts_with(r#"{"schema_version":1,"families":["#));
+    /// assert!(json.contains(r#"{"id":"linuxmint","name":"Linux Mint","parent":"ubuntu"}"#));
+    /// ```
+    pub fn to_json() -> String {
+        let families = Self::ALL.iter()
+            .map(|family| {
+                let distros = members(*family)
+                    .map(|id| {
+                        let parent = match id.parent() {
+                            Some(parent) => format!("\"{}\"", escape(parent.as_str())),
+                            None => String::from("null")
+                        };
+
+                        format!("{{\"id\":\"{}\",\"name\":\"{}\",\"parent\":{parent}}}", escape(id.as_str()), escape(id.name()))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                format!("{{\"family\":\"{family}\",\"name\":\"{}\",\"distros\":[{distros}]}}", escape(family.name()))
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!("{{\"schema_version\":{SCHEMA_VERSION},\"families\":[{families}]}}")
+    }
+}
@@ -0,0 +1,448 @@
+use std::fmt::Display;
+use std::path::Path;
+
+use crate::{Distro, DistroId, DistroFamily, FileSystem, RealFs};
+
+/// NVIDIA driver branches packaged by Ubuntu LTS releases, newest first
+/// 
+/// Codename, driver package, userspace libraries package. Releases
+/// missing in the list use the newest branch
+const UBUNTU_NVIDIA_DRIVERS: &[(&str, &str, &str)] = &[
+    ("noble",  "nvidia-driver-550", "libnvidia-gl-550"),
+    ("jammy",  "nvidia-driver-535", "libnvidia-gl-535"),
+    ("focal",  "nvidia-driver-535", "libnvidia-gl-535"),
+    ("bionic", "nvidia-driver-470", "libnvidia-gl-470")
+];
+
+/// Graphics libraries for each packaging scheme, API and GPU vendor
+/// 
+/// Packages without vendor are needed for all the GPUs (e.g. Vulkan loader).
+/// NVIDIA libraries on Ubuntu depend on the release (see `UBUNTU_NVIDIA_DRIVERS`)
+const GRAPHICS_PACKAGES: &[(Scheme, GraphicsApi, Option<GpuVendor>, &str)] = &[
+    (Scheme::Arch, GraphicsApi::Vulkan, None,                    "vulkan-icd-loader"),
+    (Scheme::Arch, GraphicsApi::Vulkan, Some(GpuVendor::Amd),    "vulkan-radeon"),
+    (Scheme::Arch, GraphicsApi::Vulkan, Some(GpuVendor::Intel),  "vulkan-intel"),
+    (Scheme::Arch, GraphicsApi::Vulkan, Some(GpuVendor::Nvidia), "nvidia-utils"),
+    (Scheme::Arch, GraphicsApi::OpenGL, Some(GpuVendor::Amd),    "mesa"),
+    (Scheme::Arch, GraphicsApi::OpenGL, Some(GpuVendor::Intel),  "mesa"),
+    (Scheme::Arch, GraphicsApi::OpenGL, Some(GpuVendor::Nvidia), "nvidia-utils"),
+
+    (Scheme::Debian, GraphicsApi::Vulkan, None,                    "libvulkan1"),
+    (Scheme::Debian, GraphicsApi::Vulkan, Some(GpuVendor::Amd),    "mesa-vulkan-drivers"),
+    (Scheme::Debian, GraphicsApi::Vulkan, Some(GpuVendor::Intel),  "mesa-vulkan-drivers"),
+    (Scheme::Debian, GraphicsApi::Vulkan, Some(GpuVendor::Nvidia), "nvidia-vulkan-icd"),
+    (Scheme::Debian, GraphicsApi::OpenGL, None,                    "libgl1"),
+    (Scheme::Debian, GraphicsApi::OpenGL, Some(GpuVendor::Amd),    "libgl1-mesa-dri"),
+    (Scheme::Debian, GraphicsApi::OpenGL, Some(GpuVendor::Intel),  "libgl1-mesa-dri"),
+    (Scheme::Debian, GraphicsApi::OpenGL, Some(GpuVendor::Nvidia), "libgl1-nvidia-glvnd-glx"),
+
+    (Scheme::Ubuntu, GraphicsApi::Vulkan, None,                    "libvulkan1"),
+    (Scheme::Ubuntu, GraphicsApi::Vulkan, Some(GpuVendor::Amd),    "mesa-vulkan-drivers"),
+    (Scheme::Ubuntu, GraphicsApi::Vulkan, Some(GpuVendor::Intel),  "mesa-vulkan-drivers"),
+    (Scheme::Ubuntu, GraphicsApi::OpenGL, None,                    "libgl1"),
+    (Scheme::Ubuntu, GraphicsApi::OpenGL, Some(GpuVendor::Amd),    "libgl1-mesa-dri"),
+    (Scheme::Ubuntu, GraphicsApi::OpenGL, Some(GpuVendor::Intel),  "libgl1-mesa-dri"),
+
+    (Scheme::Fedora, GraphicsApi::Vulkan, None,                    "vulkan-loader"),
+    (Scheme::Fedora, GraphicsApi::Vulkan, Some(GpuVendor::Amd),    "mesa-vulkan-drivers"),
+    (Scheme::Fedora, GraphicsApi::Vulkan, Some(GpuVendor::Intel),  "mesa-vulkan-drivers"),
+    (Scheme::Fedora, GraphicsApi::Vulkan, Some(GpuVendor::Nvidia), "xorg-x11-drv-nvidia-libs"),
+    (Scheme::Fedora, GraphicsApi::OpenGL, Some(GpuVendor::Amd),    "mesa-dri-drivers"),
+    (Scheme::Fedora, GraphicsApi::OpenGL, Some(GpuVendor::Intel),  "mesa-dri-drivers"),
+    (Scheme::Fedora, GraphicsApi::OpenGL, Some(GpuVendor::Nvidia), "xorg-x11-drv-nvidia-libs"),
+
+    (Scheme::OpenSUSE, GraphicsApi::Vulkan, None,                    "libvulkan1"),
+    (Scheme::OpenSUSE, GraphicsApi::Vulkan, Some(GpuVendor::Amd),    "libvulkan_radeon"),
+    (Scheme::OpenSUSE, GraphicsApi::Vulkan, Some(GpuVendor::Intel),  "libvulkan_intel"),
+    (Scheme::OpenSUSE, GraphicsApi::Vulkan, Some(GpuVendor::Nvidia), "nvidia-gl-G06"),
+    (Scheme::OpenSUSE, GraphicsApi::OpenGL, Some(GpuVendor::Amd),    "Mesa-dri"),
+    (Scheme::OpenSUSE, GraphicsApi::OpenGL, Some(GpuVendor::Intel),  "Mesa-dri"),
+    (Scheme::OpenSUSE, GraphicsApi::OpenGL, Some(GpuVendor::Nvidia), "nvidia-gl-G06"),
+
+    (Scheme::Gentoo, GraphicsApi::Vulkan, None,                    "media-libs/vulkan-loader"),
+    (Scheme::Gentoo, GraphicsApi::Vulkan, Some(GpuVendor::Amd),    "media-libs/mesa"),
+    (Scheme::Gentoo, GraphicsApi::Vulkan, Some(GpuVendor::Intel),  "media-libs/mesa"),
+    (Scheme::Gentoo, GraphicsApi::Vulkan, Some(GpuVendor::Nvidia), "x11-drivers/nvidia-drivers"),
+    (Scheme::Gentoo, GraphicsApi::OpenGL, Some(GpuVendor::Amd),    "media-libs/mesa"),
+    (Scheme::Gentoo, GraphicsApi::OpenGL, Some(GpuVendor::Intel),  "media-libs/mesa"),
+    (Scheme::Gentoo, GraphicsApi::OpenGL, Some(GpuVendor::Nvidia), "x11-drivers/nvidia-drivers"),
+
+    (Scheme::Alpine, GraphicsApi::Vulkan, None,                   "vulkan-loader"),
+    (Scheme::Alpine, GraphicsApi::Vulkan, Some(GpuVendor::Amd),   "mesa-vulkan-ati"),
+    (Scheme::Alpine, GraphicsApi::Vulkan, Some(GpuVendor::Intel), "mesa-vulkan-intel"),
+    (Scheme::Alpine, GraphicsApi::OpenGL, Some(GpuVendor::Amd),   "mesa-dri-gallium"),
+    (Scheme::Alpine, GraphicsApi::OpenGL, Some(GpuVendor::Intel), "mesa-dri-gallium")
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Graphics API
+pub enum GraphicsApi {
+    Vulkan,
+    OpenGL
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Package naming scheme of the distro
+enum Scheme {
+    Arch,
+    Debian,
+    Ubuntu,
+    Fedora,
+    OpenSUSE,
+    Gentoo,
+    Alpine
+}
+
+impl Scheme {
+    /// Get name of the 32-bit version of the package
+    fn multilib_package(&self, package: &str) -> Option<String> {
+        match self {
+            Self::Arch                  => Some(format!("lib32-{package}")),
+            Self::Debian | Self::Ubuntu => Some(format!("{package}:i386")),
+            Self::Fedora                => Some(format!("{package}.i686")),
+            Self::OpenSUSE              => Some(format!("{package}-32bit")),
+            Self::Gentoo                => Some(format!("{package}[abi_x86_32]")),
+
+            // Alpine doesn't provide 32-bit packages for 64-bit systems
+            Self::Alpine => None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Display server of the current session
+pub enum DisplayServer {
+    Wayland,
+    X11,
+
+    /// Headless session or unknown display server
+    Unknown
+}
+
+impl Display for DisplayServer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Wayland => write!(f, "wayland"),
+            Self::X11     => write!(f, "x11"),
+            Self::Unknown => write!(f, "unknown")
+        }
+    }
+}
+
+/// Detect display server of the current session
+/// 
+/// `XDG_SESSION_TYPE` variable is used first, then
+/// `WAYLAND_DISPLAY` and `DISPLAY` variables
+/// 
+/// ```
+/// use whatadistro::DisplayServer;
+/// 
+/// if whatadistro::detect_display_server() == DisplayServer::Wayland {
+///     println!("Running on Wayland");
+/// }
+/// ```
+pub fn detect_display_server() -> DisplayServer {
+    match std::env::var("XDG_SESSION_TYPE").as_deref() {
+        Ok("wayland") => return DisplayServer::Wayland,
+        Ok("x11")     => return DisplayServer::X11,
+
+        _ => ()
+    }
+
+    if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+        DisplayServer::Wayland
+    }
+
+    else if std::env::var_os("DISPLAY").is_some() {
+        DisplayServer::X11
+    }
+
+    else {
+        DisplayServer::Unknown
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// GPU vendor
+pub enum GpuVendor {
+    Nvidia,
+    Amd,
+    Intel,
+
+    /// Unknown PCI vendor id
+    Other(u16)
+}
+
+impl GpuVendor {
+    /// Get GPU vendor from its PCI vendor id
+    /// 
+    /// ```
+    /// use whatadistro::GpuVendor;
+    /// 
+    /// assert_eq!(GpuVendor::from_pci_id(0x10de), GpuVendor::Nvidia);
+    /// assert_eq!(GpuVendor::from_pci_id(0x1af4), GpuVendor::Other(0x1af4));
+    /// ```
+    pub fn from_pci_id(id: u16) -> Self {
+        match id {
+            0x10de => Self::Nvidia,
+            0x1002 => Self::Amd,
+            0x8086 => Self::Intel,
+
+            _ => Self::Other(id)
+        }
+    }
+
+    /// Get PCI vendor id of the GPU vendor
+    pub fn pci_id(&self) -> u16 {
+        match self {
+            Self::Nvidia => 0x10de,
+            Self::Amd    => 0x1002,
+            Self::Intel  => 0x8086,
+
+            Self::Other(id) => *id
+        }
+    }
+}
+
+impl Display for GpuVendor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Nvidia => write!(f, "nvidia"),
+            Self::Amd    => write!(f, "amd"),
+            Self::Intel  => write!(f, "intel"),
+
+            Self::Other(id) => write!(f, "{id:#06x}")
+        }
+    }
+}
+
+/// Detect vendors of all the GPUs installed in the system
+/// 
+/// GPUs are read from `/sys/class/drm/card*/device/vendor` files
+/// 
+/// ```
+/// use whatadistro::GpuVendor;
+/// 
+/// if whatadistro::detect_gpu_vendors().contains(&GpuVendor::Nvidia) {
+///     println!("NVIDIA GPU found");
+/// }
+/// ```
+pub fn detect_gpu_vendors() -> Vec<GpuVendor> {
+    detect_gpu_vendors_with_fs(&RealFs)
+}
+
+/// Detect vendors of all the GPUs using files of the given filesystem
+/// 
+/// ```
+/// use whatadistro::{GpuVendor, MockFs};
+/// 
+/// let fs = MockFs::default()
+///     .with_file("/sys/class/drm/card0/device/vendor", "0x10de\n")
+///     .with_file("/sys/class/drm/card0-HDMI-A-1/status", "connected\n")
+///     .with_file("/sys/class/drm/card1/device/vendor", "0x8086\n");
+/// 
+/// assert_eq!(whatadistro::detect_gpu_vendors_with_fs(&fs), [GpuVendor::Nvidia, GpuVendor::Intel]);
+/// ```
+pub fn detect_gpu_vendors_with_fs(fs: &impl FileSystem) -> Vec<GpuVendor> {
+    let mut vendors = Vec::new();
+
+    let Ok(entries) = fs.read_dir(Path::new("/sys/class/drm")) else {
+        return vendors;
+    };
+
+    for entry in entries {
+        let Some(name) = entry.file_name() else {
+            continue;
+        };
+
+        let name = name.to_string_lossy();
+
+        // Skip connectors like card0-HDMI-A-1
+        let is_card = name.strip_prefix("card")
+            .map(|index| index.bytes().all(|byte| byte.is_ascii_digit()))
+            .unwrap_or(false);
+
+        if !is_card {
+            continue;
+        }
+
+        let Ok(vendor) = fs.read_to_string(&entry.join("device/vendor")) else {
+            continue;
+        };
+
+        let vendor = vendor.trim();
+        let vendor = vendor.strip_prefix("0x").unwrap_or(vendor);
+
+        if let Ok(id) = u16::from_str_radix(vendor, 16) {
+            let vendor = GpuVendor::from_pci_id(id);
+
+            trace!("found {vendor} GPU: {name}");
+
+            if !vendors.contains(&vendor) {
+                vendors.push(vendor);
+            }
+        }
+    }
+
+    vendors
+}
+
+impl Distro {
+    /// Get name of the distro package providing graphics driver for given GPU vendor
+    /// 
+    /// For NVIDIA GPUs proprietary driver package is returned, which might
+    /// require additional repositories (e.g. RPM Fusion on Fedora). Ubuntu
+    /// driver branch depends on the release codename. For
+    /// AMD and Intel GPUs kernel drivers are used and Mesa package is returned
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, GpuVendor};
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Fedora\nID=fedora").unwrap();
+    /// 
+    /// assert_eq!(distro.graphics_driver_package(GpuVendor::Nvidia), Some("akmod-nvidia"));
+    /// assert_eq!(distro.graphics_driver_package(GpuVendor::Amd), Some("mesa-dri-drivers"));
+    /// ```
+    pub fn graphics_driver_package(&self, vendor: GpuVendor) -> Option<&'static str> {
+        match vendor {
+            GpuVendor::Nvidia => self.nvidia_driver_package(),
+
+            GpuVendor::Amd | GpuVendor::Intel => self.mesa_package(),
+
+            GpuVendor::Other(_) => None
+        }
+    }
+
+    /// Get packages providing graphics API libraries for given GPU vendor
+    /// 
+    /// With `multilib` 32-bit versions of the packages are listed as well
+    /// (needed by Wine, Proton and Steam). Distros which don't provide 32-bit
+    /// packages (e.g. Alpine) list only native ones. Drivers themselves are
+    /// not listed, use `graphics_driver_package` for them
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, GpuVendor, GraphicsApi};
+    /// 
+    /// let distro = Distro::from_os_release("NAME=\"Arch Linux\"\nID=arch").unwrap();
+    /// 
+    /// assert_eq!(distro.graphics_packages(GraphicsApi::Vulkan, GpuVendor::Amd, true), vec![
+    ///     "vulkan-icd-loader",
+    ///     "vulkan-radeon",
+    ///     "lib32-vulkan-icd-loader",
+    ///     "lib32-vulkan-radeon"
+    /// ]);
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Fedora\nID=fedora").unwrap();
+    /// 
+    /// assert!(distro.graphics_packages(GraphicsApi::Vulkan, GpuVendor::Intel, true)
+    ///     .contains(&String::from("vulkan-loader.i686")));
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Debian\nID=debian").unwrap();
+    /// 
+    /// assert!(distro.graphics_packages(GraphicsApi::Vulkan, GpuVendor::Nvidia, true)
+    ///     .contains(&String::from("libvulkan1:i386")));
+    /// ```
+    pub fn graphics_packages(&self, api: GraphicsApi, vendor: GpuVendor, multilib: bool) -> Vec<String> {
+        let Some(scheme) = self.package_scheme() else {
+            trace!("graphics packages are unknown for {}", self.id());
+
+            return Vec::new();
+        };
+
+        let mut packages = Vec::new();
+
+        for (package_scheme, package_api, package_vendor, package) in GRAPHICS_PACKAGES {
+            let matches = *package_scheme == scheme &&
+                *package_api == api &&
+                package_vendor.map(|package_vendor| package_vendor == vendor).unwrap_or(true);
+
+            if matches && !packages.iter().any(|name| name == package) {
+                packages.push(package.to_string());
+            }
+        }
+
+        if scheme == Scheme::Ubuntu && vendor == GpuVendor::Nvidia {
+            if let Some((_, libs)) = self.ubuntu_nvidia_driver() {
+                packages.push(libs.to_string());
+            }
+        }
+
+        if multilib {
+            let multilib_packages = packages.iter()
+                .filter_map(|package| scheme.multilib_package(package))
+                .collect::<Vec<_>>();
+
+            packages.extend(multilib_packages);
+        }
+
+        packages
+    }
+
+    fn package_scheme(&self) -> Option<Scheme> {
+        match self.id() {
+            DistroId::Ubuntu | DistroId::Mint => Some(Scheme::Ubuntu),
+
+            DistroId::OpenSUSE => Some(Scheme::OpenSUSE),
+
+            id => match self.family() {
+                DistroFamily::Arch   => Some(Scheme::Arch),
+                DistroFamily::Debian => Some(Scheme::Debian),
+                DistroFamily::RedHat if id != &DistroId::AzureLinux => Some(Scheme::Fedora),
+                DistroFamily::Gentoo => Some(Scheme::Gentoo),
+                DistroFamily::Alpine => Some(Scheme::Alpine),
+
+                _ => None
+            }
+        }
+    }
+
+    fn nvidia_driver_package(&self) -> Option<&'static str> {
+        match self.id() {
+            DistroId::Ubuntu | DistroId::Mint => self.ubuntu_nvidia_driver()
+                .map(|(driver, _)| driver),
+
+            DistroId::Debian | DistroId::Raspbian => Some("nvidia-driver"),
+            DistroId::Fedora | DistroId::RHEL     => Some("akmod-nvidia"),
+
+            DistroId::OpenSUSE => Some("nvidia-video-G06"),
+            DistroId::Gentoo   => Some("x11-drivers/nvidia-drivers"),
+
+            id if id.family() == DistroFamily::Arch => Some("nvidia-dkms"),
+
+            _ => None
+        }
+    }
+
+    /// Get NVIDIA driver branch packaged by the Ubuntu release
+    /// 
+    /// Ubuntu based distros (Linux Mint) set `UBUNTU_CODENAME` field
+    fn ubuntu_nvidia_driver(&self) -> Option<(&'static str, &'static str)> {
+        let codename = self.field("UBUNTU_CODENAME")
+            .or_else(|| self.version_codename());
+
+        let driver = codename.and_then(|codename| {
+            UBUNTU_NVIDIA_DRIVERS.iter().find(|(release, _, _)| *release == codename)
+        });
+
+        if driver.is_none() {
+            trace!("unknown Ubuntu release {codename:?}, using the newest NVIDIA driver branch");
+        }
+
+        driver.or_else(|| UBUNTU_NVIDIA_DRIVERS.first())
+            .map(|(_, driver, libs)| (*driver, *libs))
+    }
+
+    fn mesa_package(&self) -> Option<&'static str> {
+        match self.id().family() {
+            DistroFamily::Arch   => Some("mesa"),
+            DistroFamily::Debian => Some("libgl1-mesa-dri"),
+            DistroFamily::Gentoo => Some("media-libs/mesa"),
+            DistroFamily::Alpine => Some("mesa-dri-gallium"),
+
+            DistroFamily::RedHat if self.id() == &DistroId::OpenSUSE => Some("Mesa-dri"),
+            DistroFamily::RedHat => Some("mesa-dri-drivers"),
+
+            _ => None
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::DistroFamily;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Language {
+    English,
+    German,
+    French,
+    Russian,
+    Chinese
+}
+
+impl Language {
+    /// Parse language from the locale string
+    /// 
+    /// Accepts POSIX (`de_DE.UTF-8`) and BCP 47 (`de-DE`) formats.
+    /// Falls back to English for unknown languages
+    fn from_locale(locale: &str) -> Self {
+        let lang = locale.split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match lang.as_str() {
+            "de" => Self::German,
+            "fr" => Self::French,
+            "ru" => Self::Russian,
+            "zh" => Self::Chinese,
+
+            _ => Self::English
+        }
+    }
+}
+
+impl DistroFamily {
+    /// Get family name translated to the given locale
+    /// 
+    /// Only family names are translated. Distro names are brands shipped
+    /// the same in all the languages, so use `DistroId::name` for them.
+    /// Locale can be specified either in POSIX (`de_DE.UTF-8`) or in BCP 47 (`de-DE`) format.
+    /// Supported languages are `en`, `de`, `fr`, `ru` and `zh`. English name is returned
+    /// for unsupported languages
+    /// 
+    /// ```
+    /// use whatadistro::DistroFamily;
+    /// 
+    /// assert_eq!(DistroFamily::Debian.localized_name("fr_FR"), "basée sur Debian");
+    /// ```
+    pub fn localized_name(&self, locale: &str) -> &'static str {
+        match Language::from_locale(locale) {
+            Language::English => self.name(),
+
+            Language::German => match self {
+                Self::Arch   => "Arch-basiert",
+                Self::Debian => "Debian-basiert",
+                Self::RedHat => "Red Hat-basiert",
+                Self::Gentoo => "Gentoo-basiert",
+                Self::NixOS  => "NixOS-basiert",
+                Self::Alpine => "Alpine-basiert",
+                Self::Other  => "Andere"
+            },
+
+            Language::French => match self {
+                Self::Arch   => "basée sur Arch",
+                Self::Debian => "basée sur Debian",
+                Self::RedHat => "basée sur Red Hat",
+                Self::Gentoo => "basée sur Gentoo",
+                Self::NixOS  => "basée sur NixOS",
+                Self::Alpine => "basée sur Alpine",
+                Self::Other  => "Autre"
+            },
+
+            Language::Russian => match self {
+                Self::Arch   => "на базе Arch",
+                Self::Debian => "на базе Debian",
+                Self::RedHat => "на базе Red Hat",
+                Self::Gentoo => "на базе Gentoo",
+                Self::NixOS  => "на базе NixOS",
+                Self::Alpine => "на базе Alpine",
+                Self::Other  => "Другой"
+            },
+
+            Language::Chinese => match self {
+                Self::Arch   => "基于 Arch",
+                Self::Debian => "基于 Debian",
+                Self::RedHat => "基于 Red Hat",
+                Self::Gentoo => "基于 Gentoo",
+                Self::NixOS  => "基于 NixOS",
+                Self::Alpine => "基于 Alpine",
+                Self::Other  => "其他"
+            }
+        }
+    }
+}
@@ -0,0 +1,382 @@
+//! Minimal gzip / DEFLATE decoder used to read compressed image layers
+
+use std::io::{self, Read, Write};
+
+/// Size of the DEFLATE back-reference window
+const WINDOW_SIZE: usize = 32 * 1024;
+
+/// Amount of decoded bytes kept in memory before flushing them to the sink
+const FLUSH_SIZE: usize = 4 * WINDOW_SIZE;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31,
+    35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258
+];
+
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2,
+    3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0
+];
+
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193,
+    257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145,
+    8193, 12289, 16385, 24577
+];
+
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6,
+    7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13
+];
+
+/// Order of code length code lengths in dynamic blocks
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15
+];
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+/// LSB-first bit reader
+struct Bits<R> {
+    reader: R,
+    buf: Box<[u8]>,
+    pos: usize,
+    len: usize,
+    bit_buf: u64,
+    bit_count: u32
+}
+
+impl<R: Read> Bits<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: vec![0; 16 * 1024].into_boxed_slice(),
+            pos: 0,
+            len: 0,
+            bit_buf: 0,
+            bit_count: 0
+        }
+    }
+
+    fn next_byte(&mut self) -> io::Result<u8> {
+        if self.pos == self.len {
+            self.len = self.reader.read(&mut self.buf)?;
+            self.pos = 0;
+
+            if self.len == 0 {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "compressed stream is truncated"));
+            }
+        }
+
+        self.pos += 1;
+
+        Ok(self.buf[self.pos - 1])
+    }
+
+    fn bits(&mut self, count: u32) -> io::Result<u32> {
+        while self.bit_count < count {
+            self.bit_buf |= (self.next_byte()? as u64) << self.bit_count;
+            self.bit_count += 8;
+        }
+
+        let value = self.bit_buf & ((1 << count) - 1);
+
+        self.bit_buf >>= count;
+        self.bit_count -= count;
+
+        Ok(value as u32)
+    }
+
+    /// Skip bits until the next byte boundary
+    fn align(&mut self) {
+        let skip = self.bit_count % 8;
+
+        self.bit_buf >>= skip;
+        self.bit_count -= skip;
+    }
+}
+
+/// Canonical Huffman code
+struct Huffman {
+    counts: [u16; 16],
+    symbols: Vec<u16>
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Self {
+        let mut counts = [0; 16];
+
+        for length in lengths {
+            counts[*length as usize] += 1;
+        }
+
+        counts[0] = 0;
+
+        let mut offsets = [0; 16];
+
+        for length in 1..16 {
+            offsets[length] = offsets[length - 1] + counts[length - 1];
+        }
+
+        let mut symbols = vec![0; lengths.len()];
+
+        for (symbol, length) in lengths.iter().enumerate() {
+            if *length != 0 {
+                symbols[offsets[*length as usize] as usize] = symbol as u16;
+                offsets[*length as usize] += 1;
+            }
+        }
+
+        Self {
+            counts,
+            symbols
+        }
+    }
+
+    fn decode<R: Read>(&self, bits: &mut Bits<R>) -> io::Result<u16> {
+        let mut code = 0;
+        let mut first = 0;
+        let mut index = 0;
+
+        for length in 1..16 {
+            code |= bits.bits(1)? as i32;
+
+            let count = self.counts[length] as i32;
+
+            if code - first < count {
+                return self.symbols.get((index + code - first) as usize)
+                    .copied()
+                    .ok_or_else(|| invalid("invalid huffman code"));
+            }
+
+            index += count;
+            first += count;
+            first <<= 1;
+            code <<= 1;
+        }
+
+        Err(invalid("invalid huffman code"))
+    }
+}
+
+/// Decoded data window flushed to the sink
+struct Output<W> {
+    window: Vec<u8>,
+    sink: W
+}
+
+impl<W: Write> Output<W> {
+    fn push(&mut self, byte: u8) -> io::Result<()> {
+        self.window.push(byte);
+
+        self.flush_full()
+    }
+
+    fn copy(&mut self, distance: usize, length: usize) -> io::Result<()> {
+        if distance > self.window.len() {
+            return Err(invalid("back-reference distance is too far"));
+        }
+
+        for _ in 0..length {
+            self.window.push(self.window[self.window.len() - distance]);
+        }
+
+        self.flush_full()
+    }
+
+    /// Write decoded data to the sink keeping the back-reference window
+    fn flush_full(&mut self) -> io::Result<()> {
+        if self.window.len() >= FLUSH_SIZE {
+            let flushed = self.window.len() - WINDOW_SIZE;
+
+            self.sink.write_all(&self.window[..flushed])?;
+            self.window.drain(..flushed);
+        }
+
+        Ok(())
+    }
+
+    fn finish(mut self) -> io::Result<W> {
+        self.sink.write_all(&self.window)?;
+
+        Ok(self.sink)
+    }
+}
+
+/// Decode raw DEFLATE stream
+fn inflate<R: Read, W: Write>(bits: &mut Bits<R>, sink: W) -> io::Result<W> {
+    let mut output = Output {
+        window: Vec::with_capacity(FLUSH_SIZE + 258),
+        sink
+    };
+
+    loop {
+        let last = bits.bits(1)? == 1;
+
+        match bits.bits(2)? {
+            0 => {
+                bits.align();
+
+                let length = bits.bits(16)?;
+                let inverted = bits.bits(16)?;
+
+                if length != !inverted & 0xffff {
+                    return Err(invalid("stored block length is corrupted"));
+                }
+
+                for _ in 0..length {
+                    output.push(bits.bits(8)? as u8)?;
+                }
+            }
+
+            1 => {
+                let mut lengths = [0; 288];
+
+                lengths[..144].fill(8);
+                lengths[144..256].fill(9);
+                lengths[256..280].fill(7);
+                lengths[280..].fill(8);
+
+                let literals = Huffman::new(&lengths);
+                let distances = Huffman::new(&[5; 30]);
+
+                inflate_block(bits, &mut output, &literals, &distances)?;
+            }
+
+            2 => {
+                let (literals, distances) = dynamic_tables(bits)?;
+
+                inflate_block(bits, &mut output, &literals, &distances)?;
+            }
+
+            _ => return Err(invalid("invalid block type"))
+        }
+
+        if last {
+            return output.finish();
+        }
+    }
+}
+
+/// Read Huffman tables of the dynamic block
+fn dynamic_tables<R: Read>(bits: &mut Bits<R>) -> io::Result<(Huffman, Huffman)> {
+    let literals_count = bits.bits(5)? as usize + 257;
+    let distances_count = bits.bits(5)? as usize + 1;
+    let code_lengths_count = bits.bits(4)? as usize + 4;
+
+    let mut code_lengths = [0; 19];
+
+    for index in CODE_LENGTH_ORDER.iter().take(code_lengths_count) {
+        code_lengths[*index] = bits.bits(3)? as u8;
+    }
+
+    let code_lengths = Huffman::new(&code_lengths);
+
+    let mut lengths = Vec::with_capacity(literals_count + distances_count);
+
+    while lengths.len() < literals_count + distances_count {
+        let (value, repeat) = match code_lengths.decode(bits)? {
+            symbol @ 0..=15 => (symbol as u8, 1),
+
+            16 => {
+                let previous = *lengths.last()
+                    .ok_or_else(|| invalid("repeated code length without previous one"))?;
+
+                (previous, 3 + bits.bits(2)?)
+            }
+
+            17 => (0, 3 + bits.bits(3)?),
+            18 => (0, 11 + bits.bits(7)?),
+
+            _ => return Err(invalid("invalid code length symbol"))
+        };
+
+        if lengths.len() + repeat as usize > literals_count + distances_count {
+            return Err(invalid("too many code lengths"));
+        }
+
+        lengths.resize(lengths.len() + repeat as usize, value);
+    }
+
+    let (literals, distances) = lengths.split_at(literals_count);
+
+    Ok((Huffman::new(literals), Huffman::new(distances)))
+}
+
+/// Decode compressed block data
+fn inflate_block<R: Read, W: Write>(
+    bits: &mut Bits<R>,
+    output: &mut Output<W>,
+    literals: &Huffman,
+    distances: &Huffman
+) -> io::Result<()> {
+    loop {
+        let symbol = literals.decode(bits)? as usize;
+
+        match symbol {
+            0..=255 => output.push(symbol as u8)?,
+            256 => return Ok(()),
+
+            _ => {
+                let index = symbol - 257;
+
+                let (Some(base), Some(extra)) = (LENGTH_BASE.get(index), LENGTH_EXTRA.get(index)) else {
+                    return Err(invalid("invalid length symbol"));
+                };
+
+                let length = *base as usize + bits.bits(*extra as u32)? as usize;
+
+                let index = distances.decode(bits)? as usize;
+
+                let (Some(base), Some(extra)) = (DISTANCE_BASE.get(index), DISTANCE_EXTRA.get(index)) else {
+                    return Err(invalid("invalid distance symbol"));
+                };
+
+                let distance = *base as usize + bits.bits(*extra as u32)? as usize;
+
+                output.copy(distance, length)?;
+            }
+        }
+    }
+}
+
+/// Decode gzip stream into the sink
+pub(crate) fn gunzip<R: Read, W: Write>(reader: R, sink: W) -> io::Result<W> {
+    const FEXTRA: u32 = 4;
+    const FNAME: u32 = 8;
+    const FCOMMENT: u32 = 16;
+    const FHCRC: u32 = 2;
+
+    let mut bits = Bits::new(reader);
+
+    if bits.bits(16)? != 0x8b1f || bits.bits(8)? != 8 {
+        return Err(invalid("not a gzip stream"));
+    }
+
+    let flags = bits.bits(8)?;
+
+    // Modification time, extra flags and OS
+    for _ in 0..6 {
+        bits.bits(8)?;
+    }
+
+    if flags & FEXTRA != 0 {
+        for _ in 0..bits.bits(16)? {
+            bits.bits(8)?;
+        }
+    }
+
+    for flag in [FNAME, FCOMMENT] {
+        if flags & flag != 0 {
+            while bits.bits(8)? != 0 {}
+        }
+    }
+
+    if flags & FHCRC != 0 {
+        bits.bits(16)?;
+    }
+
+    inflate(&mut bits, sink)
+}
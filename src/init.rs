@@ -0,0 +1,134 @@
+use std::fmt::Display;
+use std::path::Path;
+
+use crate::{FileSystem, RealFs, identify_with_fs};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Init system (PID 1) used by the system
+pub enum InitSystem {
+    Systemd,
+    OpenRC,
+    Runit,
+    S6,
+    Dinit,
+    SysVinit,
+
+    /// Init system couldn't be identified
+    /// (e.g. inside a container with a custom entrypoint)
+    Unknown
+}
+
+impl InitSystem {
+    /// Match name of the PID 1 process
+    fn from_comm(comm: &str) -> Option<Self> {
+        match comm {
+            "systemd"         => Some(Self::Systemd),
+            "openrc-init"     => Some(Self::OpenRC),
+            "runit"           => Some(Self::Runit),
+            "s6-svscan"       => Some(Self::S6),
+            "dinit"           => Some(Self::Dinit),
+
+            _ => None
+        }
+    }
+}
+
+impl Display for InitSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Systemd  => write!(f, "systemd"),
+            Self::OpenRC   => write!(f, "openrc"),
+            Self::Runit    => write!(f, "runit"),
+            Self::S6       => write!(f, "s6"),
+            Self::Dinit    => write!(f, "dinit"),
+            Self::SysVinit => write!(f, "sysvinit"),
+            Self::Unknown  => write!(f, "unknown")
+        }
+    }
+}
+
+/// Runtime directories created by running init systems
+const RUNTIME_DIRS: &[(&str, InitSystem)] = &[
+    ("/run/systemd/system", InitSystem::Systemd),
+    ("/run/openrc",         InitSystem::OpenRC),
+    ("/run/runit",          InitSystem::Runit),
+    ("/run/s6-rc",          InitSystem::S6),
+    ("/run/s6",             InitSystem::S6),
+    ("/run/dinitctl",       InitSystem::Dinit)
+];
+
+/// Installed init system binaries
+const BINARIES: &[(&str, InitSystem)] = &[
+    ("/usr/lib/systemd/systemd", InitSystem::Systemd),
+    ("/lib/systemd/systemd",     InitSystem::Systemd),
+    ("/sbin/openrc-init",        InitSystem::OpenRC),
+    ("/usr/bin/openrc-init",     InitSystem::OpenRC),
+    ("/sbin/openrc",             InitSystem::OpenRC),
+    ("/usr/bin/runit",           InitSystem::Runit),
+    ("/sbin/runit",              InitSystem::Runit),
+    ("/usr/bin/s6-svscan",       InitSystem::S6),
+    ("/usr/bin/dinit",           InitSystem::Dinit),
+    ("/sbin/dinit",              InitSystem::Dinit),
+    ("/etc/inittab",             InitSystem::SysVinit)
+];
+
+/// Detect init system used by the system
+/// 
+/// Name of the PID 1 process is checked first, then runtime directories
+/// created by init systems, and then installed init binaries. Systemd binaries
+/// are ignored on systemd-free distros (e.g. Artix) because they can still
+/// ship parts of systemd like udev or elogind
+/// 
+/// ```
+/// let init = whatadistro::detect_init_system();
+/// 
+/// println!("Init system: {init}");
+/// ```
+pub fn detect_init_system() -> InitSystem {
+    detect_init_system_with_fs(&RealFs)
+}
+
+/// Detect init system using files of the given filesystem
+/// 
+/// ```
+/// use whatadistro::{InitSystem, MockFs};
+/// 
+/// let fs = MockFs::default()
+///     .with_file("/usr/lib/os-release", "NAME=Artix\nID=artix")
+///     .with_file("/usr/lib/systemd/systemd", "")
+///     .with_file("/usr/bin/openrc-init", "");
+/// 
+/// assert_eq!(whatadistro::detect_init_system_with_fs(&fs), InitSystem::OpenRC);
+/// assert_eq!(whatadistro::detect_init_system_with_fs(&MockFs::default()), InitSystem::Unknown);
+/// ```
+pub fn detect_init_system_with_fs(fs: &impl FileSystem) -> InitSystem {
+    if let Ok(comm) = fs.read_to_string(Path::new("/proc/1/comm")) {
+        if let Some(init) = InitSystem::from_comm(comm.trim()) {
+            trace!("init system detected from /proc/1/comm: {init}");
+
+            return init;
+        }
+    }
+
+    if let Some((path, init)) = RUNTIME_DIRS.iter().find(|(path, _)| fs.read_dir(Path::new(path)).is_ok()) {
+        trace!("init system detected from {path}: {init}");
+
+        return *init;
+    }
+
+    let systemd_free = identify_with_fs(fs)
+        .map(|distro| distro.id().is_systemd_free())
+        .unwrap_or(false);
+
+    let init = BINARIES.iter()
+        .filter(|(_, init)| !(systemd_free && init == &InitSystem::Systemd))
+        .find(|(path, _)| fs.exists(Path::new(path)));
+
+    if let Some((path, init)) = init {
+        trace!("init system detected from {path}: {init}");
+
+        return *init;
+    }
+
+    InitSystem::Unknown
+}
@@ -0,0 +1,178 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt::{Debug, Display};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::Arc;
+
+thread_local! {
+    /// Ids interned by the current thread
+    /// 
+    /// Every thread has its own table, so parsing in parallel
+    /// doesn't contend on a global lock
+    static INTERNED: RefCell<HashSet<Arc<str>>> = RefCell::new(HashSet::new());
+}
+
+#[derive(Clone)]
+enum Repr {
+    Static(&'static str),
+    Shared(Arc<str>)
+}
+
+#[derive(Clone)]
+/// Interned id of an unknown distro (`DistroId::Other`)
+/// 
+/// Equal ids parsed by the same thread share the same heap allocation,
+/// so holding lots of distros with the same unknown `ID` or `ID_LIKE`
+/// values is cheap
+/// 
+/// ```
+/// use whatadistro::{DistroId, OtherId};
+/// 
+/// let id = DistroId::from("void");
+/// 
+/// assert_eq!(id, DistroId::Other(OtherId::new("void")));
+/// assert_eq!(id.as_str(), "void");
+/// ```
+pub struct OtherId(Repr);
+
+impl OtherId {
+    /// Maximal amount of ids interned by a single thread
+    /// 
+    /// Ids are never removed from the table so the limit protects
+    /// from unbounded growth when parsing untrusted files. Once it's
+    /// reached new ids are allocated separately: they're still equal
+    /// to other copies but don't share memory with them
+    pub const MAX_INTERNED: usize = 4096;
+
+    /// Get interned id
+    /// 
+    /// ```
+    /// use whatadistro::OtherId;
+    /// 
+    /// let a = OtherId::new("void");
+    /// let b = OtherId::new("void");
+    /// 
+    /// assert_eq!(a.as_str().as_ptr(), b.as_str().as_ptr());
+    /// ```
+    pub fn new(id: &str) -> Self {
+        let interned = INTERNED.try_with(|interned| {
+            let mut interned = interned.try_borrow_mut().ok()?;
+
+            if let Some(id) = interned.get(id) {
+                return Some(id.clone());
+            }
+
+            let id = Arc::<str>::from(id);
+
+            if interned.len() < Self::MAX_INTERNED {
+                interned.insert(id.clone());
+            }
+
+            Some(id)
+        });
+
+        // Thread local table is not available during thread shutdown
+        let id = interned.ok()
+            .flatten()
+            .unwrap_or_else(|| Arc::from(id));
+
+        Self(Repr::Shared(id))
+    }
+
+    #[inline]
+    /// Make empty id in const context
+    pub(crate) const fn empty() -> Self {
+        Self(Repr::Static(""))
+    }
+
+    #[inline]
+    /// Get id value
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Static(id) => id,
+            Repr::Shared(id) => id
+        }
+    }
+}
+
+impl Deref for OtherId {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for OtherId {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&str> for OtherId {
+    #[inline]
+    fn from(id: &str) -> Self {
+        Self::new(id)
+    }
+}
+
+impl From<String> for OtherId {
+    #[inline]
+    fn from(id: String) -> Self {
+        Self::new(&id)
+    }
+}
+
+impl PartialEq for OtherId {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for OtherId {}
+
+impl PartialEq<str> for OtherId {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for OtherId {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for OtherId {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl Hash for OtherId {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl Debug for OtherId {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl Display for OtherId {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,244 @@
+//! Minimal JSON parser for the command outputs and serialized data
+
+/// Maximal nesting of arrays and objects
+const MAX_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>)
+}
+
+impl Value {
+    /// Get object value by its key
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Self::Object(entries) => entries.iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+
+            _ => None
+        }
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+
+            _ => None
+        }
+    }
+
+    #[inline]
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(value) => Some(*value),
+
+            _ => None
+        }
+    }
+
+    #[inline]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+
+            _ => None
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(not(feature = "ostree"), allow(dead_code))]
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Self::Array(values) => Some(values),
+
+            _ => None
+        }
+    }
+}
+
+/// Parse JSON document
+/// 
+/// Returns `None` if the document is malformed or nested too deep
+pub(crate) fn parse(json: &str) -> Option<Value> {
+    let mut parser = Parser {
+        chars: json.chars().peekable()
+    };
+
+    let value = parser.value(0)?;
+
+    parser.skip_whitespace();
+
+    if parser.chars.next().is_some() {
+        return None;
+    }
+
+    Some(value)
+}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|char| matches!(char, ' ' | '\t' | '\n' | '\r')).is_some() {}
+    }
+
+    fn expect(&mut self, expected: &str) -> Option<()> {
+        for char in expected.chars() {
+            if self.chars.next()? != char {
+                return None;
+            }
+        }
+
+        Some(())
+    }
+
+    fn value(&mut self, depth: usize) -> Option<Value> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+
+        self.skip_whitespace();
+
+        match self.chars.peek()? {
+            'n' => self.expect("null").map(|_| Value::Null),
+            't' => self.expect("true").map(|_| Value::Bool(true)),
+            'f' => self.expect("false").map(|_| Value::Bool(false)),
+            '"' => self.string().map(Value::String),
+
+            '[' => {
+                self.chars.next();
+
+                let mut values = Vec::new();
+
+                self.skip_whitespace();
+
+                if self.chars.next_if_eq(&']').is_some() {
+                    return Some(Value::Array(values));
+                }
+
+                loop {
+                    values.push(self.value(depth + 1)?);
+
+                    self.skip_whitespace();
+
+                    match self.chars.next()? {
+                        ',' => continue,
+                        ']' => return Some(Value::Array(values)),
+
+                        _ => return None
+                    }
+                }
+            }
+
+            '{' => {
+                self.chars.next();
+
+                let mut entries = Vec::new();
+
+                self.skip_whitespace();
+
+                if self.chars.next_if_eq(&'}').is_some() {
+                    return Some(Value::Object(entries));
+                }
+
+                loop {
+                    self.skip_whitespace();
+
+                    let key = self.string()?;
+
+                    self.skip_whitespace();
+                    self.expect(":")?;
+
+                    entries.push((key, self.value(depth + 1)?));
+
+                    self.skip_whitespace();
+
+                    match self.chars.next()? {
+                        ',' => continue,
+                        '}' => return Some(Value::Object(entries)),
+
+                        _ => return None
+                    }
+                }
+            }
+
+            _ => {
+                let mut number = String::new();
+
+                while let Some(char) = self.chars.next_if(|char| matches!(char, '0'..='9' | '-' | '+' | '.' | 'e' | 'E')) {
+                    number.push(char);
+                }
+
+                number.parse::<f64>().ok().map(Value::Number)
+            }
+        }
+    }
+
+    fn string(&mut self) -> Option<String> {
+        self.expect("\"")?;
+
+        let mut string = String::new();
+
+        loop {
+            match self.chars.next()? {
+                '"' => return Some(string),
+
+                '\\' => match self.chars.next()? {
+                    'b' => string.push('\u{8}'),
+                    'f' => string.push('\u{c}'),
+                    'n' => string.push('\n'),
+                    'r' => string.push('\r'),
+                    't' => string.push('\t'),
+                    'u' => string.push(self.unicode_escape()?),
+
+                    char @ ('"' | '\\' | '/') => string.push(char),
+
+                    _ => return None
+                }
+
+                char => string.push(char)
+            }
+        }
+    }
+
+    /// Parse `\uXXXX` escape including surrogate pairs,
+    /// invalid code points are replaced by `U+FFFD`
+    fn unicode_escape(&mut self) -> Option<char> {
+        let high = self.hex()?;
+
+        if !(0xD800..0xDC00).contains(&high) {
+            return Some(char::from_u32(high).unwrap_or(char::REPLACEMENT_CHARACTER));
+        }
+
+        self.expect("\\u")?;
+
+        let low = self.hex()?;
+
+        if !(0xDC00..0xE000).contains(&low) {
+            return Some(char::REPLACEMENT_CHARACTER);
+        }
+
+        let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+
+        Some(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    fn hex(&mut self) -> Option<u32> {
+        let mut code = 0;
+
+        for _ in 0..4 {
+            code = code * 16 + self.chars.next()?.to_digit(16)?;
+        }
+
+        Some(code)
+    }
+}
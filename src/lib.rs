@@ -1,9 +1,246 @@
-use std::collections::HashSet;
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic, clippy::unreachable, clippy::todo, clippy::unimplemented)]
+
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
 use std::fmt::Display;
+use std::str::FromStr;
+use std::path::Path;
+
+/// Emit detection step event if `trace-hook` feature is enabled
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "trace-hook")]
+        $crate::trace::emit(module_path!(), format_args!($($arg)*));
+
+        // Keep arguments used when the feature is disabled
+        #[cfg(not(feature = "trace-hook"))]
+        let _ = format_args!($($arg)*);
+    };
+}
+
+pub mod cmdline;
+pub mod envfile;
+pub mod security;
+pub mod prelude;
+
+mod os_release;
+mod lsb_release;
+mod date;
+mod intern;
+mod cpe;
+mod color;
+mod fields;
+mod eol;
+mod codename;
+mod version;
+mod channel;
+mod diff;
+mod overrides;
+mod sources;
+mod live;
+mod privilege;
+mod confidence;
+mod package_manager;
+mod metadata;
+mod distro_match;
+mod bulk;
+mod options;
+mod model;
+mod cached;
+mod warnings;
+mod exec;
+mod borrowed;
+mod fedora;
+mod fs;
+mod drop_ins;
+mod sbom;
+mod capabilities;
+mod glob;
+mod disk_cache;
+mod variant;
+mod graph;
+mod suggest;
+mod schema;
+mod ambiguity;
+mod platform;
+
+#[cfg(any(feature = "audit", feature = "ostree"))]
+mod json;
+
+#[cfg(feature = "packages")]
+pub mod repos;
+
+#[cfg(feature = "audit")]
+pub mod audit;
+
+#[cfg(feature = "system-info")]
+mod system;
+
+#[cfg(feature = "system-info")]
+mod boot;
+
+#[cfg(feature = "system-info")]
+mod dmi;
+
+#[cfg(feature = "system-info")]
+mod board;
+
+#[cfg(feature = "system-info")]
+mod mounts;
+
+#[cfg(feature = "system-info")]
+mod init;
+
+#[cfg(feature = "system-info")]
+mod virtualization;
+
+#[cfg(feature = "system-info")]
+mod runtime;
+
+#[cfg(feature = "system-info")]
+mod summary;
+
+#[cfg(feature = "system-info")]
+mod steam;
+
+#[cfg(feature = "desktop")]
+mod graphics;
+
+#[cfg(feature = "containers")]
+mod sandbox;
+
+#[cfg(feature = "packages")]
+mod arch;
+
+#[cfg(feature = "packages")]
+mod apt;
+
+#[cfg(feature = "packages")]
+mod dkms;
+
+#[cfg(feature = "packages")]
+mod prefix;
+
+#[cfg(target_os = "linux")]
+mod watch;
+
+#[cfg(feature = "i18n")]
+mod i18n;
+
+#[cfg(feature = "trace-hook")]
+pub mod trace;
+
+#[cfg(feature = "url-fields")]
+mod url;
+
+#[cfg(feature = "static-target")]
+mod static_target;
+
+#[cfg(feature = "oci")]
+mod oci;
+
+#[cfg(feature = "oci")]
+mod inflate;
+
+#[cfg(feature = "os-info-names")]
+mod os_info_names;
+
+#[cfg(feature = "ostree")]
+mod ostree;
+
+pub use overrides::{set_override, clear_override};
+pub use sources::{Source, DetectionSource, Reconcile, identify_all, identify_all_with_fs, reconcile};
+pub use date::Date;
+pub use eol::SuggestedUpgrade;
+pub use intern::OtherId;
+pub use cpe::{Cpe, CpePart};
+pub use color::AnsiColor;
+pub use diff::DistroDiff;
+pub use codename::DistroVersion;
+pub use channel::ReleaseChannel;
+pub use model::ReleaseModel;
+pub use cached::{id, name, version, is};
+pub use warnings::ParseWarning;
+pub use fields::{IconName, PlatformId};
+pub use privilege::{PrivilegeTool, detect_privilege_tool};
+pub use confidence::Confidence;
+pub use metadata::DistroMetadata;
+pub use bulk::identify_many;
+pub use options::{IdentifyOptions, identify_with};
+pub use borrowed::DistroRef;
+pub use fedora::FedoraVariant;
+pub use fs::{FileSystem, RealFs, MockFs};
+pub use disk_cache::{identify_with_disk_cache, clear_disk_cache};
+pub use variant::DistroVariant;
+pub use suggest::UnknownDistroError;
+pub use schema::{SCHEMA_VERSION, SchemaError};
+pub use ambiguity::{Ambiguity, AmbiguityReason};
+pub use platform::{IdentifyError, OperatingSystem, try_identify};
+pub use capabilities::{CrateCapabilities, capabilities};
+pub use exec::{with_timeout, without_exec};
+
+#[cfg(feature = "system-info")]
+pub use system::SystemInfo;
+
+#[cfg(feature = "system-info")]
+pub use boot::{Firmware, Bootloader};
+
+#[cfg(feature = "system-info")]
+pub use dmi::DmiInfo;
+
+#[cfg(feature = "system-info")]
+pub use board::{BoardInfo, detect_board, detect_board_with_fs};
+
+#[cfg(feature = "system-info")]
+pub use mounts::MountEntry;
+
+#[cfg(feature = "system-info")]
+pub use init::{InitSystem, detect_init_system, detect_init_system_with_fs};
+
+#[cfg(feature = "system-info")]
+pub use virtualization::{Virtualization, detect_virtualization, detect_virtualization_with_fs};
+
+#[cfg(feature = "system-info")]
+pub use runtime::{Runtime, RuntimeRequirement};
+
+#[cfg(feature = "system-info")]
+pub use summary::Summary;
+
+#[cfg(feature = "desktop")]
+pub use graphics::{GpuVendor, GraphicsApi, DisplayServer, detect_gpu_vendors, detect_gpu_vendors_with_fs, detect_display_server};
+
+#[cfg(feature = "containers")]
+pub use sandbox::{Sandbox, detect_sandbox, detect_sandbox_with_fs, identify_host, identify_host_with_fs};
+
+#[cfg(feature = "packages")]
+pub use prefix::{PrefixHost, identify_for_prefix};
+
+#[cfg(feature = "packages")]
+pub use dkms::DkmsSupport;
+
+#[cfg(feature = "packages")]
+pub use arch::{AurHelper, ArchInfo};
+
+#[cfg(feature = "packages")]
+pub use apt::AptInfo;
+
+#[cfg(feature = "url-fields")]
+pub use url::Url;
+
+#[cfg(feature = "oci")]
+pub use oci::{identify_tar, identify_oci_layout};
+
+#[cfg(feature = "ostree")]
+pub use ostree::{OstreeInfo, OstreeDeployment};
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 /// IDs of supported linux distros
+/// 
+/// Can be created from `&str`, `String` and `Cow<str>` values or parsed
+/// with `str::parse`. Other string types (`Box<str>`, `Rc<str>`) should
+/// be converted using `DistroId::from(value.as_ref())`
 pub enum DistroId {
     /// Arch Linux
     /// 
@@ -23,6 +260,7 @@ pub enum DistroId {
     /// 
     /// ```bash
     /// ID=ubuntu
+    /// ID=pop
     /// ```
     Ubuntu,
 
@@ -52,6 +290,7 @@ pub enum DistroId {
     /// ```bash
     /// ID=suse
     /// ID=opensuse
+    /// ID=opensuse-leap
     /// ID=opensuse-tumbleweed
     /// ```
     OpenSUSE,
@@ -68,67 +307,182 @@ pub enum DistroId {
     /// ```
     NixOS,
 
+    /// Amazon Linux (2, 2023)
+    /// 
+    /// ```bash
+    /// ID="amzn"
+    /// ```
+    AmazonLinux,
+
+    /// Azure Linux (formerly CBL-Mariner)
+    /// 
+    /// ```bash
+    /// ID=azurelinux
+    /// ID=mariner
+    /// ```
+    AzureLinux,
+
+    /// Google Container-Optimized OS
+    /// 
+    /// ```bash
+    /// ID=cos
+    /// ```
+    ContainerOptimizedOS,
+
+    /// Bottlerocket
+    /// 
+    /// ```bash
+    /// ID=bottlerocket
+    /// ```
+    Bottlerocket,
+
+    /// Flatcar Container Linux
+    /// 
+    /// ```bash
+    /// ID=flatcar
+    /// ```
+    Flatcar,
+
+    /// Manjaro
+    /// 
+    /// ```bash
+    /// ID=manjaro
+    /// ID=manjaro-arm
+    /// ```
+    Manjaro,
+
+    /// EndeavourOS
+    /// 
+    /// ```bash
+    /// ID=endeavouros
+    /// ```
+    EndeavourOS,
+
+    /// Garuda Linux
+    /// 
+    /// ```bash
+    /// ID=garuda
+    /// ```
+    Garuda,
+
+    /// Artix Linux (Arch Linux without systemd)
+    /// 
+    /// ```bash
+    /// ID=artix
+    /// ```
+    Artix,
+
+    /// Raspberry Pi OS (previously Raspbian)
+    /// 
+    /// ```bash
+    /// ID=raspbian
+    /// ```
+    Raspbian,
+
+    /// SteamOS (Valve's Arch-based gaming distro)
+    /// 
+    /// ```bash
+    /// ID=steamos
+    /// ```
+    SteamOS,
+
+    /// Alpine Linux (musl-based, common in containers)
+    /// 
+    /// ```bash
+    /// ID=alpine
+    /// ```
+    Alpine,
+
+    /// postmarketOS (Alpine-based distro for phones)
+    /// 
+    /// ```bash
+    /// ID=postmarketos
+    /// ```
+    PostmarketOS,
+
+    /// Linux Mint Debian Edition
+    /// 
+    /// ```bash
+    /// ID=lmde
+    /// ```
+    LMDE,
+
+    /// Devuan (Debian without systemd)
+    /// 
+    /// ```bash
+    /// ID=devuan
+    /// ```
+    Devuan,
+
+    /// MX Linux
+    /// 
+    /// ```bash
+    /// ID=mx
+    /// ```
+    MXLinux,
+
+    /// antiX (lightweight Debian without systemd)
+    /// 
+    /// ```bash
+    /// ID=antix
+    /// ```
+    AntiX,
+
+    /// Kali Linux
+    /// 
+    /// ```bash
+    /// ID=kali
+    /// ```
+    Kali,
+
     /// Nothing from above
-    Other(String)
+    /// 
+    /// Contains original interned `ID` value. Note that `Other` values containing
+    /// ids of known distros (e.g. `Other("arch")`) are not produced by
+    /// `From` conversions and will not round-trip through `as_str`
+    Other(OtherId)
 }
 
 impl DistroId {
-    /// List distro ids similar to the current one.
+    /// List distro ids similar to the current one, closest first.
     /// Always include current distro itself
+    /// 
+    /// Similar distros are all the known distros from the same family
+    /// 
+    /// ```
+    /// use whatadistro::DistroId;
+    /// 
+    /// assert_eq!(DistroId::Mint.list_similar(), vec![
+    ///     DistroId::Mint,
+    ///     DistroId::Ubuntu,
+    ///     DistroId::Debian,
+    ///     DistroId::Raspbian,
+    ///     DistroId::LMDE,
+    ///     DistroId::Devuan,
+    ///     DistroId::MXLinux,
+    ///     DistroId::AntiX,
+    ///     DistroId::Kali
+    /// ]);
+    /// ```
     pub fn list_similar(&self) -> Vec<Self> {
-        match self {
-            Self::Arch => vec![
-                Self::Arch
-            ],
-
-            Self::Debian => vec![
-                Self::Debian,
-                Self::Ubuntu,
-                Self::Mint
-            ],
-
-            Self::Ubuntu => vec![
-                Self::Ubuntu,
-                Self::Debian,
-                Self::Mint
-            ],
-
-            Self::Mint => vec![
-                Self::Mint,
-                Self::Debian,
-                Self::Ubuntu
-            ],
-
-            Self::RHEL => vec![
-                Self::RHEL,
-                Self::Fedora,
-                Self::OpenSUSE
-            ],
-
-            Self::Fedora => vec![
-                Self::Fedora,
-                Self::RHEL,
-                Self::OpenSUSE
-            ],
-
-            Self::OpenSUSE => vec![
-                Self::OpenSUSE,
-                Self::Fedora,
-                Self::RHEL
-            ],
-
-            Self::Gentoo => vec![
-                Self::Gentoo
-            ],
-
-            Self::NixOS => vec![
-                Self::NixOS
-            ],
-
-            Self::Other(id) => vec![
-                Self::Other(id.clone())
-            ]
+        let family = self.family();
+
+        let mut similar = vec![self.clone()];
+
+        if family == DistroFamily::Other {
+            return similar;
         }
+
+        let mut relatives = Self::ALL.iter()
+            .filter(|id| *id != self && id.family() == family)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        relatives.sort_by_key(|id| self.distance(id));
+
+        similar.extend(relatives);
+
+        similar
     }
 
     #[inline]
@@ -136,152 +490,1114 @@ impl DistroId {
     pub fn is_similar<T: Into<Self>>(&self, other: T) -> bool {
         self.list_similar().contains(&other.into())
     }
-}
 
-impl<T> From<T> for DistroId where T: AsRef<str> {
-    fn from(str: T) -> Self {
-        match str.as_ref() {
-            "arch"   => Self::Arch,
-            "debian" => Self::Debian,
-            "ubuntu" => Self::Ubuntu,
+    /// All known distros
+    /// 
+    /// ```
+    /// use whatadistro::DistroId;
+    /// 
+    /// for id in DistroId::ALL {
+    ///     println!("{} ({})", id.name(), id.family());
+    /// }
+    /// 
+    /// assert!(DistroId::ALL.contains(&DistroId::Debian));
+    /// ```
+    pub const ALL: &'static [Self] = &[
+        Self::Arch,
+        Self::Debian,
+        Self::Ubuntu,
+        Self::Mint,
+        Self::RHEL,
+        Self::Fedora,
+        Self::OpenSUSE,
+        Self::Gentoo,
+        Self::NixOS,
+        Self::AmazonLinux,
+        Self::AzureLinux,
+        Self::ContainerOptimizedOS,
+        Self::Bottlerocket,
+        Self::Flatcar,
+        Self::Manjaro,
+        Self::EndeavourOS,
+        Self::Garuda,
+        Self::Artix,
+        Self::Raspbian,
+        Self::SteamOS,
+        Self::Alpine,
+        Self::PostmarketOS,
+        Self::LMDE,
+        Self::Devuan,
+        Self::MXLinux,
+        Self::AntiX,
+        Self::Kali
+    ];
 
-            "mint"      => Self::Mint,
-            "linuxmint" => Self::Mint,
+    /// Known `ID` values and distros they belong to, including aliases
+    /// 
+    /// ```
+    /// use whatadistro::DistroId;
+    /// 
+    /// // Build lookup tables in const context
+    /// const IDS: [&str; DistroId::KNOWN_IDS.len()] = {
+    ///     let mut ids = [""; DistroId::KNOWN_IDS.len()];
+    ///     let mut i = 0;
+    /// 
+    ///     while i < ids.len() {
+    ///         ids[i] = DistroId::KNOWN_IDS[i].0;
+    ///         i += 1;
+    ///     }
+    /// 
+    ///     ids
+    /// };
+    /// 
+    /// assert!(IDS.contains(&"linuxmint"));
+    /// ```
+    pub const KNOWN_IDS: &'static [(&'static str, Self)] = &[
+        ("arch",   Self::Arch),
+        ("debian", Self::Debian),
+        ("ubuntu", Self::Ubuntu),
 
-            "rhel"   => Self::RHEL,
-            "fedora" => Self::Fedora,
+        // Pop!_OS is a rebranded Ubuntu
+        ("pop", Self::Ubuntu),
 
-            "suse"                => Self::OpenSUSE,
-            "opensuse"            => Self::OpenSUSE,
-            "opensuse_tumbleweed" => Self::OpenSUSE,
+        ("mint",                     Self::Mint),
+        ("linuxmint",                Self::Mint),
 
-            "gentoo" => Self::Gentoo,
-            "nixos"  => Self::NixOS,
+        ("rhel",   Self::RHEL),
+        ("fedora", Self::Fedora),
 
-            id => Self::Other(id.to_string())
-        }
-    }
-}
+        ("suse",                Self::OpenSUSE),
+        ("opensuse",            Self::OpenSUSE),
+        ("opensuse-leap",       Self::OpenSUSE),
+        ("opensuse-tumbleweed", Self::OpenSUSE),
+        ("opensuse-slowroll",   Self::OpenSUSE),
+        ("opensuse-microos",    Self::OpenSUSE),
 
-impl Display for DistroId {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Arch     => write!(f, "arch"),
-            Self::Debian   => write!(f, "debian"),
-            Self::Ubuntu   => write!(f, "ubuntu"),
-            Self::Mint     => write!(f, "linuxmint"),
-            Self::RHEL     => write!(f, "rhel"),
-            Self::Fedora   => write!(f, "fedora"),
-            Self::OpenSUSE => write!(f, "opensuse"),
-            Self::Gentoo   => write!(f, "gentoo"),
-            Self::NixOS    => write!(f, "nixos"),
-
-            Self::Other(id) => write!(f, "{id}")
-        }
-    }
-}
+        ("gentoo", Self::Gentoo),
+        ("nixos",  Self::NixOS),
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Distro {
-    name: String,
-    id: DistroId,
-    similar_ids: HashSet<DistroId>
-}
+        ("amzn", Self::AmazonLinux),
 
-impl Distro {
-    #[inline]
-    /// Identify current linux distro using `/etc/os-release` file
-    pub fn current() -> Option<Self> {
-        identify()
-    }
+        ("azurelinux", Self::AzureLinux),
+        ("mariner",    Self::AzureLinux),
 
-    #[inline]
-    /// Get current distro name (`NAME` entry)
-    pub fn name(&self) -> &str {
-        &self.name
-    }
+        ("cos",          Self::ContainerOptimizedOS),
+        ("bottlerocket", Self::Bottlerocket),
+        ("flatcar",      Self::Flatcar),
 
-    #[inline]
-    /// Get current distro id (`ID` entry)
-    pub fn id(&self) -> &DistroId {
-        &self.id
-    }
+        ("manjaro",     Self::Manjaro),
+        ("manjaro-arm", Self::Manjaro),
+        ("endeavouros", Self::EndeavourOS),
+        ("garuda",      Self::Garuda),
+        ("artix",       Self::Artix),
 
-    #[inline]
-    /// Get list of similar distros (`ID_LIKE` entry)
+        ("raspbian", Self::Raspbian),
+
+        ("steamos", Self::SteamOS),
+
+        ("alpine", Self::Alpine),
+
+        ("postmarketos", Self::PostmarketOS),
+
+        ("lmde",                     Self::LMDE),
+        ("linuxmint-debian-edition", Self::LMDE),
+
+        ("devuan",  Self::Devuan),
+        ("mx",      Self::MXLinux),
+        ("mxlinux", Self::MXLinux),
+        ("antix",   Self::AntiX),
+        ("kali",    Self::Kali)
+    ];
+
+    /// Get known distro by its `ID` value
+    /// 
+    /// Comparison is ASCII case-insensitive and doesn't distinguish
+    /// `-` and `_` (`opensuse_tumbleweed` is `opensuse-tumbleweed`).
+    /// Unlike `From<&str>` this function returns `None` for unknown ids
+    /// and can be used in const context
     /// 
     /// ```
-    /// if let Some(distro) = whatadistro::identify() {
-    ///     println!("Your distro: {} ({})", distro.name(), distro.id());
-    ///     println!("Similar distros: {:?}", distro.id().list_similar());
-    /// }
+    /// use whatadistro::DistroId;
+    /// 
+    /// const MINT: Option<DistroId> = DistroId::from_ascii_lowercase("linuxmint");
+    /// 
+    /// assert_eq!(MINT, Some(DistroId::Mint));
+    /// assert_eq!(DistroId::from_ascii_lowercase("Arch"), Some(DistroId::Arch));
+    /// assert_eq!(DistroId::from_ascii_lowercase("opensuse_tumbleweed"), Some(DistroId::OpenSUSE));
+    /// assert_eq!(DistroId::from_ascii_lowercase("unknown"), None);
     /// ```
-    pub fn similar_ids(&self) -> &HashSet<DistroId> {
-        &self.similar_ids
+    pub const fn from_ascii_lowercase(id: &str) -> Option<Self> {
+        let id = id.as_bytes();
+        let mut i = 0;
+
+        while i < Self::KNOWN_IDS.len() {
+            let (known_id, distro) = &Self::KNOWN_IDS[i];
+
+            if eq_normalized(id, known_id.as_bytes()) {
+                return Some(distro.const_clone());
+            }
+
+            i += 1;
+        }
+
+        None
     }
 
-    #[inline]
-    /// Compare current distro with some another
+    /// Get canonical `ID` value of the known distro
+    /// 
+    /// Returns `None` for `DistroId::Other`. Can be used in const context
     /// 
     /// ```
-    /// let status = whatadistro::identify()
-    ///     .map(|distro| distro.is_similar("arch")) // whatadistro::Distro::Arch can be used as well
-    ///     .unwrap_or(false);
+    /// use whatadistro::DistroId;
     /// 
-    /// println!("Is current system arch-based: {:?}", status);
+    /// const MINT: Option<&str> = DistroId::Mint.known_id();
+    /// 
+    /// assert_eq!(MINT, Some("linuxmint"));
     /// ```
-    pub fn is_similar<T: Into<DistroId>>(&self, other: T) -> bool {
-        let other = other.into();
+    pub const fn known_id(&self) -> Option<&'static str> {
+        match self {
+            Self::Arch     => Some("arch"),
+            Self::Debian   => Some("debian"),
+            Self::Ubuntu   => Some("ubuntu"),
+            Self::Mint     => Some("linuxmint"),
+            Self::RHEL     => Some("rhel"),
+            Self::Fedora   => Some("fedora"),
+            Self::OpenSUSE => Some("opensuse"),
+            Self::Gentoo   => Some("gentoo"),
+            Self::NixOS    => Some("nixos"),
 
-        self.similar_ids.contains(&other) || self.id.is_similar(other)
-    }
-}
+            Self::AmazonLinux          => Some("amzn"),
+            Self::AzureLinux           => Some("azurelinux"),
+            Self::ContainerOptimizedOS => Some("cos"),
+            Self::Bottlerocket         => Some("bottlerocket"),
+            Self::Flatcar              => Some("flatcar"),
 
-/// Identify current linux distro using `/etc/os-release` file
-/// 
-/// ```
-/// let distro = whatadistro::identify()
-///     .expect("Failed to parse os-release file");
-/// 
-/// println!("Your distro name is {}", distro.name());
-/// ```
-pub fn identify() -> Option<Distro> {
-    let mut id: Option<DistroId> = None;
-    let mut name: Option<String> = None;
-    let mut similar_ids: Option<HashSet<DistroId>> = None;
-
-    if let Ok(release) = std::fs::read_to_string("/etc/os-release") {
-        for line in release.lines() {
-            if let Some(distro_id) = line.strip_prefix("ID=") {
-                id = Some(distro_id.into());
-            }
+            Self::Manjaro     => Some("manjaro"),
+            Self::EndeavourOS => Some("endeavouros"),
+            Self::Garuda      => Some("garuda"),
+            Self::Artix       => Some("artix"),
 
-            else if let Some(distro_name) = line.strip_prefix("NAME=") {
-                name = Some(distro_name.to_string());
-            }
+            Self::Raspbian => Some("raspbian"),
 
-            else if let Some(ids) = line.strip_prefix("ID_LIKE=") {
-                similar_ids = Some(ids.split_whitespace().map(|id| id.into()).collect());
-            }
-        }
+            Self::SteamOS => Some("steamos"),
 
-        let Some(id) = id else {
-            return None;
-        };
+            Self::Alpine => Some("alpine"),
 
-        // TODO: maybe I can use here something like id.name() ?
-        let Some(name) = name else {
-            return None;
-        };
+            Self::PostmarketOS => Some("postmarketos"),
 
-        Some(Distro {
-            id,
-            name,
-            similar_ids: similar_ids.unwrap_or_default()
-        })
+            Self::LMDE    => Some("lmde"),
+            Self::Devuan  => Some("devuan"),
+            Self::MXLinux => Some("mx"),
+            Self::AntiX   => Some("antix"),
+            Self::Kali    => Some("kali"),
+
+            Self::Other(_) => None
+        }
     }
 
-    else {
-        None
+    /// Get stable numeric code of the distro
+    /// 
+    /// Codes are guaranteed to never change between releases and can be
+    /// used to store distro ids compactly (e.g. in databases or network
+    /// protocols). New variants get the next unused code, and codes of
+    /// removed variants are never reused. `DistroId::Other` has code `0`
+    /// 
+    /// ```
+    /// use whatadistro::DistroId;
+    /// 
+    /// assert_eq!(DistroId::Arch.code(), 1);
+    /// assert_eq!(DistroId::from("void").code(), 0);
+    /// ```
+    pub const fn code(&self) -> u16 {
+        match self {
+            Self::Arch     => 1,
+            Self::Debian   => 2,
+            Self::Ubuntu   => 3,
+            Self::Mint     => 4,
+            Self::RHEL     => 5,
+            Self::Fedora   => 6,
+            Self::OpenSUSE => 7,
+            Self::Gentoo   => 8,
+            Self::NixOS    => 9,
+
+            Self::AmazonLinux          => 10,
+            Self::AzureLinux           => 11,
+            Self::ContainerOptimizedOS => 12,
+            Self::Bottlerocket         => 13,
+            Self::Flatcar              => 14,
+
+            Self::Manjaro     => 15,
+            Self::EndeavourOS => 16,
+            Self::Garuda      => 17,
+            Self::Artix       => 18,
+
+            Self::Raspbian => 19,
+
+            Self::SteamOS => 20,
+
+            Self::Alpine => 21,
+
+            Self::PostmarketOS => 22,
+
+            Self::LMDE    => 23,
+            Self::Devuan  => 24,
+            Self::MXLinux => 25,
+            Self::AntiX   => 26,
+            Self::Kali    => 27,
+
+            Self::Other(_) => 0
+        }
     }
+
+    /// Get known distro by its numeric code
+    /// 
+    /// Returns `None` for `0` and unknown codes
+    /// 
+    /// ```
+    /// use whatadistro::DistroId;
+    /// 
+    /// const ARCH: Option<DistroId> = DistroId::from_code(1);
+    /// 
+    /// assert_eq!(ARCH, Some(DistroId::Arch));
+    /// assert_eq!(DistroId::from_code(DistroId::Fedora.code()), Some(DistroId::Fedora));
+    /// assert_eq!(DistroId::from_code(0), None);
+    /// ```
+    pub const fn from_code(code: u16) -> Option<Self> {
+        let mut i = 0;
+
+        while i < Self::ALL.len() {
+            if Self::ALL[i].code() == code {
+                return Some(Self::ALL[i].const_clone());
+            }
+
+            i += 1;
+        }
+
+        None
+    }
+
+    /// Get `ID` value of the distro
+    /// 
+    /// Canonical id is returned for known distros and the original
+    /// string for `DistroId::Other`. It is guaranteed that
+    /// `DistroId::from(id.as_str()) == id` for every `id` created
+    /// using `From` conversion
+    /// 
+    /// ```
+    /// use whatadistro::DistroId;
+    /// 
+    /// for (alias, _) in DistroId::KNOWN_IDS {
+    ///     let id = DistroId::from(alias);
+    /// 
+    ///     assert_eq!(DistroId::from(id.as_str()), id);
+    /// }
+    /// 
+    /// assert_eq!(DistroId::from("void").as_str(), "void");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Other(id) => id,
+
+            _ => self.known_id().unwrap_or_default()
+        }
+    }
+
+    /// Clone known distro id in const context
+    /// 
+    /// `Other` ids can't be cloned in const context so
+    /// they're replaced by empty `Other` ids
+    const fn const_clone(&self) -> Self {
+        match self {
+            Self::Arch     => Self::Arch,
+            Self::Debian   => Self::Debian,
+            Self::Ubuntu   => Self::Ubuntu,
+            Self::Mint     => Self::Mint,
+            Self::RHEL     => Self::RHEL,
+            Self::Fedora   => Self::Fedora,
+            Self::OpenSUSE => Self::OpenSUSE,
+            Self::Gentoo   => Self::Gentoo,
+            Self::NixOS    => Self::NixOS,
+
+            Self::AmazonLinux          => Self::AmazonLinux,
+            Self::AzureLinux           => Self::AzureLinux,
+            Self::ContainerOptimizedOS => Self::ContainerOptimizedOS,
+            Self::Bottlerocket         => Self::Bottlerocket,
+            Self::Flatcar              => Self::Flatcar,
+
+            Self::Manjaro     => Self::Manjaro,
+            Self::EndeavourOS => Self::EndeavourOS,
+            Self::Garuda      => Self::Garuda,
+            Self::Artix       => Self::Artix,
+
+            Self::Raspbian => Self::Raspbian,
+
+            Self::SteamOS => Self::SteamOS,
+
+            Self::Alpine => Self::Alpine,
+
+            Self::PostmarketOS => Self::PostmarketOS,
+
+            Self::LMDE    => Self::LMDE,
+            Self::Devuan  => Self::Devuan,
+            Self::MXLinux => Self::MXLinux,
+            Self::AntiX   => Self::AntiX,
+            Self::Kali    => Self::Kali,
+
+            Self::Other(_) => Self::Other(OtherId::empty())
+        }
+    }
+
+    /// Get closest parent distro in the family graph
+    /// 
+    /// Root distros of each family (Arch, Debian, Fedora, etc.) don't have parents.
+    /// openSUSE is a root too: it shares rpm with Fedora but is developed separately
+    /// 
+    /// ```
+    /// use whatadistro::DistroId;
+    /// 
+    /// assert_eq!(DistroId::Mint.parent(), Some(DistroId::Ubuntu));
+    /// assert_eq!(DistroId::Debian.parent(), None);
+    /// assert_eq!(DistroId::OpenSUSE.parent(), None);
+    /// ```
+    pub fn parent(&self) -> Option<Self> {
+        match self {
+            Self::Ubuntu => Some(Self::Debian),
+            Self::Mint   => Some(Self::Ubuntu),
+
+            Self::RHEL => Some(Self::Fedora),
+
+            // Amazon Linux 2023 and Azure Linux are built from Fedora sources
+            Self::AmazonLinux => Some(Self::Fedora),
+            Self::AzureLinux  => Some(Self::Fedora),
+
+            Self::Manjaro     => Some(Self::Arch),
+            Self::EndeavourOS => Some(Self::Arch),
+            Self::Garuda      => Some(Self::Arch),
+            Self::Artix       => Some(Self::Arch),
+
+            Self::Raspbian => Some(Self::Debian),
+
+            Self::SteamOS => Some(Self::Arch),
+
+            Self::PostmarketOS => Some(Self::Alpine),
+
+            Self::LMDE    => Some(Self::Debian),
+            Self::Devuan  => Some(Self::Debian),
+            Self::MXLinux => Some(Self::Debian),
+            Self::AntiX   => Some(Self::Debian),
+            Self::Kali    => Some(Self::Debian),
+
+            _ => None
+        }
+    }
+
+    /// List current distro and all its parents, closest first
+    fn ancestors(&self) -> Vec<Self> {
+        let mut ancestors = vec![self.clone()];
+
+        while let Some(parent) = ancestors[ancestors.len() - 1].parent() {
+            ancestors.push(parent);
+        }
+
+        ancestors
+    }
+
+    /// Calculate how similar given distro is to the current one
+    /// 
+    /// Score is computed from the distance between two distros in the family graph:
+    /// `1.0` for the same distro, decaying with each step, and `0.0` for unrelated distros
+    /// 
+    /// ```
+    /// use whatadistro::DistroId;
+    /// 
+    /// assert_eq!(DistroId::Mint.similarity_score(&DistroId::Mint), 1.0);
+    /// assert_eq!(DistroId::Mint.similarity_score(&DistroId::Ubuntu), 0.9);
+    /// assert_eq!(DistroId::Mint.similarity_score(&DistroId::Debian), 0.7);
+    /// assert_eq!(DistroId::Mint.similarity_score(&DistroId::Arch), 0.0);
+    /// ```
+    pub fn similarity_score(&self, other: &Self) -> f32 {
+        match self.distance(other) {
+            Some(0) => 1.0,
+            Some(1) => 0.9,
+            Some(2) => 0.7,
+            Some(3) => 0.5,
+            Some(_) => 0.3,
+
+            None => 0.0
+        }
+    }
+
+    /// Get number of steps between two distros in the family graph
+    /// 
+    /// Returns `None` for unrelated distros
+    fn distance(&self, other: &Self) -> Option<usize> {
+        let ours = self.ancestors();
+        let theirs = other.ancestors();
+
+        ours.iter().enumerate().find_map(|(i, id)| {
+            theirs.iter()
+                .position(|other| other == id)
+                .map(|j| i + j)
+        })
+    }
+
+    /// Get family the current distro belongs to
+    /// 
+    /// ```
+    /// use whatadistro::{DistroId, DistroFamily};
+    /// 
+    /// assert_eq!(DistroId::Mint.family(), DistroFamily::Debian);
+    /// ```
+    pub fn family(&self) -> DistroFamily {
+        match self {
+            Self::Arch |
+            Self::Manjaro |
+            Self::EndeavourOS |
+            Self::Garuda |
+            Self::Artix |
+            Self::SteamOS => DistroFamily::Arch,
+
+            Self::Debian |
+            Self::Ubuntu |
+            Self::Mint |
+            Self::Raspbian |
+            Self::LMDE |
+            Self::Devuan |
+            Self::MXLinux |
+            Self::AntiX |
+            Self::Kali => DistroFamily::Debian,
+
+            Self::RHEL |
+            Self::Fedora |
+            Self::OpenSUSE |
+            Self::AmazonLinux |
+            Self::AzureLinux => DistroFamily::RedHat,
+
+            Self::Gentoo => DistroFamily::Gentoo,
+            Self::NixOS  => DistroFamily::NixOS,
+
+            Self::Alpine |
+            Self::PostmarketOS => DistroFamily::Alpine,
+
+            // Container-optimized distros don't ship package managers
+            // so they're not compatible with anything else
+            Self::ContainerOptimizedOS |
+            Self::Bottlerocket |
+            Self::Flatcar |
+            Self::Other(_) => DistroFamily::Other
+        }
+    }
+
+    /// Check if the distro doesn't use systemd as its init system
+    /// 
+    /// ```
+    /// use whatadistro::DistroId;
+    /// 
+    /// assert!(DistroId::Artix.is_systemd_free());
+    /// assert!(DistroId::Devuan.is_systemd_free());
+    /// assert!(!DistroId::Arch.is_systemd_free());
+    /// ```
+    pub fn is_systemd_free(&self) -> bool {
+        matches!(self, Self::Artix | Self::Alpine | Self::Devuan | Self::AntiX)
+    }
+
+    /// Get human readable distro name
+    /// 
+    /// ```
+    /// use whatadistro::DistroId;
+    /// 
+    /// assert_eq!(DistroId::Mint.name(), "Linux Mint");
+    /// ```
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Arch     => "Arch Linux",
+            Self::Debian   => "Debian",
+            Self::Ubuntu   => "Ubuntu",
+            Self::Mint     => "Linux Mint",
+            Self::RHEL     => "Red Hat Enterprise Linux",
+            Self::Fedora   => "Fedora",
+            Self::OpenSUSE => "openSUSE",
+            Self::Gentoo   => "Gentoo",
+            Self::NixOS    => "NixOS",
+
+            Self::AmazonLinux          => "Amazon Linux",
+            Self::AzureLinux           => "Azure Linux",
+            Self::ContainerOptimizedOS => "Container-Optimized OS",
+            Self::Bottlerocket         => "Bottlerocket",
+            Self::Flatcar              => "Flatcar Container Linux",
+
+            Self::Manjaro     => "Manjaro",
+            Self::EndeavourOS => "EndeavourOS",
+            Self::Garuda      => "Garuda Linux",
+            Self::Artix       => "Artix Linux",
+
+            Self::Raspbian => "Raspberry Pi OS",
+
+            Self::SteamOS => "SteamOS",
+
+            Self::Alpine => "Alpine Linux",
+
+            Self::PostmarketOS => "postmarketOS",
+
+            Self::LMDE    => "LMDE",
+            Self::Devuan  => "Devuan GNU+Linux",
+            Self::MXLinux => "MX Linux",
+            Self::AntiX   => "antiX",
+            Self::Kali    => "Kali Linux",
+
+            Self::Other(id) => id
+        }
+    }
+}
+
+impl DistroId {
+    /// Parse distro id, falling back to `DistroId::Other`
+    fn parse(id: &str) -> Self {
+        Self::from_ascii_lowercase(id)
+            .unwrap_or_else(|| Self::Other(OtherId::new(id)))
+    }
+}
+
+impl From<&str> for DistroId {
+    #[inline]
+    fn from(id: &str) -> Self {
+        Self::parse(id)
+    }
+}
+
+impl From<&&str> for DistroId {
+    #[inline]
+    fn from(id: &&str) -> Self {
+        Self::parse(id)
+    }
+}
+
+impl From<String> for DistroId {
+    #[inline]
+    fn from(id: String) -> Self {
+        Self::parse(&id)
+    }
+}
+
+impl From<&String> for DistroId {
+    #[inline]
+    fn from(id: &String) -> Self {
+        Self::parse(id)
+    }
+}
+
+impl From<Cow<'_, str>> for DistroId {
+    #[inline]
+    fn from(id: Cow<'_, str>) -> Self {
+        Self::parse(&id)
+    }
+}
+
+impl From<&DistroId> for DistroId {
+    #[inline]
+    fn from(id: &DistroId) -> Self {
+        id.clone()
+    }
+}
+
+impl FromStr for DistroId {
+    type Err = Infallible;
+
+    #[inline]
+    /// Parse distro id, never fails
+    /// 
+    /// ```
+    /// use whatadistro::DistroId;
+    /// 
+    /// assert_eq!("ubuntu".parse::<DistroId>(), Ok(DistroId::Ubuntu));
+    /// ```
+    fn from_str(id: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(id))
+    }
+}
+
+impl Display for DistroId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Normalize `ID` byte for comparison (lowercase, `_` is `-`)
+const fn normalize(byte: u8) -> u8 {
+    match byte {
+        b'_' => b'-',
+
+        _ => byte.to_ascii_lowercase()
+    }
+}
+
+/// Compare two normalized ASCII strings in const context
+const fn eq_normalized(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+
+    while i < a.len() {
+        if normalize(a[i]) != normalize(b[i]) {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Families of similar linux distros
+pub enum DistroFamily {
+    /// Arch Linux and its derivatives
+    Arch,
+
+    /// Debian, Ubuntu, Linux Mint and other derivatives
+    Debian,
+
+    /// RHEL, Fedora, OpenSUSE and other rpm-based distros
+    RedHat,
+
+    /// Gentoo and its derivatives
+    Gentoo,
+
+    /// NixOS
+    NixOS,
+
+    /// Alpine Linux and other musl-based derivatives
+    Alpine,
+
+    /// Distro doesn't belong to any known family
+    /// or is a standalone distro (e.g. Bottlerocket)
+    Other
+}
+
+impl DistroFamily {
+    /// All distro families
+    pub const ALL: &'static [Self] = &[
+        Self::Arch,
+        Self::Debian,
+        Self::RedHat,
+        Self::Gentoo,
+        Self::NixOS,
+        Self::Alpine,
+        Self::Other
+    ];
+
+    /// Get human readable family name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Arch   => "Arch-based",
+            Self::Debian => "Debian-based",
+            Self::RedHat => "Red Hat-based",
+            Self::Gentoo => "Gentoo-based",
+            Self::NixOS  => "NixOS-based",
+            Self::Alpine => "Alpine-based",
+            Self::Other  => "Other"
+        }
+    }
+}
+
+impl Display for DistroFamily {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Arch   => write!(f, "arch"),
+            Self::Debian => write!(f, "debian"),
+            Self::RedHat => write!(f, "redhat"),
+            Self::Gentoo => write!(f, "gentoo"),
+            Self::NixOS  => write!(f, "nixos"),
+            Self::Alpine => write!(f, "alpine"),
+            Self::Other  => write!(f, "other")
+        }
+    }
+}
+
+/// Parse `ID_LIKE` value preserving the order and removing duplicates
+fn parse_id_like(value: &str) -> Vec<DistroId> {
+    let mut ids = Vec::new();
+
+    for id in value.split_whitespace().map(DistroId::from) {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+
+    ids
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Distro {
+    id: DistroId,
+    similar_ids: HashSet<DistroId>,
+    id_like: Vec<DistroId>,
+    fields: HashMap<String, String>,
+    confidence: Confidence,
+    source: Option<DetectionSource>,
+    warnings: Vec<ParseWarning>
+}
+
+impl Distro {
+    #[inline]
+    /// Identify current linux distro using `/etc/os-release` file
+    pub fn current() -> Option<Self> {
+        identify()
+    }
+
+    /// Parse distro from the os-release file content
+    /// 
+    /// Returns `None` if `ID` or `NAME` fields are missing
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, DistroId};
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME="Amazon Linux"
+    ///     ID="amzn"
+    ///     ID_LIKE="fedora"
+    /// "#).unwrap();
+    /// 
+    /// assert_eq!(distro.name(), "Amazon Linux");
+    /// assert_eq!(distro.id(), &DistroId::AmazonLinux);
+    /// ```
+    pub fn from_os_release(content: &str) -> Option<Self> {
+        let fields = os_release::parse(content);
+
+        let id = fields.get("ID").map(DistroId::from);
+        let name = fields.get("NAME");
+
+        let id_like = fields.get("ID_LIKE")
+            .map(|ids| parse_id_like(ids));
+
+        if let Some(id) = &id {
+            trace!("parsed ID: {id}");
+        }
+
+        else {
+            trace!("ID field is missing, identification failed");
+        }
+
+        if let Some(name) = &name {
+            trace!("parsed NAME: {name}");
+        }
+
+        else {
+            trace!("NAME field is missing, identification failed");
+        }
+
+        if let Some(ids) = &id_like {
+            trace!("parsed ID_LIKE: {ids:?}");
+        }
+
+        else {
+            trace!("ID_LIKE field is missing, using empty similar ids list");
+        }
+
+        // LMDE uses the same ID as Linux Mint but is based on Debian
+        let id = match id {
+            Some(DistroId::Mint) if name.map(|name| name.starts_with("LMDE")).unwrap_or(false) => {
+                trace!("LMDE detected from NAME field");
+
+                Some(DistroId::LMDE)
+            }
+
+            id => id
+        };
+
+        // TODO: maybe I can use here something like id.name() ?
+        name?;
+
+        Some(Self {
+            id: id?,
+            similar_ids: id_like.iter().flatten().cloned().collect(),
+            id_like: id_like.unwrap_or_default(),
+            fields,
+            confidence: Confidence::Exact,
+            source: None,
+            warnings: warnings::lint(content)
+        })
+    }
+
+    #[inline]
+    /// Parse distro from the raw os-release file content
+    /// 
+    /// Invalid UTF-8 sequences are replaced by `U+FFFD` instead of failing
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, DistroId};
+    /// 
+    /// let distro = Distro::from_os_release_bytes(b"NAME=\"Debian \xff\"\nID=debian").unwrap();
+    /// 
+    /// assert_eq!(distro.id(), &DistroId::Debian);
+    /// assert_eq!(distro.name(), "Debian \u{FFFD}");
+    /// ```
+    pub fn from_os_release_bytes(content: &[u8]) -> Option<Self> {
+        Self::from_os_release(&String::from_utf8_lossy(content))
+    }
+
+    #[inline]
+    /// Get current distro name (`NAME` entry)
+    pub fn name(&self) -> &str {
+        // NAME is always present because it's required by the parser
+        self.field("NAME").unwrap_or_default()
+    }
+
+    #[inline]
+    /// Get current distro id (`ID` entry)
+    pub fn id(&self) -> &DistroId {
+        &self.id
+    }
+
+    #[inline]
+    /// Get list of similar distros (`ID_LIKE` entry)
+    /// 
+    /// ```
+    /// if let Some(distro) = whatadistro::identify() {
+    ///     println!("Your distro: {} ({})", distro.name(), distro.id());
+    ///     println!("Similar distros: {:?}", distro.id().list_similar());
+    /// }
+    /// ```
+    pub fn similar_ids(&self) -> &HashSet<DistroId> {
+        &self.similar_ids
+    }
+
+    #[inline]
+    /// Get similar distros in the `ID_LIKE` entry order
+    /// 
+    /// Distros are listed from the closest to the most distant
+    /// relative. Duplicates are removed
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, DistroId};
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME="Linux Mint"
+    ///     ID=linuxmint
+    ///     ID_LIKE="ubuntu debian"
+    /// "#).unwrap();
+    /// 
+    /// assert_eq!(distro.id_like_raw(), &[DistroId::Ubuntu, DistroId::Debian]);
+    /// ```
+    pub fn id_like_raw(&self) -> &[DistroId] {
+        &self.id_like
+    }
+
+    #[inline]
+    /// Get the closest relative of the distro (first `ID_LIKE` entry)
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, DistroId};
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME="Linux Mint"
+    ///     ID=linuxmint
+    ///     ID_LIKE="ubuntu debian"
+    /// "#).unwrap();
+    /// 
+    /// assert_eq!(distro.primary_parent(), Some(&DistroId::Ubuntu));
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Debian\nID=debian").unwrap();
+    /// 
+    /// assert_eq!(distro.primary_parent(), None);
+    /// ```
+    pub fn primary_parent(&self) -> Option<&DistroId> {
+        self.id_like.first()
+    }
+
+    /// Pick candidate closest to the current distro
+    /// 
+    /// The distro itself is preferred, then `ID_LIKE` entries in their
+    /// order, then the closest relative in the family graph. Returns `None`
+    /// if no candidate is related to the distro
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, DistroId};
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME="Linux Mint"
+    ///     ID=linuxmint
+    ///     ID_LIKE="ubuntu debian"
+    /// "#).unwrap();
+    /// 
+    /// let artifacts = [DistroId::Debian, DistroId::Ubuntu, DistroId::Fedora];
+    /// 
+    /// assert_eq!(distro.best_match(&artifacts), Some(DistroId::Ubuntu));
+    /// assert_eq!(distro.best_match(&[DistroId::Fedora]), None);
+    /// ```
+    pub fn best_match(&self, candidates: &[DistroId]) -> Option<DistroId> {
+        if candidates.contains(&self.id) {
+            return Some(self.id.clone());
+        }
+
+        if let Some(id) = self.id_like.iter().find(|id| candidates.contains(id)) {
+            trace!("{id} is picked by ID_LIKE order");
+
+            return Some(id.clone());
+        }
+
+        candidates.iter()
+            .filter_map(|candidate| self.id.distance(candidate).map(|distance| (distance, candidate)))
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, candidate)| candidate.clone())
+    }
+
+    #[inline]
+    /// Get raw value of any os-release field, including vendor-specific ones
+    /// 
+    /// ```
+    /// use whatadistro::Distro;
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME="Flatcar Container Linux by Kinvolk"
+    ///     ID=flatcar
+    ///     ID_LIKE=coreos
+    ///     FLATCAR_BOARD="amd64-usr"
+    /// "#).unwrap();
+    /// 
+    /// assert_eq!(distro.field("FLATCAR_BOARD"), Some("amd64-usr"));
+    /// ```
+    pub fn field(&self, key: impl AsRef<str>) -> Option<&str> {
+        self.fields.get(key.as_ref()).map(String::as_str)
+    }
+
+    /// List fields which are not described by the os-release specification
+    /// 
+    /// Cloud images often ship such fields, e.g. `FLATCAR_BOARD` on Flatcar
+    /// or `GOOGLE_METRICS_PRODUCT_ID` on Container-Optimized OS
+    pub fn vendor_fields(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields.iter()
+            .filter(|(key, _)| !os_release::STANDARD_FIELDS.contains(&key.as_str()))
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    #[inline]
+    /// Compare current distro with some another
+    /// 
+    /// ```
+    /// let status = whatadistro::identify()
+    ///     .map(|distro| distro.is_similar("arch")) // whatadistro::Distro::Arch can be used as well
+    ///     .unwrap_or(false);
+    /// 
+    /// println!("Is current system arch-based: {:?}", status);
+    /// ```
+    pub fn is_similar<T: Into<DistroId>>(&self, other: T) -> bool {
+        let other = other.into();
+
+        self.similar_ids.contains(&other) || self.id.is_similar(other)
+    }
+}
+
+/// Identify current linux distro using `/etc/os-release` file
+/// 
+/// `/usr/lib/os-release` is used if `/etc/os-release` can't be read
+/// (e.g. it's a dangling symlink). Vendor drop-ins (`/etc/os-release.d/*.conf`)
+/// are merged over the file (see `DetectionSource::drop_ins`). `/etc/lsb-release` file is used to fill
+/// missing fields or as a fallback when os-release files are not available.
+/// Legacy release files like `/etc/arch-release` and package databases
+/// are used as a last resort (see `Distro::confidence`). Distro set by `set_override`
+/// is returned if any. With `static-target`
+/// feature distro embedded at compile time is returned without reading files.
+/// External commands are never spawned (see `IdentifyOptions::no_exec`)
+/// 
+/// ```
+/// let distro = whatadistro::identify()
+///     .expect("Failed to parse os-release file");
+/// 
+/// println!("Your distro name is {}", distro.name());
+/// ```
+pub fn identify() -> Option<Distro> {
+    if let Some(distro) = overrides::get_override() {
+        trace!("using distro override");
+
+        return Some(distro);
+    }
+
+    #[cfg(feature = "static-target")]
+    if let Some(distro) = static_target::static_distro() {
+        trace!("using static target distro");
+
+        return Some(distro);
+    }
+
+    platform::identify_system()
+}
+
+/// Identify linux distro using given filesystem
+/// 
+/// Works like `identify()` but reads files using the given filesystem
+/// implementation and ignores overrides and compile time distros
+/// 
+/// ```
+/// use whatadistro::{MockFs, DistroId, Confidence};
+/// 
+/// let fs = MockFs::default()
+///     .with_file("/etc/arch-release", "");
+/// 
+/// let distro = whatadistro::identify_with_fs(&fs).unwrap();
+/// 
+/// assert_eq!(distro.id(), &DistroId::Arch);
+/// assert_eq!(distro.confidence(), Confidence::Heuristic);
+/// ```
+pub fn identify_with_fs(fs: &impl FileSystem) -> Option<Distro> {
+    let lsb_release = fs.read_to_string(Path::new("/etc/lsb-release")).ok();
+
+    // os-release can be a dangling symlink for a moment during upgrades
+    let distro = identify_file(fs, "/etc/os-release")
+        .or_else(|| {
+            trace!("falling back to /usr/lib/os-release");
+
+            identify_file(fs, "/usr/lib/os-release")
+        });
+
+    match distro {
+        Some(mut distro) => {
+            if let Some(lsb_release) = &lsb_release {
+                distro.merge_lsb_release(lsb_release);
+            }
+
+            Some(distro)
+        }
+
+        None => {
+            trace!("falling back to /etc/lsb-release");
+
+            lsb_release.and_then(|lsb_release| Distro::from_lsb_release(&lsb_release))
+                .map(|distro| distro.with_source_in(fs, "/etc/lsb-release"))
+                .or_else(|| confidence::identify_legacy(fs))
+                .or_else(|| confidence::identify_package_db(fs))
+        }
+    }
+}
+
+/// Identify linux distro using given os-release file
+fn identify_file(fs: &impl FileSystem, path: impl AsRef<Path>) -> Option<Distro> {
+    let path = path.as_ref();
+
+    let Ok(release) = fs.read_to_string(path) else {
+        if fs.read_link(path).is_ok() {
+            trace!("{path:?} is a dangling symlink");
+        }
+
+        else {
+            trace!("failed to read {path:?}");
+        }
+
+        return None;
+    };
+
+    trace!("read {path:?}");
+
+    let (merged, drop_ins) = drop_ins::merge(fs, path, &release);
+
+    Distro::from_os_release(&merged)
+        .map(|mut distro| {
+            // Line numbers of drop-ins would point to the merged content
+            distro.warnings = warnings::lint(&release);
+
+            distro.with_source_in(fs, path)
+                .with_drop_ins(drop_ins)
+        })
 }
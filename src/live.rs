@@ -0,0 +1,112 @@
+use std::path::Path;
+
+use crate::{Distro, FileSystem, RealFs};
+use crate::cmdline::Cmdline;
+
+/// Directories created by live media init scripts
+const LIVE_DIRECTORIES: &[&str] = &[
+    "/run/live/medium",
+    "/run/initramfs/live",
+    "/run/archiso",
+    "/cdrom/casper",
+    "/rofs"
+];
+
+impl Distro {
+    /// Check if the system is running from a live or installer media
+    /// 
+    /// Detection is based on the kernel command line (`boot=live`, `boot=casper`, `rd.live.image`),
+    /// directories created by live media init scripts and squashfs / overlayfs mounted root
+    /// 
+    /// ```
+    /// if let Some(distro) = whatadistro::identify() {
+    ///     if distro.is_live_environment() {
+    ///         println!("Running from live media, persistent config will not be written");
+    ///     }
+    /// }
+    /// ```
+    pub fn is_live_environment(&self) -> bool {
+        self.is_live_environment_with_fs(&RealFs)
+    }
+
+    /// Check if the system is running from a live or installer media
+    /// using files of the given filesystem
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, MockFs};
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Ubuntu\nID=ubuntu").unwrap();
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/proc/cmdline", "BOOT_IMAGE=/casper/vmlinuz boot=casper quiet splash");
+    /// 
+    /// assert!(distro.is_live_environment_with_fs(&fs));
+    /// assert!(!distro.is_live_environment_with_fs(&MockFs::default()));
+    /// ```
+    pub fn is_live_environment_with_fs(&self, fs: &impl FileSystem) -> bool {
+        if let Ok(cmdline) = fs.read_to_string(Path::new("/proc/cmdline")) {
+            let cmdline = Cmdline::parse(&cmdline);
+
+            // Debian live-boot and Ubuntu casper
+            if matches!(cmdline.get("boot"), Some("live" | "casper")) {
+                trace!("live boot parameter found in kernel command line");
+
+                return true;
+            }
+
+            // Fedora (dracut dmsquash-live) and Arch Linux ISO
+            if cmdline.has_flag("rd.live.image") || cmdline.contains("archisobasedir") {
+                trace!("live image parameter found in kernel command line");
+
+                return true;
+            }
+        }
+
+        if let Some(path) = LIVE_DIRECTORIES.iter().find(|path| fs.read_dir(Path::new(path)).is_ok()) {
+            trace!("live media directory found: {path}");
+
+            return true;
+        }
+
+        if let Ok(mounts) = fs.read_to_string(Path::new("/proc/mounts")) {
+            let mut root_fs = None;
+            let mut has_squashfs = false;
+
+            for line in mounts.lines() {
+                let mut entry = line.split_whitespace().skip(1);
+
+                let (Some(mount_point), Some(fs_type)) = (entry.next(), entry.next()) else {
+                    continue;
+                };
+
+                if mount_point == "/" {
+                    root_fs = Some(fs_type);
+                }
+
+                // Snap packages are squashfs images as well
+                if fs_type == "squashfs" && !mount_point.starts_with("/snap/") {
+                    has_squashfs = true;
+                }
+            }
+
+            // Overlay root alone is not enough because containers use it as well
+            match root_fs {
+                Some("squashfs") => {
+                    trace!("root filesystem is squashfs");
+
+                    return true;
+                }
+
+                Some("overlay") if has_squashfs => {
+                    trace!("root filesystem is overlay over squashfs");
+
+                    return true;
+                }
+
+                _ => ()
+            }
+        }
+
+        false
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::process::Command;
+
+use crate::{Distro, DistroId, Confidence};
+use crate::{os_release, exec};
+
+/// lsb-release keys and os-release keys they correspond to
+const KEYS: &[(&str, &str)] = &[
+    ("DISTRIB_ID",          "NAME"),
+    ("DISTRIB_RELEASE",     "VERSION_ID"),
+    ("DISTRIB_CODENAME",    "VERSION_CODENAME"),
+    ("DISTRIB_DESCRIPTION", "PRETTY_NAME")
+];
+
+/// `lsb_release -a` output labels and os-release keys they correspond to
+const COMMAND_LABELS: &[(&str, &str)] = &[
+    ("Distributor ID", "NAME"),
+    ("Release",        "VERSION_ID"),
+    ("Codename",       "VERSION_CODENAME"),
+    ("Description",    "PRETTY_NAME")
+];
+
+/// Parse lsb-release file content into os-release key-value pairs
+/// 
+/// `ID` field is made from the lowercased `DISTRIB_ID` value
+pub(crate) fn parse(content: &str) -> HashMap<String, String> {
+    let mut fields = HashMap::new();
+
+    for (key, value) in os_release::fields(content) {
+        if key == "DISTRIB_ID" {
+            fields.insert(String::from("ID"), value.to_ascii_lowercase());
+        }
+
+        if let Some((_, os_release_key)) = KEYS.iter().find(|(lsb_key, _)| *lsb_key == key) {
+            fields.insert(os_release_key.to_string(), value.into_owned());
+        }
+    }
+
+    fields
+}
+
+impl Distro {
+    /// Parse distro from the lsb-release file content
+    /// 
+    /// Returns `None` if `DISTRIB_ID` field is missing
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, DistroId};
+    /// 
+    /// let distro = Distro::from_lsb_release(r#"
+    ///     DISTRIB_ID=Ubuntu
+    ///     DISTRIB_RELEASE=22.04
+    ///     DISTRIB_CODENAME=jammy
+    ///     DISTRIB_DESCRIPTION="Ubuntu 22.04.3 LTS"
+    /// "#).unwrap();
+    /// 
+    /// assert_eq!(distro.id(), &DistroId::Ubuntu);
+    /// assert_eq!(distro.version_codename(), Some("jammy"));
+    /// ```
+    pub fn from_lsb_release(content: &str) -> Option<Self> {
+        let fields = parse(content);
+
+        Some(Self {
+            id: fields.get("ID")?.into(),
+            similar_ids: Default::default(),
+            id_like: Vec::new(),
+            fields,
+            confidence: Confidence::High,
+            source: None,
+            warnings: crate::warnings::lint(content)
+        })
+    }
+
+    /// Fill fields missing in the current distro info using lsb-release file content
+    /// 
+    /// Fields which are already set are kept as is. Nothing is merged
+    /// if lsb-release file describes another distro
+    /// 
+    /// ```
+    /// use whatadistro::Distro;
+    /// 
+    /// let mut distro = Distro::from_os_release("NAME=Ubuntu\nID=ubuntu\nVERSION_ID=22.04").unwrap();
+    /// 
+    /// distro.merge_lsb_release("DISTRIB_ID=Ubuntu\nDISTRIB_RELEASE=22.04\nDISTRIB_CODENAME=jammy");
+    /// 
+    /// assert_eq!(distro.version_codename(), Some("jammy"));
+    /// ```
+    pub fn merge_lsb_release(&mut self, content: &str) {
+        let fields = parse(content);
+
+        if let Some(id) = fields.get("ID") {
+            if DistroId::from(id) != self.id {
+                trace!("lsb-release describes another distro ({id}), ignoring it");
+
+                return;
+            }
+        }
+
+        for (key, value) in fields {
+            if let Entry::Vacant(entry) = self.fields.entry(key) {
+                trace!("using {} from lsb-release: {value}", entry.key());
+
+                entry.insert(value);
+            }
+        }
+    }
+}
+
+/// Identify distro using `lsb_release -a` command output
+pub(crate) fn identify_command() -> Option<Distro> {
+    let output = exec::stdout(Command::new("lsb_release").arg("-a"), exec::remaining())?;
+
+    let mut fields = HashMap::new();
+
+    for line in output.lines() {
+        let Some((label, value)) = line.split_once(':') else {
+            continue;
+        };
+
+        let value = value.trim();
+
+        // Missing values are reported as "n/a"
+        if value.is_empty() || value == "n/a" {
+            continue;
+        }
+
+        if label.trim() == "Distributor ID" {
+            fields.insert(String::from("ID"), value.to_ascii_lowercase());
+        }
+
+        if let Some((_, key)) = COMMAND_LABELS.iter().find(|(known, _)| *known == label.trim()) {
+            fields.insert(key.to_string(), value.to_string());
+        }
+    }
+
+    trace!("parsed lsb_release command output: {fields:?}");
+
+    Some(Distro {
+        id: fields.get("ID")?.into(),
+        similar_ids: Default::default(),
+        id_like: Vec::new(),
+        fields,
+        confidence: Confidence::High,
+        source: None,
+        warnings: Vec::new()
+    })
+}
@@ -0,0 +1,98 @@
+use crate::{DistroId, DistroFamily};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Static information about a known distro
+pub struct DistroMetadata {
+    id: &'static DistroId
+}
+
+impl DistroMetadata {
+    #[inline]
+    /// Get distro id
+    pub fn id(&self) -> &'static DistroId {
+        self.id
+    }
+
+    #[inline]
+    /// Get canonical `ID` value (e.g. `linuxmint`)
+    pub fn canonical_id(&self) -> &'static str {
+        self.id.known_id().unwrap_or_default()
+    }
+
+    #[inline]
+    /// Get human readable distro name
+    pub fn name(&self) -> &'static str {
+        self.id.name()
+    }
+
+    #[inline]
+    /// Get family of the distro
+    pub fn family(&self) -> DistroFamily {
+        self.id.family()
+    }
+
+    #[inline]
+    /// Get name of the default package manager binary
+    pub fn package_manager(&self) -> Option<&'static str> {
+        self.id.package_manager()
+    }
+
+    #[inline]
+    /// Check if the distro is a rolling release one
+    pub fn is_rolling(&self) -> bool {
+        self.id.is_rolling()
+    }
+}
+
+impl DistroId {
+    /// Get static information about the known distro
+    /// 
+    /// Returns `None` for `DistroId::Other`
+    /// 
+    /// ```
+    /// use whatadistro::{DistroId, DistroFamily};
+    /// 
+    /// let metadata = DistroId::Mint.metadata().unwrap();
+    /// 
+    /// assert_eq!(metadata.canonical_id(), "linuxmint");
+    /// assert_eq!(metadata.name(), "Linux Mint");
+    /// assert_eq!(metadata.family(), DistroFamily::Debian);
+    /// assert_eq!(metadata.package_manager(), Some("apt"));
+    /// assert!(!metadata.is_rolling());
+    /// 
+    /// // Build "select your distro" list
+    /// let names = DistroId::ALL.iter()
+    ///     .filter_map(DistroId::metadata)
+    ///     .map(|metadata| metadata.name())
+    ///     .collect::<Vec<_>>();
+    /// 
+    /// assert!(names.contains(&"Arch Linux"));
+    /// ```
+    pub fn metadata(&self) -> Option<DistroMetadata> {
+        Self::ALL.iter()
+            .find(|id| *id == self)
+            .map(|id| DistroMetadata { id })
+    }
+
+    /// Check if the distro is a rolling release one
+    /// 
+    /// openSUSE is not considered rolling because Leap and Tumbleweed
+    /// share the same id, use `Distro::release_channel` to tell them apart
+    /// 
+    /// ```
+    /// use whatadistro::DistroId;
+    /// 
+    /// assert!(DistroId::Arch.is_rolling());
+    /// assert!(DistroId::Gentoo.is_rolling());
+    /// assert!(!DistroId::Debian.is_rolling());
+    /// ```
+    pub fn is_rolling(&self) -> bool {
+        match self {
+            // SteamOS images are versioned unlike other Arch-based distros
+            Self::SteamOS => false,
+            Self::Gentoo  => true,
+
+            _ => self.family() == DistroFamily::Arch
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt::Display;
+
+use crate::{Distro, DistroId, ReleaseChannel};
+
+/// openSUSE ids of immutable transactional distros
+const OPENSUSE_IMAGE_IDS: &[&str] = &[
+    "opensuse-microos",
+    "opensuse-aeon",
+    "opensuse-kalpa",
+    "opensuse-leap-micro"
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// How the distro ships updates
+pub enum ReleaseModel {
+    /// Versioned releases with point updates (Debian, Ubuntu, RHEL)
+    FixedPointRelease,
+
+    /// Packages are updated continuously (Arch, Gentoo, openSUSE Tumbleweed)
+    Rolling,
+
+    /// Rolling release with delayed, tested package snapshots
+    /// (Manjaro, openSUSE Slowroll)
+    SemiRolling,
+
+    /// Whole system is updated as an immutable image
+    /// (Fedora Silverblue, openSUSE MicroOS, SteamOS, Flatcar)
+    ImageBased
+}
+
+impl Display for ReleaseModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FixedPointRelease => write!(f, "fixed point release"),
+            Self::Rolling           => write!(f, "rolling"),
+            Self::SemiRolling       => write!(f, "semi-rolling"),
+            Self::ImageBased        => write!(f, "image-based")
+        }
+    }
+}
+
+impl Distro {
+    /// Get release model of the distro
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, ReleaseModel};
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME="Fedora Linux"
+    ///     ID=fedora
+    ///     VERSION_ID=40
+    ///     VARIANT="Silverblue"
+    ///     VARIANT_ID=silverblue
+    /// "#).unwrap();
+    /// 
+    /// assert_eq!(distro.release_model(), ReleaseModel::ImageBased);
+    /// 
+    /// let distro = Distro::from_os_release("NAME=\"openSUSE Tumbleweed\"\nID=opensuse-tumbleweed").unwrap();
+    /// 
+    /// assert_eq!(distro.release_model(), ReleaseModel::Rolling);
+    /// 
+    /// let distro = Distro::from_os_release("NAME=\"Manjaro Linux\"\nID=manjaro").unwrap();
+    /// 
+    /// assert_eq!(distro.release_model(), ReleaseModel::SemiRolling);
+    /// ```
+    pub fn release_model(&self) -> ReleaseModel {
+        let raw_id = self.field("ID").unwrap_or_default();
+        let fedora_image = self.fedora_variant()
+            .map(|variant| variant.is_image_based())
+            .unwrap_or(false);
+
+        match self.id() {
+            DistroId::Fedora if fedora_image => ReleaseModel::ImageBased,
+            DistroId::OpenSUSE if OPENSUSE_IMAGE_IDS.contains(&raw_id) => ReleaseModel::ImageBased,
+
+            DistroId::SteamOS |
+            DistroId::ContainerOptimizedOS |
+            DistroId::Bottlerocket |
+            DistroId::Flatcar => ReleaseModel::ImageBased,
+
+            DistroId::Manjaro => ReleaseModel::SemiRolling,
+            DistroId::OpenSUSE if raw_id == "opensuse-slowroll" => ReleaseModel::SemiRolling,
+
+            _ if self.release_channel() == ReleaseChannel::Rolling => ReleaseModel::Rolling,
+
+            _ => ReleaseModel::FixedPointRelease
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use std::path::Path;
+
+use crate::{Distro, SystemInfo, FileSystem, RealFs};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Entry of the mounts table (`/proc/mounts`)
+pub struct MountEntry {
+    device: String,
+    mount_point: String,
+    fs_type: String,
+    options: Vec<String>
+}
+
+impl MountEntry {
+    /// Find mount the path belongs to in `/proc/mounts` content
+    /// 
+    /// The deepest mount point containing the path is used.
+    /// For the same mount point the last entry wins
+    /// because it hides the previous ones
+    /// 
+    /// ```
+    /// use whatadistro::MountEntry;
+    /// 
+    /// let mounts = "/dev/sda1 / ext4 ro,relatime 0 0\noverlay /etc overlay rw,lowerdir=/usr/etc 0 0";
+    /// 
+    /// let etc = MountEntry::from_mounts(mounts, "/etc/pacman.conf").unwrap();
+    /// 
+    /// assert_eq!(etc.mount_point(), "/etc");
+    /// assert!(etc.is_overlay());
+    /// assert!(!etc.is_read_only());
+    /// ```
+    pub fn from_mounts(mounts: &str, path: &str) -> Option<Self> {
+        let contains = |mount_point: &str| {
+            mount_point == "/" || path == mount_point || path.strip_prefix(mount_point)
+                .map(|rest| rest.starts_with('/'))
+                .unwrap_or(false)
+        };
+
+        mounts.lines()
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+
+                Some(Self {
+                    device: fields.next()?.to_string(),
+                    mount_point: fields.next()?.to_string(),
+                    fs_type: fields.next()?.to_string(),
+                    options: fields.next()?.split(',').map(String::from).collect()
+                })
+            })
+            .filter(|entry| contains(&entry.mount_point))
+            .fold(None, |deepest: Option<Self>, entry| match deepest {
+                Some(deepest) if deepest.mount_point.len() > entry.mount_point.len() => Some(deepest),
+
+                _ => Some(entry)
+            })
+    }
+
+    #[inline]
+    /// Get mounted device (`/dev/sda1`, `overlay`, `tmpfs`)
+    pub fn device(&self) -> &str {
+        &self.device
+    }
+
+    #[inline]
+    /// Get mount point path
+    pub fn mount_point(&self) -> &str {
+        &self.mount_point
+    }
+
+    #[inline]
+    /// Get filesystem type (`ext4`, `overlay`, `tmpfs`)
+    pub fn fs_type(&self) -> &str {
+        &self.fs_type
+    }
+
+    #[inline]
+    /// Get mount options (`rw`, `relatime`, `lowerdir=/usr/etc`)
+    pub fn options(&self) -> &[String] {
+        &self.options
+    }
+
+    #[inline]
+    /// Check if the filesystem is mounted read-only
+    pub fn is_read_only(&self) -> bool {
+        self.options.iter().any(|option| option == "ro")
+    }
+
+    #[inline]
+    /// Check if the filesystem is an overlay (Flatcar, kiosk systems)
+    pub fn is_overlay(&self) -> bool {
+        self.fs_type == "overlay"
+    }
+
+    #[inline]
+    /// Check if the filesystem is stored in memory (Bottlerocket)
+    /// 
+    /// Changes made to such filesystems are lost on reboot
+    pub fn is_tmpfs(&self) -> bool {
+        matches!(self.fs_type.as_str(), "tmpfs" | "ramfs")
+    }
+}
+
+impl SystemInfo {
+    /// Get mount the `/etc` directory belongs to
+    /// 
+    /// Value is taken from the `/proc/mounts` file
+    /// 
+    /// ```
+    /// use whatadistro::SystemInfo;
+    /// 
+    /// if let Some(etc) = SystemInfo::etc_mount() {
+    ///     if etc.is_tmpfs() {
+    ///         println!("Changes in /etc will be lost on reboot");
+    ///     }
+    /// }
+    /// ```
+    pub fn etc_mount() -> Option<MountEntry> {
+        Self::etc_mount_with_fs(&RealFs)
+    }
+
+    /// Get mount the `/etc` directory belongs to using files of the given filesystem
+    /// 
+    /// ```
+    /// use whatadistro::{SystemInfo, MockFs};
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/proc/mounts", "/dev/sda1 / ext4 rw 0 0\ntmpfs /etc tmpfs rw 0 0");
+    /// 
+    /// assert!(SystemInfo::etc_mount_with_fs(&fs).unwrap().is_tmpfs());
+    /// ```
+    pub fn etc_mount_with_fs(fs: &impl FileSystem) -> Option<MountEntry> {
+        let mounts = fs.read_to_string(Path::new("/proc/mounts")).ok();
+
+        if mounts.is_none() {
+            trace!("failed to read /proc/mounts");
+        }
+
+        MountEntry::from_mounts(&mounts?, "/etc")
+    }
+}
+
+impl Distro {
+    /// Check if the `/etc` directory can be modified
+    /// 
+    /// Returns `false` if `/etc` is mounted read-only or has no
+    /// write permission bits. User permissions are not checked,
+    /// so writing can still fail for unprivileged processes
+    /// 
+    /// ```
+    /// if let Some(distro) = whatadistro::identify() {
+    ///     if !distro.etc_writable() {
+    ///         println!("Package manager config can't be changed");
+    ///     }
+    /// }
+    /// ```
+    pub fn etc_writable(&self) -> bool {
+        self.etc_writable_with_fs(&RealFs)
+    }
+
+    /// Check if the `/etc` directory can be modified using files of the given filesystem
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, MockFs};
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Fedora\nID=fedora").unwrap();
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/etc/fedora-release", "Fedora release 40 (Forty)");
+    /// 
+    /// assert!(distro.etc_writable_with_fs(&fs));
+    /// 
+    /// let fs = fs.with_file("/proc/mounts", "/dev/vda1 / ext4 ro 0 0");
+    /// 
+    /// assert!(!distro.etc_writable_with_fs(&fs));
+    /// ```
+    pub fn etc_writable_with_fs(&self, fs: &impl FileSystem) -> bool {
+        if let Some(etc) = SystemInfo::etc_mount_with_fs(fs) {
+            if etc.is_read_only() {
+                trace!("/etc is mounted read-only from {}", etc.mount_point());
+
+                return false;
+            }
+        }
+
+        match fs.is_read_only(Path::new("/etc")) {
+            Ok(read_only) => !read_only,
+
+            Err(err) => {
+                trace!("failed to read /etc metadata: {err}");
+
+                false
+            }
+        }
+    }
+}
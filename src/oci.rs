@@ -0,0 +1,495 @@
+use std::io::{self, Read, Write};
+use std::path::{Component, Path, PathBuf};
+
+use crate::{Distro, FileSystem, RealFs, identify_file, inflate};
+
+/// Entries bigger than this are not read
+const MAX_ENTRY_SIZE: u64 = 1024 * 1024;
+
+/// Maximal depth of nested image indexes
+const MAX_INDEX_DEPTH: usize = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// os-release file entry found in the layer
+enum Entry {
+    File(String),
+
+    /// Symlink or hardlink to another file
+    Link,
+
+    /// File is removed by the layer (`.wh.os-release`)
+    Whiteout
+}
+
+#[derive(Debug, Default)]
+/// os-release files found in the image layers
+struct Files {
+    etc: Option<Entry>,
+    usr_lib: Option<Entry>
+}
+
+impl Files {
+    /// Fill missing entries using files from the lower layer
+    fn merge_lower(&mut self, lower: Files) {
+        if self.etc.is_none() {
+            self.etc = lower.etc;
+        }
+
+        if self.usr_lib.is_none() {
+            self.usr_lib = lower.usr_lib;
+        }
+    }
+
+    /// Check if lower layers can't change the result
+    fn is_resolved(&self) -> bool {
+        match &self.etc {
+            Some(Entry::File(_)) => true,
+
+            _ => self.usr_lib.is_some()
+        }
+    }
+
+    fn identify(&self) -> Option<Distro> {
+        let content = match (&self.etc, &self.usr_lib) {
+            (Some(Entry::File(content)), _) |
+            (_, Some(Entry::File(content))) => content,
+
+            _ => return None
+        };
+
+        Distro::from_os_release(content)
+    }
+}
+
+#[derive(Debug)]
+enum State {
+    Header,
+
+    Data {
+        remaining: u64,
+        padding: u64,
+        kind: Capture,
+        buf: Vec<u8>
+    },
+
+    Padding(u64)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// What to do with the entry data
+enum Capture {
+    Skip,
+    Etc,
+    UsrLib,
+    PaxHeader,
+    LongName
+}
+
+/// Streaming tar parser looking for os-release files
+struct TarScanner {
+    header: Vec<u8>,
+    state: State,
+    next_name: Option<String>,
+    files: Files
+}
+
+impl TarScanner {
+    fn new() -> Self {
+        Self {
+            header: Vec::with_capacity(512),
+            state: State::Header,
+            next_name: None,
+            files: Files::default()
+        }
+    }
+
+    fn parse_header(&mut self) {
+        let header = std::mem::take(&mut self.header);
+
+        // Empty blocks mark the end of the archive
+        if header.iter().all(|byte| *byte == 0) {
+            return;
+        }
+
+        let field = |range: std::ops::Range<usize>| {
+            let field = &header[range];
+            let end = field.iter().position(|byte| *byte == 0).unwrap_or(field.len());
+
+            String::from_utf8_lossy(&field[..end]).into_owned()
+        };
+
+        let mut name = field(0..100);
+
+        if &header[257..262] == b"ustar" {
+            let prefix = field(345..500);
+
+            if !prefix.is_empty() {
+                name = format!("{prefix}/{name}");
+            }
+        }
+
+        if let Some(long_name) = self.next_name.take() {
+            name = long_name;
+        }
+
+        let size = parse_size(&header[124..136]);
+        let kind = header[156];
+
+        let name = name.trim_start_matches("./").trim_start_matches('/');
+
+        let capture = match (kind, name) {
+            (b'x', _) => Capture::PaxHeader,
+            (b'L', _) => Capture::LongName,
+
+            (b'0' | b'\0' | b'7', "etc/os-release") => Capture::Etc,
+            (b'0' | b'\0' | b'7', "usr/lib/os-release") => Capture::UsrLib,
+
+            (b'1' | b'2', "etc/os-release") => {
+                self.files.etc.get_or_insert(Entry::Link);
+
+                Capture::Skip
+            }
+
+            (b'1' | b'2', "usr/lib/os-release") => {
+                self.files.usr_lib.get_or_insert(Entry::Link);
+
+                Capture::Skip
+            }
+
+            (_, "etc/.wh.os-release") => {
+                self.files.etc.get_or_insert(Entry::Whiteout);
+
+                Capture::Skip
+            }
+
+            (_, "usr/lib/.wh.os-release") => {
+                self.files.usr_lib.get_or_insert(Entry::Whiteout);
+
+                Capture::Skip
+            }
+
+            _ => Capture::Skip
+        };
+
+        let capture = if size > MAX_ENTRY_SIZE {
+            Capture::Skip
+        }
+
+        else {
+            capture
+        };
+
+        self.state = State::Data {
+            remaining: size,
+            padding: (512 - size % 512) % 512,
+            kind: capture,
+            buf: Vec::new()
+        };
+
+        self.finish_data();
+    }
+
+    /// Store captured entry data if it's fully read
+    fn finish_data(&mut self) {
+        let State::Data { remaining: 0, padding, kind, buf } = &mut self.state else {
+            return;
+        };
+
+        let content = String::from_utf8_lossy(buf).into_owned();
+
+        match kind {
+            Capture::Etc    => { self.files.etc.get_or_insert(Entry::File(content)); }
+            Capture::UsrLib => { self.files.usr_lib.get_or_insert(Entry::File(content)); }
+
+            Capture::PaxHeader => {
+                self.next_name = content.lines()
+                    .filter_map(|record| record.split_once(' '))
+                    .find_map(|(_, record)| record.strip_prefix("path="))
+                    .map(String::from);
+            }
+
+            Capture::LongName => {
+                self.next_name = Some(content.trim_end_matches('\0').to_string());
+            }
+
+            Capture::Skip => ()
+        }
+
+        self.state = State::Padding(*padding);
+    }
+}
+
+impl Write for TarScanner {
+    fn write(&mut self, mut data: &[u8]) -> io::Result<usize> {
+        let written = data.len();
+
+        while !data.is_empty() {
+            match &mut self.state {
+                State::Header => {
+                    let needed = (512 - self.header.len()).min(data.len());
+
+                    self.header.extend_from_slice(&data[..needed]);
+
+                    data = &data[needed..];
+
+                    if self.header.len() == 512 {
+                        self.parse_header();
+                    }
+                }
+
+                State::Data { remaining, kind, buf, .. } => {
+                    let needed = (*remaining).min(data.len() as u64) as usize;
+
+                    if *kind != Capture::Skip {
+                        buf.extend_from_slice(&data[..needed]);
+                    }
+
+                    *remaining -= needed as u64;
+
+                    data = &data[needed..];
+
+                    self.finish_data();
+                }
+
+                State::Padding(remaining) => {
+                    let needed = (*remaining).min(data.len() as u64) as usize;
+
+                    *remaining -= needed as u64;
+
+                    data = &data[needed..];
+
+                    if *remaining == 0 {
+                        self.state = State::Header;
+                    }
+                }
+            }
+        }
+
+        // Zero padding is possible right after the header
+        if let State::Padding(0) = self.state {
+            self.state = State::Header;
+        }
+
+        Ok(written)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Parse octal or base-256 encoded tar entry size
+fn parse_size(field: &[u8]) -> u64 {
+    if field[0] & 0x80 != 0 {
+        return field[1..].iter()
+            .fold(0u64, |size, byte| size.saturating_mul(256).saturating_add(*byte as u64));
+    }
+
+    let field = String::from_utf8_lossy(field);
+    let field = field.trim_matches(|char: char| char == '\0' || char == ' ');
+
+    u64::from_str_radix(field, 8).unwrap_or(0)
+}
+
+/// Find os-release files in the plain or gzip compressed tar stream
+fn scan_tar(reader: impl Read) -> io::Result<Files> {
+    let mut reader = io::BufReader::new(reader);
+
+    let is_gzip = io::BufRead::fill_buf(&mut reader)?
+        .starts_with(&[0x1f, 0x8b]);
+
+    let scanner = if is_gzip {
+        inflate::gunzip(reader, TarScanner::new())?
+    }
+
+    else {
+        let mut scanner = TarScanner::new();
+
+        io::copy(&mut reader, &mut scanner)?;
+
+        scanner
+    };
+
+    Ok(scanner.files)
+}
+
+/// Identify distro from the image layer tarball
+/// 
+/// Both plain and gzip compressed tarballs are supported.
+/// `etc/os-release` is used first, then `usr/lib/os-release`
+/// 
+/// ```no_run
+/// let layer = std::fs::File::open("layer.tar.gz").unwrap();
+/// 
+/// if let Some(distro) = whatadistro::identify_tar(layer).unwrap() {
+///     println!("Image is based on {}", distro.name());
+/// }
+/// ```
+pub fn identify_tar(reader: impl Read) -> io::Result<Option<Distro>> {
+    Ok(scan_tar(reader)?.identify())
+}
+
+/// Unpacked root filesystem of the image
+/// 
+/// Symlinks inside of the root are not followed as they can point
+/// to the host files (e.g. `etc/os-release` -> `/etc/os-release`)
+struct RootFs<'a>(&'a Path);
+
+impl RootFs<'_> {
+    /// Check that the path doesn't leave the root through symlinks
+    fn check(&self, path: &Path) -> io::Result<()> {
+        let outside = || io::Error::new(io::ErrorKind::InvalidInput, format!("{path:?} is outside of the image"));
+
+        let relative = path.strip_prefix(self.0)
+            .map_err(|_| outside())?;
+
+        let mut current = self.0.to_path_buf();
+
+        for component in relative.components() {
+            let Component::Normal(name) = component else {
+                return Err(outside());
+            };
+
+            current.push(name);
+
+            if std::fs::symlink_metadata(&current)?.file_type().is_symlink() {
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("{current:?} is a symlink")));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FileSystem for RootFs<'_> {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        self.check(path)?;
+
+        RealFs.read_to_string(path)
+    }
+
+    #[inline]
+    fn exists(&self, path: &Path) -> bool {
+        self.check(path).is_ok()
+    }
+
+    fn read_link(&self, path: &Path) -> io::Result<PathBuf> {
+        self.check(path)?;
+
+        Err(io::Error::new(io::ErrorKind::InvalidInput, format!("{path:?} is not a symlink")))
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        self.check(path)?;
+
+        RealFs.read_dir(path)
+    }
+}
+
+/// Extract sha256 digests from the JSON array stored under the given key
+fn digests(json: &str, key: &str) -> Vec<String> {
+    let Some(start) = json.find(&format!("\"{key}\"")) else {
+        return Vec::new();
+    };
+
+    let json = &json[start..];
+
+    let Some(array_start) = json.find('[') else {
+        return Vec::new();
+    };
+
+    let mut depth = 0;
+    let mut array_end = json.len();
+
+    for (i, char) in json[array_start..].char_indices() {
+        match char {
+            '[' => depth += 1,
+
+            ']' => {
+                depth -= 1;
+
+                if depth == 0 {
+                    array_end = array_start + i;
+
+                    break;
+                }
+            }
+
+            _ => ()
+        }
+    }
+
+    json[array_start..array_end].split("sha256:")
+        .skip(1)
+        .map(|digest| digest.chars().take_while(char::is_ascii_hexdigit).collect::<String>())
+        .filter(|digest| digest.len() == 64)
+        .collect()
+}
+
+/// Identify distro from the container image filesystem
+/// 
+/// Given path can be an OCI image layout (directory with `index.json` and `blobs`),
+/// an OCI runtime bundle (directory with `rootfs`) or an unpacked root filesystem.
+/// Layers of the first image manifest are inspected from the top one
+/// 
+/// ```no_run
+/// if let Some(distro) = whatadistro::identify_oci_layout("image").unwrap() {
+///     println!("Image is based on {}", distro.name());
+/// }
+/// ```
+pub fn identify_oci_layout(path: impl AsRef<Path>) -> io::Result<Option<Distro>> {
+    let path = path.as_ref();
+
+    if !path.join("index.json").is_file() {
+        // Symlinks are not followed as they can point to the host files
+        let rootfs = path.join("rootfs");
+
+        if std::fs::symlink_metadata(&rootfs).map(|metadata| metadata.is_dir()).unwrap_or(false) {
+            return identify_oci_layout(rootfs);
+        }
+
+        let fs = RootFs(path);
+
+        let distro = identify_file(&fs, path.join("etc/os-release"))
+            .or_else(|| identify_file(&fs, path.join("usr/lib/os-release")));
+
+        return Ok(distro);
+    }
+
+    let blob = |digest: &str| path.join("blobs/sha256").join(digest);
+
+    let mut manifest = std::fs::read_to_string(path.join("index.json"))?;
+    let mut depth = 0;
+
+    // Image indexes can reference other indexes
+    while !manifest.contains("\"layers\"") {
+        let Some(digest) = digests(&manifest, "manifests").into_iter().next() else {
+            trace!("image index doesn't reference any manifest");
+
+            return Ok(None);
+        };
+
+        depth += 1;
+
+        if depth > MAX_INDEX_DEPTH {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "too many nested image indexes"));
+        }
+
+        manifest = std::fs::read_to_string(blob(&digest))?;
+    }
+
+    let mut files = Files::default();
+
+    for digest in digests(&manifest, "layers").iter().rev() {
+        trace!("scanning image layer {digest}");
+
+        files.merge_lower(scan_tar(std::fs::File::open(blob(digest))?)?);
+
+        if files.is_resolved() {
+            break;
+        }
+    }
+
+    Ok(files.identify())
+}
@@ -0,0 +1,321 @@
+use std::collections::HashMap;
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+
+use crate::{Distro, DistroId, Confidence, Ambiguity, identify, identify_all, lsb_release, exec};
+use crate::ambiguity::{self, Callback};
+
+/// Commands spawned by the detectors if exec is allowed
+const EXEC_DETECTORS: &[&str] = &[
+    "lsb_release",
+    "ldd",
+    "python3",
+    "dpkg",
+    "rpm-ostree"
+];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Manual corrections applied to the identified distro
+/// 
+/// Useful for appliances and vendor images shipping broken
+/// os-release files (e.g. `ID=linux`)
+/// 
+/// ```
+/// use whatadistro::{IdentifyOptions, DistroId};
+/// 
+/// let options = IdentifyOptions::default()
+///     .override_id(DistroId::Debian)
+///     .override_version("12")
+///     .extra_id_like("ubuntu");
+/// 
+/// let distro = whatadistro::identify_with(&options).unwrap();
+/// 
+/// assert_eq!(distro.id(), &DistroId::Debian);
+/// assert_eq!(distro.version_id(), Some("12"));
+/// assert!(distro.similar_ids().contains(&DistroId::Ubuntu));
+/// ```
+pub struct IdentifyOptions {
+    override_id: Option<DistroId>,
+    override_version: Option<String>,
+    extra_id_like: Vec<DistroId>,
+    timeout: Option<Duration>,
+    exec: bool,
+    on_ambiguous: Option<Callback>
+}
+
+impl IdentifyOptions {
+    #[inline]
+    /// Replace `ID` of the identified distro
+    /// 
+    /// If the distro can't be identified at all then a distro
+    /// with given id and its default name is returned
+    pub fn override_id(mut self, id: impl Into<DistroId>) -> Self {
+        self.override_id = Some(id.into());
+
+        self
+    }
+
+    #[inline]
+    /// Replace `VERSION_ID` of the identified distro
+    pub fn override_version(mut self, version: impl ToString) -> Self {
+        self.override_version = Some(version.to_string());
+
+        self
+    }
+
+    #[inline]
+    /// Add distro to the `ID_LIKE` list of the identified distro
+    pub fn extra_id_like(mut self, id: impl Into<DistroId>) -> Self {
+        self.extra_id_like.push(id.into());
+
+        self
+    }
+
+    #[inline]
+    /// Limit time spent on the distro identification
+    /// 
+    /// Identification runs in a separate thread and is treated
+    /// as failed if it doesn't finish in time, so slow filesystems
+    /// can't hang the caller (e.g. a UI thread). External commands
+    /// spawned by the detectors share the same budget (see `with_timeout`)
+    /// 
+    /// ```
+    /// use std::time::Duration;
+    /// 
+    /// use whatadistro::{IdentifyOptions, DistroId};
+    /// 
+    /// let options = IdentifyOptions::default()
+    ///     .timeout(Duration::from_millis(500))
+    ///     .override_id(DistroId::Debian);
+    /// 
+    /// // Overridden id is used if identification fails or times out
+    /// assert_eq!(whatadistro::identify_with(&options).unwrap().id(), &DistroId::Debian);
+    /// ```
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    #[inline]
+    /// Never spawn external commands during the identification
+    /// 
+    /// Enabled by default, so the identification works under seccomp
+    /// profiles forbidding exec. When disabled and the `detect-exec` feature
+    /// is enabled, `lsb_release -a` output is used if no release files were found.
+    /// The whole `identify_with` call is run inside of `without_exec`
+    /// 
+    /// ```
+    /// use whatadistro::IdentifyOptions;
+    /// 
+    /// let options = IdentifyOptions::default();
+    /// 
+    /// assert!(options.disabled_detectors().contains(&"lsb_release"));
+    /// 
+    /// let options = options.no_exec(false);
+    /// 
+    /// // Commands are never spawned without the `detect-exec` feature
+    /// if cfg!(feature = "detect-exec") {
+    ///     assert!(options.disabled_detectors().is_empty());
+    /// }
+    /// ```
+    pub fn no_exec(mut self, no_exec: bool) -> Self {
+        self.exec = !no_exec;
+
+        self
+    }
+
+    #[inline]
+    /// Get names of the commands detectors which are disabled
+    /// by the `no_exec` option or disabled `detect-exec` feature
+    /// 
+    /// List depends on the options only. Enabled detectors still
+    /// run only when they're needed: `lsb_release` is used
+    /// only if no release files were found
+    pub fn disabled_detectors(&self) -> &'static [&'static str] {
+        if self.exec && cfg!(feature = "detect-exec") {
+            &[]
+        }
+
+        else {
+            EXEC_DETECTORS
+        }
+    }
+
+    #[inline]
+    /// Call function when the identification result is ambiguous
+    /// 
+    /// Callback is called if os-release and lsb-release files disagree
+    /// or if the distro was guessed heuristically. Returned
+    /// id replaces the identified one, `None` keeps it as is. Callback is
+    /// not called if the id is overridden
+    /// 
+    /// ```
+    /// use whatadistro::IdentifyOptions;
+    /// 
+    /// let options = IdentifyOptions::default()
+    ///     .on_ambiguous(|ambiguity| {
+    ///         println!("Detected {}-like system, is this one of {:?}?", ambiguity.distro().id(), ambiguity.candidates());
+    /// 
+    ///         // Keep the best guess
+    ///         None
+    ///     });
+    /// 
+    /// let distro = whatadistro::identify_with(&options);
+    /// ```
+    pub fn on_ambiguous(mut self, callback: impl Fn(&Ambiguity) -> Option<DistroId> + Send + Sync + 'static) -> Self {
+        self.on_ambiguous = Some(Callback(Arc::new(callback)));
+
+        self
+    }
+
+    /// Ask the ambiguity callback to pick the distro id
+    fn disambiguate(&self, distro: &mut Distro) {
+        let Some(Callback(callback)) = &self.on_ambiguous else {
+            return;
+        };
+
+        if self.override_id.is_some() {
+            return;
+        }
+
+        let Some(ambiguity) = ambiguity::detect(distro, &identify_all()) else {
+            return;
+        };
+
+        if let Some(id) = callback(&ambiguity) {
+            trace!("ambiguity callback picked distro id: {id}");
+
+            replace_id(distro, id);
+        }
+    }
+
+    /// Apply corrections to the distro
+    fn apply(&self, mut distro: Distro) -> Distro {
+        if let Some(id) = &self.override_id {
+            trace!("overriding distro id: {} -> {id}", distro.id);
+
+            replace_id(&mut distro, id.clone());
+        }
+
+        if let Some(version) = &self.override_version {
+            trace!("overriding distro version: {version}");
+
+            distro.fields.insert(String::from("VERSION_ID"), version.clone());
+        }
+
+        if !self.extra_id_like.is_empty() {
+            distro.similar_ids.extend(self.extra_id_like.iter().cloned());
+
+            // Extra ids go after the ones from the file, without duplicates
+            for id in &self.extra_id_like {
+                if !distro.id_like.contains(id) {
+                    distro.id_like.push(id.clone());
+                }
+            }
+
+            let ids = distro.id_like.iter()
+                .map(DistroId::to_string)
+                .collect::<Vec<_>>();
+
+            distro.fields.insert(String::from("ID_LIKE"), ids.join(" "));
+        }
+
+        distro
+    }
+}
+
+/// Replace `ID` field of the distro
+fn replace_id(distro: &mut Distro, id: DistroId) {
+    distro.fields.insert(String::from("ID"), id.to_string());
+    distro.id = id;
+}
+
+/// Identify current linux distro and apply manual corrections
+/// 
+/// Works like `identify()` but replaces fields given in the options
+/// and runs detectors allowed by them
+pub fn identify_with(options: &IdentifyOptions) -> Option<Distro> {
+    let identify = || match options.timeout {
+        Some(timeout) => exec::with_timeout(timeout, || identify_in_budget(options)),
+        None => identify_in_budget(options)
+    };
+
+    if options.exec {
+        identify()
+    }
+
+    else {
+        exec::without_exec(identify)
+    }
+}
+
+/// Identify distro using time budget of the current `with_timeout` call
+fn identify_in_budget(options: &IdentifyOptions) -> Option<Distro> {
+    let distro = match options.timeout {
+        Some(timeout) => identify_timeout(timeout),
+        None => identify()
+    };
+
+    let distro = distro.or_else(|| {
+        if !options.exec {
+            trace!("release files are missing, skipping lsb_release command because exec is disabled");
+
+            return None;
+        }
+
+        trace!("release files are missing, running lsb_release command");
+
+        lsb_release::identify_command()
+    });
+
+    let mut distro = distro.or_else(|| {
+        let id = options.override_id.as_ref()?;
+
+        trace!("distro can't be identified, using overridden id");
+
+        let mut fields = HashMap::new();
+
+        fields.insert(String::from("ID"), id.to_string());
+        fields.insert(String::from("NAME"), id.name().to_string());
+
+        Some(Distro {
+            id: id.clone(),
+            similar_ids: Default::default(),
+            id_like: Vec::new(),
+            fields,
+            confidence: Confidence::Heuristic,
+            source: None,
+            warnings: Vec::new()
+        })
+    })?;
+
+    options.disambiguate(&mut distro);
+
+    Some(options.apply(distro))
+}
+
+/// Run `identify()` in a separate thread and wait for it at most `timeout`
+fn identify_timeout(timeout: Duration) -> Option<Distro> {
+    let (sender, receiver) = mpsc::channel();
+
+    let thread = std::thread::Builder::new().spawn(move || {
+        let _ = sender.send(identify());
+    });
+
+    if thread.is_err() {
+        trace!("failed to spawn identification thread, identifying in place");
+
+        return identify();
+    }
+
+    match receiver.recv_timeout(timeout) {
+        Ok(distro) => distro,
+
+        Err(_) => {
+            trace!("identification didn't finish in {timeout:?}");
+
+            None
+        }
+    }
+}
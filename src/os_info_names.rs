@@ -0,0 +1,64 @@
+//! Mapping between `DistroId` and `os_info::Type` variants
+//! 
+//! `os_info` is not a dependency so its types are matched
+//! by variant names (`format!("{:?}", info.os_type())`)
+
+use crate::DistroId;
+
+/// Known distros and `os_info::Type` variants they correspond to
+const TYPES: &[(DistroId, &str)] = &[
+    (DistroId::Arch,        "Arch"),
+    (DistroId::Debian,      "Debian"),
+    (DistroId::Ubuntu,      "Ubuntu"),
+    (DistroId::Mint,        "Mint"),
+    (DistroId::RHEL,        "RedHatEnterprise"),
+    (DistroId::Fedora,      "Fedora"),
+    (DistroId::OpenSUSE,    "openSUSE"),
+    (DistroId::Gentoo,      "Gentoo"),
+    (DistroId::NixOS,       "NixOS"),
+    (DistroId::AmazonLinux, "Amazon"),
+    (DistroId::AzureLinux,  "Mariner"),
+    (DistroId::Manjaro,     "Manjaro"),
+    (DistroId::EndeavourOS, "EndeavourOS"),
+    (DistroId::Garuda,      "Garuda"),
+    (DistroId::Artix,       "Artix"),
+    (DistroId::Raspbian,    "Raspbian"),
+    (DistroId::Alpine,      "Alpine"),
+    (DistroId::Kali,        "Kali")
+];
+
+impl DistroId {
+    /// Get name of the `os_info::Type` variant of the distro
+    /// 
+    /// Distros unknown to `os_info` are mapped to `Linux`
+    /// 
+    /// ```
+    /// use whatadistro::DistroId;
+    /// 
+    /// assert_eq!(DistroId::RHEL.os_info_type(), "RedHatEnterprise");
+    /// assert_eq!(DistroId::Bottlerocket.os_info_type(), "Linux");
+    /// ```
+    pub fn os_info_type(&self) -> &'static str {
+        TYPES.iter()
+            .find(|(id, _)| id == self)
+            .map(|(_, name)| *name)
+            .unwrap_or("Linux")
+    }
+
+    /// Get distro from the `os_info::Type` variant name
+    /// 
+    /// Returns `None` for variants without a corresponding
+    /// distro (e.g. `Linux`, `Windows` or `Unknown`)
+    /// 
+    /// ```
+    /// use whatadistro::DistroId;
+    /// 
+    /// assert_eq!(DistroId::from_os_info_type("Mariner"), Some(DistroId::AzureLinux));
+    /// assert_eq!(DistroId::from_os_info_type("Windows"), None);
+    /// ```
+    pub fn from_os_info_type(name: &str) -> Option<Self> {
+        TYPES.iter()
+            .find(|(_, type_name)| *type_name == name)
+            .map(|(id, _)| id.clone())
+    }
+}
@@ -0,0 +1,148 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+/// Maximal amount of bytes read from os-release files
+/// 
+/// Real files are a few kilobytes at most, the limit protects
+/// from reading huge or infinite files (e.g. `/dev/zero` symlinks)
+const MAX_FILE_SIZE: u64 = 1024 * 1024;
+
+/// Lines longer than this are skipped
+const MAX_LINE_LENGTH: usize = 64 * 1024;
+
+/// Fields described by the os-release specification
+/// 
+/// Everything else is a vendor-specific field, e.g. `FLATCAR_BOARD`
+/// on Flatcar or `GOOGLE_METRICS_PRODUCT_ID` on Container-Optimized OS
+pub(crate) const STANDARD_FIELDS: &[&str] = &[
+    "NAME",
+    "ID",
+    "ID_LIKE",
+    "PRETTY_NAME",
+    "CPE_NAME",
+    "VARIANT",
+    "VARIANT_ID",
+    "VERSION",
+    "VERSION_ID",
+    "VERSION_CODENAME",
+    "BUILD_ID",
+    "IMAGE_ID",
+    "IMAGE_VERSION",
+    "RELEASE_TYPE",
+    "HOME_URL",
+    "DOCUMENTATION_URL",
+    "SUPPORT_URL",
+    "BUG_REPORT_URL",
+    "PRIVACY_POLICY_URL",
+    "SUPPORT_END",
+    "LOGO",
+    "ANSI_COLOR",
+    "VENDOR_NAME",
+    "VENDOR_URL",
+    "DEFAULT_HOSTNAME",
+    "ARCHITECTURE",
+    "SYSEXT_LEVEL",
+    "CONFEXT_LEVEL",
+    "SYSEXT_SCOPE",
+    "CONFEXT_SCOPE",
+    "PORTABLE_PREFIXES"
+];
+
+/// Iterate over os-release fields without allocating
+/// 
+/// Values can be unquoted, single quoted or double quoted with
+/// shell-like escape sequences (`\"`, `\\`, `\$`, `` \` ``).
+/// Values are borrowed from the content unless they contain
+/// escape sequences. Empty lines and comments are skipped
+pub(crate) fn fields(content: &str) -> impl Iterator<Item = (&str, Cow<'_, str>)> {
+    content.lines().filter_map(|line| {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') || line.len() > MAX_LINE_LENGTH {
+            return None;
+        }
+
+        let (key, value) = line.split_once('=')?;
+        let key = key.trim();
+
+        if !is_valid_key(key) {
+            return None;
+        }
+
+        // Values are cut at embedded NUL like C strings are
+        let value = value.split('\0').next().unwrap_or_default();
+
+        Some((key, unquote(value.trim())))
+    })
+}
+
+/// Check if the key contains only ASCII letters, digits and underscores
+fn is_valid_key(key: &str) -> bool {
+    !key.is_empty() && key.bytes().all(|byte| byte.is_ascii_alphanumeric() || byte == b'_')
+}
+
+/// Read file as os-release content
+/// 
+/// Invalid UTF-8 sequences are replaced instead of failing the whole file,
+/// and only first `MAX_FILE_SIZE` bytes are read
+pub(crate) fn read_lossy(path: impl AsRef<Path>) -> std::io::Result<String> {
+    let mut content = Vec::new();
+
+    File::open(path)?
+        .take(MAX_FILE_SIZE)
+        .read_to_end(&mut content)?;
+
+    Ok(String::from_utf8_lossy(&content).into_owned())
+}
+
+/// Parse os-release file content into key-value pairs
+pub(crate) fn parse(content: &str) -> HashMap<String, String> {
+    fields(content)
+        .map(|(key, value)| (key.to_string(), value.into_owned()))
+        .collect()
+}
+
+/// Remove quotes and escape sequences from the value
+fn unquote(value: &str) -> Cow<'_, str> {
+    if let Some(value) = value.strip_prefix('\'') {
+        return Cow::Borrowed(value.strip_suffix('\'').unwrap_or(value));
+    }
+
+    let Some(value) = value.strip_prefix('"') else {
+        return Cow::Borrowed(value);
+    };
+
+    let value = value.strip_suffix('"').unwrap_or(value);
+
+    if !value.contains('\\') {
+        return Cow::Borrowed(value);
+    }
+
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(char) = chars.next() {
+        if char != '\\' {
+            result.push(char);
+
+            continue;
+        }
+
+        match chars.next() {
+            Some(escaped @ ('"' | '\\' | '$' | '`')) => result.push(escaped),
+
+            // Unknown escape sequences are kept as is
+            Some(other) => {
+                result.push('\\');
+                result.push(other);
+            }
+
+            None => result.push('\\')
+        }
+    }
+
+    Cow::Owned(result)
+}
@@ -0,0 +1,226 @@
+use std::path::Path;
+use std::process::Command;
+
+use crate::exec;
+use crate::json::{self, Value};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Deployment of an image-based system managed by rpm-ostree
+pub struct OstreeDeployment {
+    id: String,
+    osname: Option<String>,
+    checksum: Option<String>,
+    version: Option<String>,
+    origin: Option<String>,
+    booted: bool,
+    pinned: bool,
+    staged: bool,
+    layered_packages: Vec<String>,
+    removed_packages: Vec<String>
+}
+
+impl OstreeDeployment {
+    fn from_json(deployment: &Value) -> Option<Self> {
+        let string = |key: &str| deployment.get(key)
+            .and_then(Value::as_str)
+            .map(String::from);
+
+        let flag = |key: &str| deployment.get(key)
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        let list = |key: &str| deployment.get(key)
+            .and_then(Value::as_array)
+            .map(|values| {
+                values.iter()
+                    .filter_map(Value::as_str)
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(Self {
+            id: string("id")?,
+            osname: string("osname"),
+            checksum: string("checksum"),
+            version: string("version"),
+            origin: string("container-image-reference").or_else(|| string("origin")),
+            booted: flag("booted"),
+            pinned: flag("pinned"),
+            staged: flag("staged"),
+            layered_packages: list("requested-packages"),
+            removed_packages: list("requested-base-removals")
+        })
+    }
+
+    #[inline]
+    /// Get deployment id (`fedora-<checksum>.0`)
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    #[inline]
+    /// Get name of the deployed system (`fedora`)
+    pub fn osname(&self) -> Option<&str> {
+        self.osname.as_deref()
+    }
+
+    #[inline]
+    /// Get ostree commit checksum
+    pub fn checksum(&self) -> Option<&str> {
+        self.checksum.as_deref()
+    }
+
+    #[inline]
+    /// Get image version (`40.20240901.0`)
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    #[inline]
+    /// Get ostree refspec or container image reference the deployment
+    /// is updated from (`fedora:fedora/40/x86_64/silverblue`)
+    pub fn origin(&self) -> Option<&str> {
+        self.origin.as_deref()
+    }
+
+    #[inline]
+    /// Check if the system is currently running this deployment
+    pub fn is_booted(&self) -> bool {
+        self.booted
+    }
+
+    #[inline]
+    /// Check if the deployment is pinned and will not be garbage collected
+    pub fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
+    #[inline]
+    /// Check if the deployment will be used after the next reboot
+    pub fn is_staged(&self) -> bool {
+        self.staged
+    }
+
+    #[inline]
+    /// Get packages layered on top of the base image (`rpm-ostree install`)
+    pub fn layered_packages(&self) -> &[String] {
+        &self.layered_packages
+    }
+
+    #[inline]
+    /// Get base image packages removed by `rpm-ostree override remove`
+    pub fn removed_packages(&self) -> &[String] {
+        &self.removed_packages
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Deployments of an rpm-ostree system (Fedora Silverblue, Kinoite, CoreOS)
+/// 
+/// On such systems packages should be installed with `rpm-ostree install`
+/// (or toolbox containers) instead of dnf
+pub struct OstreeInfo {
+    deployments: Vec<OstreeDeployment>
+}
+
+impl OstreeInfo {
+    /// Parse output of the `rpm-ostree status --json` command
+    /// 
+    /// ```
+    /// use whatadistro::OstreeInfo;
+    /// 
+    /// let info = OstreeInfo::from_json(r#"{
+    ///     "deployments": [
+    ///         {
+    ///             "id": "fedora-94b1a3.0",
+    ///             "osname": "fedora",
+    ///             "version": "40.20240901.0",
+    ///             "origin": "fedora:fedora/40/x86_64/silverblue",
+    ///             "booted": true,
+    ///             "pinned": true,
+    ///             "requested-packages": ["htop", "distrobox"]
+    ///         },
+    ///         {
+    ///             "id": "fedora-3c8e0f.0",
+    ///             "osname": "fedora",
+    ///             "version": "40.20240825.0",
+    ///             "booted": false
+    ///         }
+    ///     ],
+    ///     "transaction": null
+    /// }"#).unwrap();
+    /// 
+    /// let booted = info.booted().unwrap();
+    /// 
+    /// assert_eq!(booted.version(), Some("40.20240901.0"));
+    /// assert!(booted.is_pinned());
+    /// assert_eq!(booted.layered_packages(), ["htop", "distrobox"]);
+    /// assert_eq!(info.deployments().len(), 2);
+    /// ```
+    pub fn from_json(json: &str) -> Option<Self> {
+        let status = json::parse(json)?;
+
+        let deployments = status.get("deployments")?
+            .as_array()?
+            .iter()
+            .filter_map(OstreeDeployment::from_json)
+            .collect();
+
+        Some(Self {
+            deployments
+        })
+    }
+
+    /// Get deployments info of the current system
+    /// 
+    /// Returns `None` if the system is not booted from ostree
+    /// or `rpm-ostree` is not available
+    /// 
+    /// ```
+    /// use whatadistro::OstreeInfo;
+    /// 
+    /// if let Some(info) = OstreeInfo::detect() {
+    ///     if let Some(booted) = info.booted() {
+    ///         println!("Layered packages: {}", booted.layered_packages().join(" "));
+    ///     }
+    /// }
+    /// ```
+    pub fn detect() -> Option<Self> {
+        if !Self::is_booted() {
+            trace!("system is not booted from ostree");
+
+            return None;
+        }
+
+        let mut command = Command::new("rpm-ostree");
+
+        command.args(["status", "--json"]);
+
+        Self::from_json(&exec::stdout(&mut command, exec::remaining())?)
+    }
+
+    #[inline]
+    /// Check if the system is booted from an ostree deployment
+    pub fn is_booted() -> bool {
+        Path::new("/run/ostree-booted").exists()
+    }
+
+    #[inline]
+    /// Get all the deployments, default one first
+    pub fn deployments(&self) -> &[OstreeDeployment] {
+        &self.deployments
+    }
+
+    #[inline]
+    /// Get currently running deployment
+    pub fn booted(&self) -> Option<&OstreeDeployment> {
+        self.deployments.iter().find(|deployment| deployment.booted)
+    }
+
+    #[inline]
+    /// Get deployment staged for the next boot
+    pub fn staged(&self) -> Option<&OstreeDeployment> {
+        self.deployments.iter().find(|deployment| deployment.staged)
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::RwLock;
+
+use crate::Distro;
+
+static OVERRIDE: RwLock<Option<Distro>> = RwLock::new(None);
+
+/// Force `identify()` to return given distro instead of reading system files
+/// 
+/// Useful in application test suites and on systems with broken
+/// os-release files. Override is global for the whole process
+/// 
+/// ```
+/// use whatadistro::{Distro, DistroId};
+/// 
+/// let distro = Distro::from_os_release("NAME=Fedora\nID=fedora").unwrap();
+/// 
+/// whatadistro::set_override(distro);
+/// 
+/// assert_eq!(whatadistro::identify().unwrap().id(), &DistroId::Fedora);
+/// 
+/// whatadistro::clear_override();
+/// ```
+pub fn set_override(distro: Distro) {
+    trace!("overriding identified distro with {}", distro.id());
+
+    match OVERRIDE.write() {
+        Ok(mut current) => *current = Some(distro),
+
+        // Lock is poisoned only if some thread panicked while holding it,
+        // stored value is still a valid distro so it's safe to replace it
+        Err(err) => *err.into_inner() = Some(distro)
+    }
+}
+
+/// Remove distro override set by `set_override`
+pub fn clear_override() {
+    trace!("clearing distro override");
+
+    match OVERRIDE.write() {
+        Ok(mut current) => *current = None,
+        Err(err) => *err.into_inner() = None
+    }
+}
+
+/// Get distro override if it's set
+pub(crate) fn get_override() -> Option<Distro> {
+    match OVERRIDE.read() {
+        Ok(current) => current.clone(),
+        Err(err) => err.into_inner().clone()
+    }
+}
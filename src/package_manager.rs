@@ -0,0 +1,33 @@
+use crate::{DistroId, DistroFamily};
+
+impl DistroId {
+    /// Get name of the distro's package manager binary
+    /// 
+    /// Returns `None` for distros without a package manager
+    /// (e.g. Bottlerocket or Flatcar) and unknown distros
+    /// 
+    /// ```
+    /// use whatadistro::DistroId;
+    /// 
+    /// assert_eq!(DistroId::Alpine.package_manager(), Some("apk"));
+    /// assert_eq!(DistroId::PostmarketOS.package_manager(), Some("apk"));
+    /// assert_eq!(DistroId::Mint.package_manager(), Some("apt"));
+    /// assert_eq!(DistroId::Flatcar.package_manager(), None);
+    /// ```
+    pub fn package_manager(&self) -> Option<&'static str> {
+        match self {
+            Self::OpenSUSE   => Some("zypper"),
+            Self::AzureLinux => Some("tdnf"),
+
+            _ => match self.family() {
+                DistroFamily::Arch   => Some("pacman"),
+                DistroFamily::Debian => Some("apt"),
+                DistroFamily::RedHat => Some("dnf"),
+                DistroFamily::Gentoo => Some("emerge"),
+                DistroFamily::NixOS  => Some("nix-env"),
+                DistroFamily::Alpine => Some("apk"),
+                DistroFamily::Other  => None
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::fmt::Display;
+
+use crate::{Distro, identify};
+
+#[cfg(target_os = "linux")]
+use crate::{RealFs, identify_with_fs};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Reason why the distro couldn't be identified
+pub enum IdentifyError {
+    /// Program is compiled for a platform other than linux
+    UnsupportedPlatform,
+
+    /// No release files were found or they couldn't be parsed
+    NotFound
+}
+
+impl Display for IdentifyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedPlatform => write!(f, "distro identification is not supported on {}", std::env::consts::OS),
+            Self::NotFound            => write!(f, "distro release files are missing or malformed")
+        }
+    }
+}
+
+impl std::error::Error for IdentifyError {}
+
+#[cfg(target_os = "linux")]
+/// Error returned when the distro can't be identified on the target platform
+const NOT_IDENTIFIED: IdentifyError = IdentifyError::NotFound;
+
+#[cfg(not(target_os = "linux"))]
+/// Error returned when the distro can't be identified on the target platform
+const NOT_IDENTIFIED: IdentifyError = IdentifyError::UnsupportedPlatform;
+
+#[cfg(target_os = "linux")]
+#[inline]
+/// Identify distro using files of the current system
+pub(crate) fn identify_system() -> Option<Distro> {
+    identify_with_fs(&RealFs)
+}
+
+#[cfg(not(target_os = "linux"))]
+/// Distro files are not read on platforms other than linux
+pub(crate) fn identify_system() -> Option<Distro> {
+    trace!("{} is not linux, identification skipped", std::env::consts::OS);
+
+    None
+}
+
+/// Identify current linux distro, telling unsupported platforms apart
+/// 
+/// Works like `identify()`. `IdentifyError::UnsupportedPlatform` is
+/// returned on every platform other than linux unless the distro is
+/// set by `set_override` or embedded at compile time
+/// 
+/// ```
+/// use whatadistro::IdentifyError;
+/// 
+/// match whatadistro::try_identify() {
+///     Ok(distro) => println!("Your distro name is {}", distro.name()),
+///     Err(IdentifyError::UnsupportedPlatform) => println!("Not a linux system"),
+///     Err(IdentifyError::NotFound) => println!("Failed to identify distro")
+/// }
+/// ```
+pub fn try_identify() -> Result<Distro, IdentifyError> {
+    identify().ok_or(NOT_IDENTIFIED)
+}
+
+// Linux is the common case, boxing the distro would only add an allocation
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Operating system the program is running on
+/// 
+/// Recommended entry point for cross-platform programs
+/// 
+/// ```
+/// use whatadistro::OperatingSystem;
+/// 
+/// match OperatingSystem::current() {
+///     Ok(OperatingSystem::Linux(distro)) => println!("Linux: {}", distro.name()),
+///     Ok(os) => println!("Not linux: {os}"),
+///     Err(err) => println!("Failed to identify linux distro: {err}")
+/// }
+/// ```
+pub enum OperatingSystem {
+    /// Linux with identified distro
+    Linux(Distro),
+
+    Windows,
+    MacOS,
+    FreeBSD,
+
+    /// Other operating system (`std::env::consts::OS` value)
+    Other(&'static str)
+}
+
+impl OperatingSystem {
+    /// Get operating system the program is running on
+    /// 
+    /// Returns `IdentifyError::NotFound` if the program is running on
+    /// linux but the distro can't be identified
+    pub fn current() -> Result<Self, IdentifyError> {
+        match std::env::consts::OS {
+            "linux"   => try_identify().map(Self::Linux),
+            "windows" => Ok(Self::Windows),
+            "macos"   => Ok(Self::MacOS),
+            "freebsd" => Ok(Self::FreeBSD),
+
+            os => Ok(Self::Other(os))
+        }
+    }
+
+    #[inline]
+    /// Get identified linux distro
+    pub fn distro(&self) -> Option<&Distro> {
+        match self {
+            Self::Linux(distro) => Some(distro),
+
+            _ => None
+        }
+    }
+
+    #[inline]
+    /// Check if the operating system is linux
+    pub fn is_linux(&self) -> bool {
+        matches!(self, Self::Linux(_))
+    }
+}
+
+impl Display for OperatingSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Linux(distro) => write!(f, "{}", distro.name()),
+            Self::Windows       => write!(f, "Windows"),
+            Self::MacOS         => write!(f, "macOS"),
+            Self::FreeBSD       => write!(f, "FreeBSD"),
+
+            Self::Other(os) => write!(f, "{os}")
+        }
+    }
+}
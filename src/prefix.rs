@@ -0,0 +1,227 @@
+use std::path::Path;
+use std::process::Command;
+
+use crate::{Distro, DistroFamily, FileSystem, RealFs, identify_host, detect_sandbox, repos, exec};
+
+/// 32-bit dynamic loader locations used by different distros
+const LOADERS_32BIT: &[&str] = &[
+    "/usr/lib32/ld-linux.so.2",
+    "/usr/lib/i386-linux-gnu/ld-linux.so.2",
+    "/lib/i386-linux-gnu/ld-linux.so.2",
+    "/usr/lib/ld-linux.so.2",
+    "/lib/ld-linux.so.2"
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Host system info relevant for Wine and Proton prefixes
+pub struct PrefixHost {
+    distro: Distro,
+    has_32bit_libs: bool,
+    multilib_enabled: Option<bool>,
+    i386_enabled: Option<bool>
+}
+
+impl PrefixHost {
+    #[inline]
+    /// Get host distro
+    pub fn distro(&self) -> &Distro {
+        &self.distro
+    }
+
+    #[inline]
+    /// Check if 32-bit dynamic loader (`ld-linux.so.2`) is installed
+    pub fn has_32bit_libs(&self) -> bool {
+        self.has_32bit_libs
+    }
+
+    #[inline]
+    /// Check if `multilib` repository is enabled in `/etc/pacman.conf`
+    /// 
+    /// Returns `None` on distros outside of the Arch family
+    pub fn multilib_enabled(&self) -> Option<bool> {
+        self.multilib_enabled
+    }
+
+    #[inline]
+    /// Check if `i386` foreign architecture is enabled in dpkg
+    /// 
+    /// Returns `None` on distros outside of the Debian family
+    /// or if `dpkg` can't be executed
+    pub fn i386_enabled(&self) -> Option<bool> {
+        self.i386_enabled
+    }
+
+    /// Check if 32-bit Windows applications can be run
+    /// 
+    /// 32-bit libraries must be installed and repositories providing
+    /// them must be enabled if the distro needs them
+    pub fn supports_32bit(&self) -> bool {
+        self.has_32bit_libs &&
+            self.multilib_enabled != Some(false) &&
+            self.i386_enabled != Some(false)
+    }
+}
+
+/// Check if `multilib` repository is enabled in the pacman config
+pub(crate) fn multilib_enabled(fs: &impl FileSystem) -> bool {
+    fs.read_to_string(Path::new("/etc/pacman.conf"))
+        .map(|content| {
+            repos::parse_pacman_conf(&content)
+                .iter()
+                .any(|repo| repo.name() == "multilib" && repo.is_enabled())
+        })
+        .unwrap_or(false)
+}
+
+/// Check if `i386` is listed in dpkg foreign architectures
+fn i386_enabled(fs: &impl FileSystem) -> Option<bool> {
+    let enabled = dpkg_foreign_architectures(fs)?
+        .iter()
+        .any(|arch| arch == "i386");
+
+    Some(enabled)
+}
+
+/// List architectures added with `dpkg --add-architecture`
+/// 
+/// dpkg database is read first, `dpkg` command is used if it's missing
+fn dpkg_foreign_architectures(fs: &impl FileSystem) -> Option<Vec<String>> {
+    // dpkg writes the native architecture first, then the foreign ones
+    if let Ok(content) = fs.read_to_string(Path::new("/var/lib/dpkg/arch")) {
+        let architectures = content.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .skip(1)
+            .map(String::from)
+            .collect();
+
+        return Some(architectures);
+    }
+
+    trace!("/var/lib/dpkg/arch is missing, running dpkg command");
+
+    let mut command = Command::new("dpkg");
+
+    command.arg("--print-foreign-architectures");
+
+    let Some(stdout) = exec::stdout(&mut command, exec::remaining()) else {
+        trace!("failed to list dpkg foreign architectures");
+
+        return None;
+    };
+
+    let architectures = stdout
+        .split_whitespace()
+        .map(String::from)
+        .collect();
+
+    Some(architectures)
+}
+
+impl Distro {
+    /// List enabled extra architectures packages can be installed for
+    /// 
+    /// Debian family distros report dpkg foreign architectures (`i386`, `arm64`).
+    /// Arch family distros report `i686` if the `multilib` repository is enabled.
+    /// Other distros install 32-bit packages without extra configuration,
+    /// so empty list is returned for them
+    /// 
+    /// ```
+    /// if let Some(distro) = whatadistro::identify() {
+    ///     let architectures = distro.foreign_architectures();
+    /// 
+    ///     if !architectures.iter().any(|arch| arch == "i386" || arch == "i686") {
+    ///         println!("32-bit packages are not enabled");
+    ///     }
+    /// }
+    /// ```
+    pub fn foreign_architectures(&self) -> Vec<String> {
+        self.foreign_architectures_with_fs(&RealFs)
+    }
+
+    /// List enabled extra architectures using files of the given filesystem
+    /// 
+    /// Debian family distros run `dpkg` of the current system
+    /// if `/var/lib/dpkg/arch` is missing
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, MockFs};
+    /// 
+    /// let distro = Distro::from_os_release("NAME=\"Arch Linux\"\nID=arch").unwrap();
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/etc/pacman.conf", "[core]\nInclude = /etc/pacman.d/mirrorlist\n\n[multilib]\nInclude = /etc/pacman.d/mirrorlist\n");
+    /// 
+    /// assert_eq!(distro.foreign_architectures_with_fs(&fs), ["i686"]);
+    /// assert!(distro.foreign_architectures_with_fs(&MockFs::default()).is_empty());
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Debian\nID=debian").unwrap();
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/var/lib/dpkg/arch", "amd64\ni386\n");
+    /// 
+    /// assert_eq!(distro.foreign_architectures_with_fs(&fs), ["i386"]);
+    /// ```
+    pub fn foreign_architectures_with_fs(&self, fs: &impl FileSystem) -> Vec<String> {
+        match self.family() {
+            DistroFamily::Debian => dpkg_foreign_architectures(fs).unwrap_or_default(),
+
+            DistroFamily::Arch if multilib_enabled(fs) => vec![String::from("i686")],
+
+            _ => Vec::new()
+        }
+    }
+}
+
+/// Identify host distro for running Wine or Proton prefixes
+/// 
+/// Host distro is identified using `identify_host()`. Library and
+/// repository flags are not available inside of Flatpak and Snap
+/// sandboxes because they would describe the sandbox runtime,
+/// so `None` is returned there
+/// 
+/// ```
+/// if let Some(host) = whatadistro::identify_for_prefix() {
+///     if !host.supports_32bit() {
+///         println!("32-bit libraries are missing on {}", host.distro().name());
+///     }
+/// 
+///     if host.multilib_enabled() == Some(false) {
+///         println!("Enable multilib repository in /etc/pacman.conf");
+///     }
+/// }
+/// ```
+pub fn identify_for_prefix() -> Option<PrefixHost> {
+    if detect_sandbox().hides_host() {
+        trace!("host libraries can't be inspected from inside of the sandbox");
+
+        return None;
+    }
+
+    let distro = identify_host()?;
+
+    let family = distro.family();
+
+    let multilib_enabled = if family == DistroFamily::Arch {
+        Some(multilib_enabled(&RealFs))
+    }
+
+    else {
+        None
+    };
+
+    let i386_enabled = if family == DistroFamily::Debian {
+        i386_enabled(&RealFs)
+    }
+
+    else {
+        None
+    };
+
+    Some(PrefixHost {
+        distro,
+        has_32bit_libs: LOADERS_32BIT.iter().any(|path| RealFs.exists(Path::new(path))),
+        multilib_enabled,
+        i386_enabled
+    })
+}
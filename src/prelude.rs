@@ -0,0 +1,24 @@
+//! Commonly used types and functions
+//! 
+//! ```
+//! use whatadistro::prelude::*;
+//! 
+//! if let Some(distro) = identify() {
+//!     if distro.id().family() == DistroFamily::Debian {
+//!         println!("{} is Debian-based", distro.name());
+//!     }
+//! }
+//! ```
+
+pub use crate::{
+    Distro,
+    DistroId,
+    DistroFamily,
+    ReleaseChannel,
+    ReleaseModel,
+    identify,
+    distro_match
+};
+
+#[cfg(feature = "containers")]
+pub use crate::identify_host;
@@ -0,0 +1,229 @@
+use std::ffi::OsString;
+use std::fmt::Display;
+use std::process::Command;
+
+use crate::Distro;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Tool used to run commands with root privileges
+pub enum PrivilegeTool {
+    Sudo,
+    Doas,
+    Run0,
+    Pkexec,
+
+    /// No tool is needed (process is already running as root)
+    /// or no tool is installed
+    None
+}
+
+impl PrivilegeTool {
+    /// Tools in order of preference
+    const KNOWN: &'static [Self] = &[
+        Self::Sudo,
+        Self::Doas,
+        Self::Run0,
+        Self::Pkexec
+    ];
+
+    /// Get binary name of the tool
+    pub fn binary(&self) -> Option<&'static str> {
+        match self {
+            Self::Sudo   => Some("sudo"),
+            Self::Doas   => Some("doas"),
+            Self::Run0   => Some("run0"),
+            Self::Pkexec => Some("pkexec"),
+            Self::None   => None
+        }
+    }
+
+    /// Wrap command so it's run using this tool
+    /// 
+    /// These tools reset the environment, so variables set for the command
+    /// are passed using `run0 --setenv` or `env` command for the other tools.
+    /// Command is returned as is for `PrivilegeTool::None`
+    /// 
+    /// ```
+    /// use std::process::Command;
+    /// 
+    /// use whatadistro::PrivilegeTool;
+    /// 
+    /// let mut command = Command::new("apt-get");
+    /// 
+    /// command.args(["install", "-y", "git"])
+    ///     .env("DEBIAN_FRONTEND", "noninteractive");
+    /// 
+    /// let command = PrivilegeTool::Sudo.wrap(command);
+    /// 
+    /// assert_eq!(command.get_program(), "sudo");
+    /// assert!(command.get_args().eq(["env", "DEBIAN_FRONTEND=noninteractive", "apt-get", "install", "-y", "git"]));
+    /// 
+    /// let mut command = Command::new("true");
+    /// 
+    /// command.env("LANG", "C");
+    /// 
+    /// let command = PrivilegeTool::Run0.wrap(command);
+    /// 
+    /// assert!(command.get_args().eq(["--setenv=LANG=C", "true"]));
+    /// ```
+    pub fn wrap(&self, command: Command) -> Command {
+        let Some(binary) = self.binary() else {
+            return command;
+        };
+
+        let mut wrapped = Command::new(binary);
+
+        let mut envs = Vec::new();
+        let mut removed = Vec::new();
+
+        for (key, value) in command.get_envs() {
+            match value {
+                Some(value) => {
+                    let mut env = key.to_os_string();
+
+                    env.push("=");
+                    env.push(value);
+
+                    envs.push(env);
+                }
+
+                None => removed.push(key.to_os_string())
+            }
+        }
+
+        if self == &Self::Run0 {
+            // run0 doesn't inherit the environment, so removed variables are already unset
+            for env in envs {
+                let mut arg = OsString::from("--setenv=");
+
+                arg.push(env);
+
+                wrapped.arg(arg);
+            }
+        }
+
+        else if !envs.is_empty() || !removed.is_empty() {
+            wrapped.arg("env");
+
+            for key in removed {
+                wrapped.arg("-u").arg(key);
+            }
+
+            wrapped.args(envs);
+        }
+
+        wrapped.arg(command.get_program())
+            .args(command.get_args());
+
+        if let Some(dir) = command.get_current_dir() {
+            wrapped.current_dir(dir);
+        }
+
+        wrapped
+    }
+}
+
+impl Display for PrivilegeTool {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.binary().unwrap_or("none"))
+    }
+}
+
+/// Check if the current process is running as root
+fn is_root() -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+
+        // /proc/self is owned by the effective user id of the process
+        std::fs::metadata("/proc/self")
+            .map(|metadata| metadata.uid() == 0)
+            .unwrap_or(false)
+    }
+
+    #[cfg(not(unix))]
+    {
+        false
+    }
+}
+
+/// Check if the binary is available in `PATH`
+pub(crate) fn in_path(binary: &str) -> bool {
+    let Some(path) = std::env::var_os("PATH") else {
+        return false;
+    };
+
+    std::env::split_paths(&path).any(|dir| dir.join(binary).is_file())
+}
+
+#[cfg(feature = "packages")]
+/// Check if binary exists in one of the `PATH` directories of the given filesystem
+pub(crate) fn in_path_with_fs(fs: &impl crate::FileSystem, binary: &str) -> bool {
+    let Some(path) = std::env::var_os("PATH") else {
+        return false;
+    };
+
+    std::env::split_paths(&path).any(|dir| fs.exists(&dir.join(binary)))
+}
+
+/// Detect tool which should be used to run commands with root privileges
+/// 
+/// `PrivilegeTool::None` is returned if the process is already running
+/// as root. Otherwise sudo, doas, run0 and pkexec are searched in `PATH`
+/// 
+/// ```
+/// use whatadistro::PrivilegeTool;
+/// 
+/// match whatadistro::detect_privilege_tool() {
+///     PrivilegeTool::None => println!("No privilege escalation needed or available"),
+///     tool => println!("Using {tool}")
+/// }
+/// ```
+pub fn detect_privilege_tool() -> PrivilegeTool {
+    if is_root() {
+        trace!("running as root, no privilege tool is needed");
+
+        return PrivilegeTool::None;
+    }
+
+    let tool = PrivilegeTool::KNOWN.iter()
+        .find(|tool| tool.binary().map(in_path).unwrap_or(false))
+        .copied()
+        .unwrap_or(PrivilegeTool::None);
+
+    trace!("detected privilege tool: {tool}");
+
+    tool
+}
+
+impl Distro {
+    /// Wrap command so it's run with root privileges
+    /// 
+    /// Tool returned by `detect_privilege_tool` is used. Command's arguments,
+    /// environment variables and working directory are kept (see `PrivilegeTool::wrap`).
+    /// Command is returned as is if no tool is needed or available
+    /// 
+    /// ```no_run
+    /// use std::process::Command;
+    /// 
+    /// let distro = whatadistro::identify().unwrap();
+    /// 
+    /// let mut command = Command::new("pacman");
+    /// 
+    /// command.args(["-S", "--noconfirm", "git"]);
+    /// 
+    /// distro.escalate(command).status().unwrap();
+    /// ```
+    pub fn escalate(&self, command: Command) -> Command {
+        let tool = detect_privilege_tool();
+
+        if tool == PrivilegeTool::None {
+            return command;
+        }
+
+        trace!("escalating {:?} using {tool} on {}", command.get_program(), self.id());
+
+        tool.wrap(command)
+    }
+}
@@ -0,0 +1,440 @@
+//! Configured package repositories inspection
+//! 
+//! apt (`sources.list` and deb822 `.sources`), dnf / yum, zypper
+//! and pacman configuration files are supported
+//! 
+//! ```
+//! use whatadistro::repos;
+//! 
+//! let repos = repos::parse_apt_sources("deb http://archive.ubuntu.com/ubuntu jammy main universe");
+//! 
+//! assert_eq!(repos[0].name(), "jammy");
+//! assert!(repos[0].has_component("universe"));
+//! ```
+
+use std::path::Path;
+
+use crate::{FileSystem, RealFs};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Configured package repository
+pub struct Repository {
+    name: String,
+    url: Option<String>,
+    enabled: bool,
+    components: Vec<String>
+}
+
+impl Repository {
+    #[inline]
+    /// Get repository name
+    /// 
+    /// This is the suite for apt repositories (e.g. `jammy-updates`),
+    /// section name for pacman repositories (e.g. `multilib`) and
+    /// repository id for dnf and zypper repositories (e.g. `epel`)
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    /// Get repository url
+    /// 
+    /// Can be missing for dnf repositories using only mirror lists
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    #[inline]
+    /// Check if the repository is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    #[inline]
+    /// Get apt repository components (e.g. `main`, `universe`)
+    pub fn components(&self) -> &[String] {
+        &self.components
+    }
+
+    #[inline]
+    /// Check if apt repository has given component
+    pub fn has_component(&self, component: &str) -> bool {
+        self.components.iter().any(|known| known == component)
+    }
+}
+
+/// Split line into key and value by the `=` or `:` character
+fn key_value(line: &str, separator: char) -> Option<(&str, &str)> {
+    let (key, value) = line.split_once(separator)?;
+
+    Some((key.trim(), value.trim()))
+}
+
+/// Parse apt one-line style sources list (`/etc/apt/sources.list`)
+/// 
+/// Commented out entries are returned as disabled repositories
+/// 
+/// ```
+/// use whatadistro::repos;
+/// 
+/// let repos = repos::parse_apt_sources(r#"
+///     deb [arch=amd64] http://deb.debian.org/debian bookworm main contrib
+///     #deb http://deb.debian.org/debian bookworm-backports main
+///     deb-src http://deb.debian.org/debian bookworm main
+/// "#);
+/// 
+/// assert_eq!(repos.len(), 3);
+/// assert_eq!(repos[0].url(), Some("http://deb.debian.org/debian"));
+/// assert!(!repos[1].is_enabled());
+/// ```
+pub fn parse_apt_sources(content: &str) -> Vec<Repository> {
+    let mut repos = Vec::new();
+
+    for line in content.lines() {
+        let mut line = line.trim();
+        let mut enabled = true;
+
+        if let Some(commented) = line.strip_prefix('#') {
+            line = commented.trim_start();
+            enabled = false;
+        }
+
+        let Some(line) = line.strip_prefix("deb-src ").or_else(|| line.strip_prefix("deb ")) else {
+            continue;
+        };
+
+        let mut line = line.trim_start();
+
+        // Skip options block
+        if line.starts_with('[') {
+            let Some((_, rest)) = line.split_once(']') else {
+                continue;
+            };
+
+            line = rest;
+        }
+
+        let mut parts = line.split_whitespace();
+
+        let (Some(url), Some(suite)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+
+        repos.push(Repository {
+            name: suite.to_string(),
+            url: Some(url.to_string()),
+            enabled,
+            components: parts.map(String::from).collect()
+        });
+    }
+
+    repos
+}
+
+/// Parse apt deb822 style sources file (`/etc/apt/sources.list.d/*.sources`)
+/// 
+/// ```
+/// use whatadistro::repos;
+/// 
+/// let repos = repos::parse_apt_deb822(r#"
+/// Types: deb
+/// URIs: http://archive.ubuntu.com/ubuntu
+/// Suites: noble noble-updates
+/// Components: main restricted universe
+/// 
+/// Types: deb
+/// URIs: http://security.ubuntu.com/ubuntu
+/// Suites: noble-security
+/// Components: main
+/// Enabled: no
+/// "#);
+/// 
+/// assert_eq!(repos.len(), 3);
+/// assert_eq!(repos[1].name(), "noble-updates");
+/// assert!(repos[1].has_component("universe"));
+/// assert!(!repos[2].is_enabled());
+/// ```
+pub fn parse_apt_deb822(content: &str) -> Vec<Repository> {
+    let mut repos = Vec::new();
+
+    // Stanzas are separated by empty lines
+    let mut stanzas = vec![Vec::new()];
+
+    for line in content.lines() {
+        let line = line.trim();
+
+        if line.is_empty() {
+            stanzas.push(Vec::new());
+        }
+
+        else if !line.starts_with('#') {
+            if let (Some(stanza), Some(entry)) = (stanzas.last_mut(), key_value(line, ':')) {
+                stanza.push(entry);
+            }
+        }
+    }
+
+    for stanza in stanzas {
+        let get = |key: &str| stanza.iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, value)| *value);
+
+        let enabled = !matches!(get("Enabled"), Some("no" | "false"));
+
+        let components = get("Components")
+            .map(|components| components.split_whitespace().map(String::from).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        for url in get("URIs").unwrap_or_default().split_whitespace() {
+            for suite in get("Suites").unwrap_or_default().split_whitespace() {
+                repos.push(Repository {
+                    name: suite.to_string(),
+                    url: Some(url.to_string()),
+                    enabled,
+                    components: components.clone()
+                });
+            }
+        }
+    }
+
+    repos
+}
+
+/// Parse dnf / yum or zypper `.repo` file
+/// 
+/// Both formats are ini files with a section per repository
+/// 
+/// ```
+/// use whatadistro::repos;
+/// 
+/// let repos = repos::parse_repo_file(r#"
+/// [epel]
+/// name=Extra Packages for Enterprise Linux 9 - $basearch
+/// metalink=https://mirrors.fedoraproject.org/metalink?repo=epel-9&arch=$basearch
+/// enabled=1
+/// 
+/// [epel-testing]
+/// name=Extra Packages for Enterprise Linux 9 - Testing - $basearch
+/// baseurl=https://download.example/pub/epel/testing/9/Everything/$basearch/
+/// enabled=0
+/// "#);
+/// 
+/// assert_eq!(repos[0].name(), "epel");
+/// assert_eq!(repos[0].url(), None);
+/// assert!(repos[0].is_enabled());
+/// 
+/// assert_eq!(repos[1].url(), Some("https://download.example/pub/epel/testing/9/Everything/$basearch/"));
+/// assert!(!repos[1].is_enabled());
+/// ```
+pub fn parse_repo_file(content: &str) -> Vec<Repository> {
+    let mut repos: Vec<Repository> = Vec::new();
+
+    for line in content.lines() {
+        let line = line.trim();
+
+        if line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+            repos.push(Repository {
+                name: name.trim().to_string(),
+                url: None,
+                enabled: true,
+                components: Vec::new()
+            });
+
+            continue;
+        }
+
+        let (Some(repo), Some((key, value))) = (repos.last_mut(), key_value(line, '=')) else {
+            continue;
+        };
+
+        match key {
+            // baseurl can contain multiple space separated urls
+            "baseurl" if repo.url.is_none() => repo.url = value.split_whitespace().next().map(String::from),
+
+            "enabled" => repo.enabled = matches!(value, "1" | "yes" | "true" | "True"),
+
+            _ => ()
+        }
+    }
+
+    repos
+}
+
+/// Parse pacman config file (`/etc/pacman.conf`)
+/// 
+/// Commented out repository sections are returned as disabled repositories.
+/// Repository url is the first `Server` value. `Include` directives
+/// are not resolved by this function
+/// 
+/// ```
+/// use whatadistro::repos;
+/// 
+/// let repos = repos::parse_pacman_conf(r#"
+/// [options]
+/// Architecture = auto
+/// 
+/// [core]
+/// Include = /etc/pacman.d/mirrorlist
+/// 
+/// #[multilib]
+/// #Include = /etc/pacman.d/mirrorlist
+/// 
+/// [custom]
+/// Server = https://example.com/$repo/$arch
+/// "#);
+/// 
+/// assert_eq!(repos.len(), 3);
+/// assert_eq!(repos[0].name(), "core");
+/// assert!(!repos[1].is_enabled());
+/// assert_eq!(repos[2].url(), Some("https://example.com/$repo/$arch"));
+/// ```
+pub fn parse_pacman_conf(content: &str) -> Vec<Repository> {
+    parse_pacman_sections(content).into_iter()
+        .map(|(repo, _)| repo)
+        .collect()
+}
+
+/// Parse pacman config file into repositories and their `Include` paths
+fn parse_pacman_sections(content: &str) -> Vec<(Repository, Option<String>)> {
+    let mut repos: Vec<(Repository, Option<String>)> = Vec::new();
+
+    // Whether the current section is a repository section
+    let mut in_repo = false;
+
+    for line in content.lines() {
+        let mut line = line.trim();
+        let mut commented = false;
+
+        if let Some(uncommented) = line.strip_prefix('#') {
+            // Disabled config lines have no space after `#` (`#[multilib]`), others
+            // are notes like `#   [repo-name]` in the header of the stock config
+            if uncommented.starts_with(char::is_whitespace) {
+                continue;
+            }
+
+            line = uncommented;
+            commented = true;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+            in_repo = name != "options";
+
+            if in_repo {
+                repos.push((Repository {
+                    name: name.to_string(),
+                    url: None,
+                    enabled: !commented,
+                    components: Vec::new()
+                }, None));
+            }
+
+            continue;
+        }
+
+        let Some((repo, include)) = repos.last_mut().filter(|_| in_repo) else {
+            continue;
+        };
+
+        // Comments in enabled sections are not directives
+        if commented && repo.enabled {
+            continue;
+        }
+
+        match key_value(line, '=') {
+            Some(("Server", value)) if repo.url.is_none() => repo.url = Some(value.to_string()),
+            Some(("Include", value)) if include.is_none() => *include = Some(value.to_string()),
+
+            _ => ()
+        }
+    }
+
+    repos
+}
+
+/// Read files from the directory with given extension
+pub(crate) fn read_dir_files(fs: &impl FileSystem, dir: impl AsRef<Path>, extension: &str) -> Vec<String> {
+    let Ok(mut paths) = fs.read_dir(dir.as_ref()) else {
+        return Vec::new();
+    };
+
+    paths.retain(|path| path.extension().map(|ext| ext == extension).unwrap_or(false));
+    paths.sort();
+
+    paths.into_iter()
+        .filter_map(|path| fs.read_to_string(&path).ok())
+        .collect()
+}
+
+/// List package repositories configured in the current system
+/// 
+/// ```
+/// let multilib = whatadistro::repos::current()
+///     .iter()
+///     .any(|repo| repo.name() == "multilib" && repo.is_enabled());
+/// 
+/// println!("multilib enabled: {multilib}");
+/// ```
+pub fn current() -> Vec<Repository> {
+    current_with_fs(&RealFs)
+}
+
+/// List package repositories configured in the given filesystem
+/// 
+/// ```
+/// use whatadistro::{repos, MockFs};
+/// 
+/// let fs = MockFs::default()
+///     .with_file("/etc/pacman.conf", "[core]\nInclude = /etc/pacman.d/mirrorlist")
+///     .with_file("/etc/pacman.d/mirrorlist", "Server = https://geo.mirror.pkgbuild.com/$repo/os/$arch");
+/// 
+/// let repos = repos::current_with_fs(&fs);
+/// 
+/// assert_eq!(repos[0].name(), "core");
+/// assert_eq!(repos[0].url(), Some("https://geo.mirror.pkgbuild.com/$repo/os/$arch"));
+/// ```
+pub fn current_with_fs(fs: &impl FileSystem) -> Vec<Repository> {
+    let mut repos = Vec::new();
+
+    if let Ok(content) = fs.read_to_string(Path::new("/etc/apt/sources.list")) {
+        repos.extend(parse_apt_sources(&content));
+    }
+
+    for content in read_dir_files(fs, "/etc/apt/sources.list.d", "list") {
+        repos.extend(parse_apt_sources(&content));
+    }
+
+    for content in read_dir_files(fs, "/etc/apt/sources.list.d", "sources") {
+        repos.extend(parse_apt_deb822(&content));
+    }
+
+    for dir in ["/etc/yum.repos.d", "/etc/zypp/repos.d"] {
+        for content in read_dir_files(fs, dir, "repo") {
+            repos.extend(parse_repo_file(&content));
+        }
+    }
+
+    if let Ok(content) = fs.read_to_string(Path::new("/etc/pacman.conf")) {
+        for (mut repo, include) in parse_pacman_sections(&content) {
+            // Take the first server from the included mirror list
+            if let (None, Some(include)) = (&repo.url, include) {
+                repo.url = fs.read_to_string(Path::new(&include)).ok()
+                    .and_then(|mirrorlist| {
+                        mirrorlist.lines()
+                            .filter_map(|line| key_value(line.trim(), '='))
+                            .find(|(key, _)| *key == "Server")
+                            .map(|(_, server)| server.to_string())
+                    });
+            }
+
+            repos.push(repo);
+        }
+    }
+
+    trace!("found {} configured repositories", repos.len());
+
+    repos
+}
@@ -0,0 +1,357 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::{Distro, DistroId, DistroFamily, FileSystem, RealFs};
+use crate::exec;
+use crate::version::{parse_version, matches_constraint};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Runtime commonly needed by prebuilt applications (AppImages, Electron apps)
+pub enum Runtime {
+    /// Python 3 interpreter
+    Python3,
+
+    /// GNU C library
+    Glibc,
+
+    /// GNU C++ standard library. Version is the highest
+    /// supported `GLIBCXX` symbols version (`3.4.32`)
+    Libstdcxx,
+
+    /// FUSE 2 library needed to mount AppImages
+    Fuse2
+}
+
+impl Runtime {
+    /// Detect version of the runtime installed in the system
+    /// 
+    /// Returns `None` if the runtime is missing or its version can't be
+    /// detected. glibc version is taken from the current process if it's
+    /// linked with glibc, python version needs `detect-exec` feature
+    /// 
+    /// ```
+    /// use whatadistro::Runtime;
+    /// 
+    /// if let Some(version) = Runtime::Glibc.detect_version() {
+    ///     println!("glibc {version}");
+    /// }
+    /// ```
+    pub fn detect_version(&self) -> Option<String> {
+        self.probe_current().flatten()
+    }
+
+    /// Detect version of the runtime using files of the given filesystem
+    /// 
+    /// Libraries are searched in the given filesystem, but `python3`
+    /// and `ldd` commands of the current system are still used
+    /// if the version can't be read from the files
+    /// 
+    /// ```
+    /// use whatadistro::{Runtime, MockFs};
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/usr/lib/libfuse.so.2.9.9", "")
+    ///     .with_link("/usr/lib/libfuse.so.2", "libfuse.so.2.9.9")
+    ///     .with_file("/lib/x86_64-linux-gnu/libc-2.31.so", "")
+    ///     .with_link("/lib/x86_64-linux-gnu/libc.so.6", "libc-2.31.so");
+    /// 
+    /// assert_eq!(Runtime::Fuse2.detect_version_with_fs(&fs).as_deref(), Some("2.9.9"));
+    /// assert_eq!(Runtime::Fuse2.detect_version_with_fs(&MockFs::default()), None);
+    /// 
+    /// if cfg!(target_arch = "x86_64") {
+    ///     assert_eq!(Runtime::Glibc.detect_version_with_fs(&fs).as_deref(), Some("2.31"));
+    /// }
+    /// ```
+    pub fn detect_version_with_fs(&self, fs: &impl FileSystem) -> Option<String> {
+        self.probe(fs).flatten()
+    }
+
+    /// Detect runtime version of the current system
+    /// 
+    /// See `probe` for the returned value
+    fn probe_current(&self) -> Option<Option<String>> {
+        if self == &Self::Glibc {
+            if let Some(version) = process_glibc_version() {
+                return Some(Some(version));
+            }
+        }
+
+        self.probe(&RealFs)
+    }
+
+    /// Detect runtime version
+    /// 
+    /// Returns `Some(None)` if the runtime is missing and `None`
+    /// if it's unknown because commands can't be spawned
+    fn probe(&self, fs: &impl FileSystem) -> Option<Option<String>> {
+        let version = match self {
+            Self::Python3 => {
+                if !exec::is_allowed() {
+                    return None;
+                }
+
+                command_stdout("python3", "--version")
+                    .and_then(|stdout| Some(stdout.strip_prefix("Python ")?.trim().to_string()))
+            }
+
+            Self::Glibc => match library_version(fs, "libc.so.6") {
+                // glibc < 2.34 links the soname to libc-2.31.so
+                Some(Some(version)) => Some(version),
+
+                _ if !exec::is_allowed() => return None,
+
+                // ldd (GNU libc) 2.39
+                _ => command_stdout("ldd", "--version").and_then(|stdout| {
+                    let line = stdout.lines().next()?;
+
+                    if !line.to_ascii_lowercase().contains("libc") {
+                        return None;
+                    }
+
+                    Some(line.split_whitespace().last()?.to_string())
+                })
+            }
+
+            // libstdc++.so.6.0.N provides GLIBCXX_3.4.N symbols
+            Self::Libstdcxx => library_version(fs, "libstdc++.so.6").and_then(|version| match version {
+                Some(version) => Some(format!("3.4.{}", version.strip_prefix("6.0.")?)),
+                None => Some(String::from("3.4"))
+            }),
+
+            Self::Fuse2 => library_version(fs, "libfuse.so.2")
+                .map(|version| version.unwrap_or_else(|| String::from("2")))
+        };
+
+        let version = version.filter(|version| {
+            if parse_version(version).is_none() {
+                trace!("{self:?} version is malformed: {version}");
+
+                return false;
+            }
+
+            true
+        });
+
+        Some(version)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Result of the runtime requirement check
+pub struct RuntimeRequirement {
+    runtime: Runtime,
+    version: Option<String>,
+    is_satisfied: Option<bool>,
+    package: Option<String>
+}
+
+impl RuntimeRequirement {
+    #[inline]
+    /// Get checked runtime
+    pub fn runtime(&self) -> Runtime {
+        self.runtime
+    }
+
+    #[inline]
+    /// Get installed runtime version
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    #[inline]
+    /// Check if runtime is installed and its version satisfies constraints
+    /// 
+    /// Returns `None` if it's unknown whether the runtime is installed
+    /// (e.g. python version can't be checked without `detect-exec` feature)
+    pub fn is_satisfied(&self) -> Option<bool> {
+        self.is_satisfied
+    }
+
+    #[inline]
+    /// Get name of the package providing the runtime
+    pub fn package(&self) -> Option<&str> {
+        self.package.as_deref()
+    }
+}
+
+impl Distro {
+    /// Get name of the package providing the runtime
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, Runtime};
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Ubuntu\nID=ubuntu\nVERSION_ID=22.04").unwrap();
+    /// 
+    /// assert_eq!(distro.runtime_package(Runtime::Fuse2).as_deref(), Some("libfuse2"));
+    /// assert_eq!(distro.runtime_package(Runtime::Libstdcxx).as_deref(), Some("libstdc++6"));
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Ubuntu\nID=ubuntu\nVERSION_ID=24.04").unwrap();
+    /// 
+    /// assert_eq!(distro.runtime_package(Runtime::Fuse2).as_deref(), Some("libfuse2t64"));
+    /// 
+    /// let distro = Distro::from_os_release("NAME=\"Arch Linux\"\nID=arch").unwrap();
+    /// 
+    /// assert_eq!(distro.runtime_package(Runtime::Python3).as_deref(), Some("python"));
+    /// assert_eq!(distro.runtime_package(Runtime::Fuse2).as_deref(), Some("fuse2"));
+    /// ```
+    pub fn runtime_package(&self, runtime: Runtime) -> Option<String> {
+        let package = match (runtime, self.family()) {
+            (_, DistroFamily::NixOS | DistroFamily::Other) => return None,
+
+            (Runtime::Python3, DistroFamily::Arch)   => "python",
+            (Runtime::Python3, DistroFamily::Gentoo) => "dev-lang/python",
+            (Runtime::Python3, _)                    => "python3",
+
+            (Runtime::Glibc, DistroFamily::Debian) => "libc6",
+            (Runtime::Glibc, DistroFamily::Gentoo) => "sys-libs/glibc",
+            (Runtime::Glibc, DistroFamily::Alpine) => "gcompat",
+            (Runtime::Glibc, _)                    => "glibc",
+
+            (Runtime::Libstdcxx, DistroFamily::Debian) => "libstdc++6",
+            (Runtime::Libstdcxx, DistroFamily::Arch)   => "gcc-libs",
+            (Runtime::Libstdcxx, DistroFamily::Gentoo) => "sys-devel/gcc",
+
+            (Runtime::Libstdcxx, _) if self.id() == &DistroId::OpenSUSE => "libstdc++6",
+            (Runtime::Libstdcxx, _) => "libstdc++",
+
+            (Runtime::Fuse2, DistroFamily::Debian) if self.is_t64() => "libfuse2t64",
+            (Runtime::Fuse2, DistroFamily::Debian) => "libfuse2",
+            (Runtime::Fuse2, DistroFamily::Arch)   => "fuse2",
+            (Runtime::Fuse2, DistroFamily::Gentoo) => "sys-fs/fuse:0",
+            (Runtime::Fuse2, DistroFamily::Alpine) => "fuse",
+
+            (Runtime::Fuse2, _) if self.id() == &DistroId::OpenSUSE => "libfuse2",
+            (Runtime::Fuse2, _) => "fuse-libs"
+        };
+
+        Some(String::from(package))
+    }
+
+    /// Check if runtimes are installed and satisfy version constraints
+    /// 
+    /// Constraints use the same format as `is_similar_versioned`
+    /// (`>=2.31`), empty constraints accept any installed version
+    /// 
+    /// ```
+    /// use whatadistro::Runtime;
+    /// 
+    /// if let Some(distro) = whatadistro::identify() {
+    ///     let requirements = distro.runtime_requirements(&[
+    ///         (Runtime::Glibc, ">=2.31"),
+    ///         (Runtime::Fuse2, "")
+    ///     ]);
+    /// 
+    ///     for requirement in requirements {
+    ///         if let (Some(false), Some(package)) = (requirement.is_satisfied(), requirement.package()) {
+    ///             println!("Install {package} to run this app");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn runtime_requirements(&self, requirements: &[(Runtime, &str)]) -> Vec<RuntimeRequirement> {
+        requirements.iter()
+            .map(|(runtime, constraints)| {
+                let probe = runtime.probe_current();
+
+                let is_satisfied = probe.as_ref().map(|version| {
+                    version.as_deref()
+                        .and_then(parse_version)
+                        .and_then(|version| matches_constraint(&version, constraints))
+                        .unwrap_or(false)
+                });
+
+                let version = probe.flatten();
+
+                RuntimeRequirement {
+                    runtime: *runtime,
+                    version,
+                    is_satisfied,
+                    package: self.runtime_package(*runtime)
+                }
+            })
+            .collect()
+    }
+
+    /// Check if distro renamed libraries during the 64-bit `time_t` transition
+    fn is_t64(&self) -> bool {
+        let since = match self.id() {
+            DistroId::Ubuntu => ">=24.04",
+            DistroId::Mint   => ">=22",
+            DistroId::Debian => ">=13",
+
+            _ => return false
+        };
+
+        self.version_number()
+            .and_then(parse_version)
+            .and_then(|version| matches_constraint(&version, since))
+            .unwrap_or(false)
+    }
+}
+
+#[inline]
+/// Run command with a single argument and get its stdout
+fn command_stdout(binary: &str, arg: &str) -> Option<String> {
+    exec::stdout(Command::new(binary).arg(arg), exec::remaining())
+}
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+/// Get version of glibc the current process is linked with
+fn process_glibc_version() -> Option<String> {
+    extern "C" {
+        fn gnu_get_libc_version() -> *const std::os::raw::c_char;
+    }
+
+    // SAFETY: function doesn't take any arguments and returns a static string
+    let version = unsafe { gnu_get_libc_version() };
+
+    if version.is_null() {
+        return None;
+    }
+
+    // SAFETY: pointer is not null and points to a static nul-terminated string
+    let version = unsafe { std::ffi::CStr::from_ptr(version) };
+
+    version.to_str().ok().map(String::from)
+}
+
+#[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+#[inline]
+/// Process is not linked with glibc
+fn process_glibc_version() -> Option<String> {
+    None
+}
+
+/// Find library and get version from the name of the file it links to
+/// (`libfuse.so.2` -> `libfuse.so.2.9.9` -> `2.9.9`, `libc.so.6` -> `libc-2.31.so` -> `2.31`)
+/// 
+/// Returns `Some(None)` if library is found but its version is unknown
+fn library_version(fs: &impl FileSystem, library: &str) -> Option<Option<String>> {
+    let arch = std::env::consts::ARCH;
+
+    let dirs = [
+        PathBuf::from("/usr/lib64"),
+        PathBuf::from(format!("/usr/lib/{arch}-linux-gnu")),
+        PathBuf::from("/usr/lib"),
+        PathBuf::from("/lib64"),
+        PathBuf::from(format!("/lib/{arch}-linux-gnu")),
+        PathBuf::from("/lib")
+    ];
+
+    let path = dirs.iter()
+        .map(|dir| dir.join(library))
+        .find(|path| fs.exists(path))?;
+
+    let version = fs.read_link(&path).ok()
+        .and_then(|target| {
+            let name = target.file_name()?.to_str()?;
+            let name = name.strip_prefix(library.split(".so.").next()?)?;
+
+            let version = name.strip_prefix(".so.")
+                .or_else(|| name.strip_prefix('-')?.strip_suffix(".so"))?;
+
+            Some(version.to_string())
+        });
+
+    Some(version)
+}
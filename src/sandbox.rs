@@ -0,0 +1,158 @@
+use std::fmt::Display;
+use std::path::Path;
+
+use crate::{Distro, FileSystem, RealFs, identify, identify_with_fs, identify_file};
+use crate::overrides::get_override;
+
+/// os-release files of the host system available from inside sandboxes
+const FLATPAK_HOST_FILES: &[&str] = &[
+    "/run/host/os-release",
+    "/run/host/etc/os-release",
+    "/run/host/usr/lib/os-release"
+];
+
+const SNAP_HOST_FILES: &[&str] = &[
+    "/var/lib/snapd/hostfs/etc/os-release",
+    "/var/lib/snapd/hostfs/usr/lib/os-release"
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Application sandbox the current process is running in
+pub enum Sandbox {
+    /// Process is not sandboxed
+    None,
+
+    /// Flatpak (`/.flatpak-info` file exists)
+    Flatpak,
+
+    /// Snap (`SNAP` environment variable is set)
+    Snap,
+
+    /// AppImage (`APPIMAGE` environment variable is set)
+    /// 
+    /// AppImages are not sandboxed so `/etc/os-release`
+    /// still describes the host system
+    AppImage
+}
+
+impl Sandbox {
+    #[inline]
+    /// Check if `/etc/os-release` describes sandbox runtime instead of the host system
+    pub fn hides_host(&self) -> bool {
+        matches!(self, Self::Flatpak | Self::Snap)
+    }
+}
+
+impl Display for Sandbox {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None     => write!(f, "none"),
+            Self::Flatpak  => write!(f, "flatpak"),
+            Self::Snap     => write!(f, "snap"),
+            Self::AppImage => write!(f, "appimage")
+        }
+    }
+}
+
+/// Detect application sandbox the current process is running in
+/// 
+/// ```
+/// use whatadistro::Sandbox;
+/// 
+/// if whatadistro::detect_sandbox() == Sandbox::Flatpak {
+///     println!("Running inside flatpak, use identify_host() to get the host distro");
+/// }
+/// ```
+pub fn detect_sandbox() -> Sandbox {
+    detect_sandbox_with_fs(&RealFs)
+}
+
+/// Detect application sandbox using files of the given filesystem
+/// 
+/// Environment variables of the current process are still used
+/// 
+/// ```
+/// use whatadistro::{Sandbox, MockFs};
+/// 
+/// let fs = MockFs::default()
+///     .with_file("/.flatpak-info", "[Application]\nname=org.example.App");
+/// 
+/// assert_eq!(whatadistro::detect_sandbox_with_fs(&fs), Sandbox::Flatpak);
+/// ```
+pub fn detect_sandbox_with_fs(fs: &impl FileSystem) -> Sandbox {
+    if fs.exists(Path::new("/.flatpak-info")) {
+        trace!("/.flatpak-info exists, running inside flatpak");
+
+        Sandbox::Flatpak
+    }
+
+    else if std::env::var_os("SNAP").is_some() {
+        trace!("SNAP variable is set, running inside snap");
+
+        Sandbox::Snap
+    }
+
+    else if std::env::var_os("APPIMAGE").is_some() {
+        trace!("APPIMAGE variable is set, running inside appimage");
+
+        Sandbox::AppImage
+    }
+
+    else {
+        Sandbox::None
+    }
+}
+
+/// Identify host linux distro
+/// 
+/// Inside Flatpak and Snap sandboxes `/etc/os-release` describes the sandbox
+/// runtime (e.g. `org.freedesktop.Platform`), so host os-release files
+/// exposed by the sandbox are used instead. Works as `identify()` otherwise.
+/// Distro set by `set_override` is returned if any
+/// 
+/// ```
+/// if let Some(distro) = whatadistro::identify_host() {
+///     println!("Host distro: {}", distro.name());
+/// }
+/// ```
+pub fn identify_host() -> Option<Distro> {
+    if let Some(distro) = get_override() {
+        return Some(distro);
+    }
+
+    let host_files = match detect_sandbox() {
+        Sandbox::Flatpak => FLATPAK_HOST_FILES,
+        Sandbox::Snap    => SNAP_HOST_FILES,
+
+        Sandbox::AppImage | Sandbox::None => return identify()
+    };
+
+    host_files.iter().find_map(|path| identify_file(&RealFs, path))
+}
+
+/// Identify host linux distro using files of the given filesystem
+/// 
+/// Works like `identify_host()` but ignores overrides
+/// 
+/// ```
+/// use whatadistro::{MockFs, DistroId};
+/// 
+/// let fs = MockFs::default()
+///     .with_file("/.flatpak-info", "[Application]\nname=org.example.App")
+///     .with_file("/etc/os-release", "NAME=\"Freedesktop SDK\"\nID=org.freedesktop.platform")
+///     .with_file("/run/host/os-release", "NAME=Fedora\nID=fedora\nVERSION_ID=40");
+/// 
+/// let distro = whatadistro::identify_host_with_fs(&fs).unwrap();
+/// 
+/// assert_eq!(distro.id(), &DistroId::Fedora);
+/// ```
+pub fn identify_host_with_fs(fs: &impl FileSystem) -> Option<Distro> {
+    let host_files = match detect_sandbox_with_fs(fs) {
+        Sandbox::Flatpak => FLATPAK_HOST_FILES,
+        Sandbox::Snap    => SNAP_HOST_FILES,
+
+        Sandbox::AppImage | Sandbox::None => return identify_with_fs(fs)
+    };
+
+    host_files.iter().find_map(|path| identify_file(fs, path))
+}
@@ -0,0 +1,94 @@
+use crate::{Distro, DistroId, DistroFamily, FileSystem, RealFs, confidence};
+
+impl Distro {
+    /// Get package URL type and namespace of the distro packages
+    /// 
+    /// Used as `pkg:<namespace>/<name>@<version>` in SBOMs.
+    /// Returns `None` for distros without a package URL type
+    /// (Gentoo, NixOS, immutable distros)
+    /// 
+    /// ```
+    /// use whatadistro::Distro;
+    /// 
+    /// let distro = Distro::from_os_release("NAME=\"Fedora Linux\"\nID=fedora").unwrap();
+    /// 
+    /// assert_eq!(distro.purl_namespace().as_deref(), Some("rpm/fedora"));
+    /// 
+    /// let distro = Distro::from_os_release("NAME=\"Zorin OS\"\nID=zorin\nID_LIKE=ubuntu").unwrap();
+    /// 
+    /// assert_eq!(distro.purl_namespace().as_deref(), Some("deb/zorin"));
+    /// ```
+    pub fn purl_namespace(&self) -> Option<String> {
+        let purl_type = match self.family() {
+            DistroFamily::Debian => "deb",
+            DistroFamily::RedHat => "rpm",
+            DistroFamily::Arch   => "alpm",
+            DistroFamily::Alpine => "apk",
+
+            _ => return None
+        };
+
+        // Red Hat publishes its packages under the vendor name
+        let namespace = match self.id() {
+            DistroId::RHEL => "redhat",
+
+            id => id.as_str()
+        };
+
+        Some(format!("{purl_type}/{namespace}"))
+    }
+
+    #[inline]
+    /// Check if the current system is a distroless container image
+    /// 
+    /// See `Distro::is_distroless_with_fs`
+    /// 
+    /// ```
+    /// if let Some(distro) = whatadistro::identify() {
+    ///     if distro.is_distroless() {
+    ///         println!("No packages can be installed in this image");
+    ///     }
+    /// }
+    /// ```
+    pub fn is_distroless(&self) -> bool {
+        self.is_distroless_with_fs(&RealFs)
+    }
+
+    /// Check if the given filesystem is a distroless container image
+    /// 
+    /// Images are distroless if their os-release file names distroless
+    /// (`PRETTY_NAME="Distroless"`) or if the distro has a package manager
+    /// but its package database is missing
+    /// 
+    /// ```
+    /// use whatadistro::MockFs;
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/etc/os-release", "NAME=Debian\nID=debian\nVERSION_ID=12");
+    /// 
+    /// let distro = whatadistro::identify_with_fs(&fs).unwrap();
+    /// 
+    /// assert!(distro.is_distroless_with_fs(&fs));
+    /// 
+    /// let fs = fs.with_file("/var/lib/dpkg/status", "");
+    /// 
+    /// assert!(!distro.is_distroless_with_fs(&fs));
+    /// ```
+    pub fn is_distroless_with_fs(&self, fs: &impl FileSystem) -> bool {
+        let marked = ["PRETTY_NAME", "HOME_URL"].iter()
+            .filter_map(|key| self.field(key))
+            .any(|value| value.to_ascii_lowercase().contains("distroless"));
+
+        if marked {
+            trace!("os-release file is marked as distroless");
+
+            return true;
+        }
+
+        if self.id().package_manager().is_none() && self.purl_namespace().is_none() {
+            return false;
+        }
+
+        !confidence::has_package_db(fs)
+    }
+}
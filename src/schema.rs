@@ -0,0 +1,53 @@
+use std::fmt::Display;
+
+#[cfg(feature = "audit")]
+use crate::json::Value;
+
+/// Version of the JSON schema used by the serialized outputs
+/// (`AuditBundle::to_json`, `DistroFamily::to_json`)
+/// 
+/// Every output has a `schema_version` number starting from 1.
+/// Version is increased on any incompatible change, and parsers
+/// of the crate keep reading all the previous versions
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Error returned when serialized output can't be read
+pub enum SchemaError {
+    /// Input is not a valid JSON document or misses required fields
+    Malformed,
+
+    /// Output was made by a newer version of the crate
+    UnsupportedVersion(u32)
+}
+
+impl Display for SchemaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Malformed => write!(f, "malformed serialized output"),
+
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported schema version {version}, latest supported is {SCHEMA_VERSION}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+#[cfg(feature = "audit")]
+/// Get schema version of the parsed JSON object
+/// 
+/// Objects without `schema_version` field are malformed
+pub(crate) fn version(value: &Value) -> Result<u32, SchemaError> {
+    let version = value.get("schema_version")
+        .and_then(Value::as_number)
+        .filter(|version| version.fract() == 0.0 && *version >= 1.0 && *version <= f64::from(u32::MAX))
+        .ok_or(SchemaError::Malformed)? as u32;
+
+    if version > SCHEMA_VERSION {
+        return Err(SchemaError::UnsupportedVersion(version));
+    }
+
+    Ok(version)
+}
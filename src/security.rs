@@ -0,0 +1,215 @@
+//! Linux security modules status (SELinux and AppArmor)
+//! 
+//! ```
+//! use whatadistro::security::{self, SelinuxMode};
+//! 
+//! let selinux = security::selinux();
+//! 
+//! if selinux.mode() != SelinuxMode::Disabled {
+//!     println!("SELinux is {} ({:?} policy)", selinux.mode(), selinux.policy());
+//! }
+//! 
+//! if security::apparmor().is_enabled() {
+//!     println!("AppArmor is enabled");
+//! }
+//! ```
+
+use std::fmt::Display;
+use std::path::Path;
+
+use crate::{FileSystem, RealFs};
+use crate::envfile;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// SELinux mode
+pub enum SelinuxMode {
+    /// Policy is enforced
+    Enforcing,
+
+    /// Policy violations are only logged
+    Permissive,
+
+    /// SELinux is not loaded
+    Disabled
+}
+
+impl SelinuxMode {
+    /// Parse mode name used in `/etc/selinux/config`
+    fn from_config(mode: &str) -> Option<Self> {
+        match mode.to_ascii_lowercase().as_str() {
+            "enforcing"  => Some(Self::Enforcing),
+            "permissive" => Some(Self::Permissive),
+            "disabled"   => Some(Self::Disabled),
+
+            _ => None
+        }
+    }
+}
+
+impl Display for SelinuxMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Enforcing  => write!(f, "enforcing"),
+            Self::Permissive => write!(f, "permissive"),
+            Self::Disabled   => write!(f, "disabled")
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// SELinux status
+pub struct SelinuxStatus {
+    mode: SelinuxMode,
+    configured_mode: Option<SelinuxMode>,
+    policy: Option<String>
+}
+
+impl SelinuxStatus {
+    /// Make status from `/sys/fs/selinux/enforce` and `/etc/selinux/config` files content
+    /// 
+    /// ```
+    /// use whatadistro::security::{SelinuxStatus, SelinuxMode};
+    /// 
+    /// let status = SelinuxStatus::from_files(Some("0"), Some(r#"
+    ///     SELINUX=enforcing
+    ///     SELINUXTYPE=targeted
+    /// "#));
+    /// 
+    /// assert_eq!(status.mode(), SelinuxMode::Permissive);
+    /// assert_eq!(status.configured_mode(), Some(SelinuxMode::Enforcing));
+    /// assert_eq!(status.policy(), Some("targeted"));
+    /// 
+    /// let status = SelinuxStatus::from_files(None, None);
+    /// 
+    /// assert_eq!(status.mode(), SelinuxMode::Disabled);
+    /// ```
+    pub fn from_files(enforce: Option<&str>, config: Option<&str>) -> Self {
+        let mode = match enforce.map(str::trim) {
+            Some("1") => SelinuxMode::Enforcing,
+            Some("0") => SelinuxMode::Permissive,
+
+            _ => SelinuxMode::Disabled
+        };
+
+        let config = config.map(envfile::parse).unwrap_or_default();
+
+        Self {
+            mode,
+            configured_mode: config.get("SELINUX").and_then(|mode| SelinuxMode::from_config(mode)),
+            policy: config.get("SELINUXTYPE").cloned()
+        }
+    }
+
+    #[inline]
+    /// Get current SELinux mode
+    pub fn mode(&self) -> SelinuxMode {
+        self.mode
+    }
+
+    #[inline]
+    /// Get mode configured in `/etc/selinux/config`
+    /// 
+    /// It can differ from the current one until reboot
+    /// or if the mode was changed using `setenforce`
+    pub fn configured_mode(&self) -> Option<SelinuxMode> {
+        self.configured_mode
+    }
+
+    #[inline]
+    /// Get configured policy type (e.g. `targeted` or `mls`)
+    pub fn policy(&self) -> Option<&str> {
+        self.policy.as_deref()
+    }
+
+    #[inline]
+    /// Check if SELinux file contexts should be set for installed files
+    pub fn needs_contexts(&self) -> bool {
+        self.mode != SelinuxMode::Disabled
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// AppArmor status
+pub struct AppArmorStatus {
+    enabled: bool,
+    profiles: Option<usize>
+}
+
+impl AppArmorStatus {
+    #[inline]
+    /// Check if AppArmor is enabled in kernel
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    #[inline]
+    /// Get amount of loaded profiles
+    /// 
+    /// Profiles list is readable only by root,
+    /// so `None` is returned for other users
+    pub fn profiles(&self) -> Option<usize> {
+        self.profiles
+    }
+}
+
+/// Get current SELinux status
+pub fn selinux() -> SelinuxStatus {
+    selinux_with_fs(&RealFs)
+}
+
+/// Get SELinux status using files of the given filesystem
+/// 
+/// ```
+/// use whatadistro::MockFs;
+/// use whatadistro::security::{self, SelinuxMode};
+/// 
+/// let fs = MockFs::default()
+///     .with_file("/sys/fs/selinux/enforce", "1")
+///     .with_file("/etc/selinux/config", "SELINUX=enforcing\nSELINUXTYPE=targeted");
+/// 
+/// assert_eq!(security::selinux_with_fs(&fs).mode(), SelinuxMode::Enforcing);
+/// assert_eq!(security::selinux_with_fs(&MockFs::default()).mode(), SelinuxMode::Disabled);
+/// ```
+pub fn selinux_with_fs(fs: &impl FileSystem) -> SelinuxStatus {
+    let enforce = fs.read_to_string(Path::new("/sys/fs/selinux/enforce")).ok();
+    let config = fs.read_to_string(Path::new("/etc/selinux/config")).ok();
+
+    if enforce.is_none() {
+        trace!("/sys/fs/selinux/enforce is missing, SELinux is disabled");
+    }
+
+    SelinuxStatus::from_files(enforce.as_deref(), config.as_deref())
+}
+
+/// Get current AppArmor status
+pub fn apparmor() -> AppArmorStatus {
+    apparmor_with_fs(&RealFs)
+}
+
+/// Get AppArmor status using files of the given filesystem
+/// 
+/// ```
+/// use whatadistro::MockFs;
+/// use whatadistro::security;
+/// 
+/// let fs = MockFs::default()
+///     .with_file("/sys/module/apparmor/parameters/enabled", "Y")
+///     .with_file("/sys/kernel/security/apparmor/profiles", "/usr/bin/man (enforce)\nlsb_release (enforce)");
+/// 
+/// assert!(security::apparmor_with_fs(&fs).is_enabled());
+/// assert_eq!(security::apparmor_with_fs(&fs).profiles(), Some(2));
+/// ```
+pub fn apparmor_with_fs(fs: &impl FileSystem) -> AppArmorStatus {
+    let enabled = fs.read_to_string(Path::new("/sys/module/apparmor/parameters/enabled"))
+        .map(|enabled| enabled.trim() == "Y")
+        .unwrap_or(false);
+
+    let profiles = fs.read_to_string(Path::new("/sys/kernel/security/apparmor/profiles")).ok()
+        .filter(|_| enabled)
+        .map(|profiles| profiles.lines().filter(|line| !line.trim().is_empty()).count());
+
+    AppArmorStatus {
+        enabled,
+        profiles
+    }
+}
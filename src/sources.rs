@@ -0,0 +1,243 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use crate::{Distro, FileSystem, RealFs, identify_file};
+use crate::overrides::get_override;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Source of the distro info
+pub enum Source {
+    /// Distro set by `set_override`
+    Override,
+
+    /// `/etc/os-release` file
+    EtcOsRelease,
+
+    /// `/usr/lib/os-release` file
+    UsrLibOsRelease,
+
+    /// `/etc/lsb-release` file
+    LsbRelease
+}
+
+impl Source {
+    #[inline]
+    /// Check if the source is one of os-release files
+    pub fn is_os_release(&self) -> bool {
+        matches!(self, Self::EtcOsRelease | Self::UsrLibOsRelease)
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Override        => write!(f, "override"),
+            Self::EtcOsRelease    => write!(f, "/etc/os-release"),
+            Self::UsrLibOsRelease => write!(f, "/usr/lib/os-release"),
+            Self::LsbRelease      => write!(f, "/etc/lsb-release")
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// File the distro info was actually read from
+pub struct DetectionSource {
+    path: PathBuf,
+    is_symlink: bool,
+    drop_ins: Vec<PathBuf>
+}
+
+impl DetectionSource {
+    #[inline]
+    /// Get path of the file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    #[inline]
+    /// Check if the path is a symlink (`/etc/os-release` usually
+    /// links to `/usr/lib/os-release`)
+    pub fn is_symlink(&self) -> bool {
+        self.is_symlink
+    }
+
+    #[inline]
+    /// Get drop-in files merged over the file, in the merge order
+    /// 
+    /// Drop-ins are `*.conf` files of the `.d` directory next to the
+    /// os-release file (`/etc/os-release.d/*.conf`). Later files win per key
+    pub fn drop_ins(&self) -> &[PathBuf] {
+        &self.drop_ins
+    }
+}
+
+impl Distro {
+    #[inline]
+    /// Store file the distro was read from
+    pub(crate) fn with_source(self, path: impl AsRef<Path>) -> Self {
+        self.with_source_in(&RealFs, path)
+    }
+
+    /// Store file of the given filesystem the distro was read from
+    pub(crate) fn with_source_in(mut self, fs: &impl FileSystem, path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+
+        self.source = Some(DetectionSource {
+            path: path.to_path_buf(),
+            is_symlink: fs.read_link(path).is_ok(),
+            drop_ins: Vec::new()
+        });
+
+        self
+    }
+
+    #[inline]
+    /// Store drop-in files merged over the source file
+    pub(crate) fn with_drop_ins(mut self, drop_ins: Vec<PathBuf>) -> Self {
+        if let Some(source) = &mut self.source {
+            source.drop_ins = drop_ins;
+        }
+
+        self
+    }
+
+    #[inline]
+    /// Get file the distro info was read from
+    /// 
+    /// Returns `None` for distros parsed from strings
+    /// or embedded at compile time
+    /// 
+    /// ```
+    /// if let Some(distro) = whatadistro::identify() {
+    ///     if let Some(source) = distro.detection_source() {
+    ///         println!("Read from {:?} (symlink: {})", source.path(), source.is_symlink());
+    ///     }
+    /// }
+    /// ```
+    pub fn detection_source(&self) -> Option<&DetectionSource> {
+        self.source.as_ref()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Strategy used to pick a single distro from multiple sources
+pub enum Reconcile {
+    /// Use override or os-release files first, then lsb-release
+    PreferOsRelease,
+
+    /// Use override or lsb-release file first, then os-release files
+    PreferLsb,
+
+    /// Return distro only if all the sources agree on its id
+    Strict
+}
+
+/// Identify distro using every available source separately
+/// 
+/// Unlike `identify()` sources are not merged, so tools can see
+/// when they disagree (e.g. bind-mounted files in containers)
+/// 
+/// ```
+/// for (source, distro) in whatadistro::identify_all() {
+///     println!("{source}: {}", distro.id());
+/// }
+/// ```
+pub fn identify_all() -> Vec<(Source, Distro)> {
+    let mut distros = identify_all_with_fs(&RealFs);
+
+    if let Some(distro) = get_override() {
+        distros.insert(0, (Source::Override, distro));
+    }
+
+    distros
+}
+
+/// Identify distro using every available source of the given filesystem separately
+/// 
+/// Works like `identify_all()` but ignores overrides
+/// 
+/// ```
+/// use whatadistro::{MockFs, Source, DistroId};
+/// 
+/// let fs = MockFs::default()
+///     .with_file("/etc/os-release", "NAME=Debian\nID=debian")
+///     .with_file("/etc/lsb-release", "DISTRIB_ID=Ubuntu");
+/// 
+/// let sources = whatadistro::identify_all_with_fs(&fs);
+/// 
+/// assert_eq!(sources.len(), 2);
+/// assert_eq!(sources[0].0, Source::EtcOsRelease);
+/// assert_eq!(sources[1].1.id(), &DistroId::Ubuntu);
+/// ```
+pub fn identify_all_with_fs(fs: &impl FileSystem) -> Vec<(Source, Distro)> {
+    let mut distros = Vec::new();
+
+    if let Some(distro) = identify_file(fs, "/etc/os-release") {
+        distros.push((Source::EtcOsRelease, distro));
+    }
+
+    if let Some(distro) = identify_file(fs, "/usr/lib/os-release") {
+        distros.push((Source::UsrLibOsRelease, distro));
+    }
+
+    let lsb_release = fs.read_to_string(Path::new("/etc/lsb-release")).ok()
+        .and_then(|lsb_release| Distro::from_lsb_release(&lsb_release))
+        .map(|distro| distro.with_source_in(fs, "/etc/lsb-release"));
+
+    if let Some(distro) = lsb_release {
+        distros.push((Source::LsbRelease, distro));
+    }
+
+    distros
+}
+
+/// Pick a single distro from the list returned by `identify_all()`
+/// 
+/// Override always wins unless `Reconcile::Strict` is used
+/// and other sources disagree with it
+/// 
+/// ```
+/// use whatadistro::{Distro, DistroId, Source, Reconcile};
+/// 
+/// let candidates = vec![
+///     (Source::EtcOsRelease, Distro::from_os_release("NAME=Debian\nID=debian").unwrap()),
+///     (Source::LsbRelease, Distro::from_lsb_release("DISTRIB_ID=Ubuntu").unwrap())
+/// ];
+/// 
+/// let distro = whatadistro::reconcile(&candidates, Reconcile::PreferOsRelease).unwrap();
+/// assert_eq!(distro.id(), &DistroId::Debian);
+/// 
+/// let distro = whatadistro::reconcile(&candidates, Reconcile::PreferLsb).unwrap();
+/// assert_eq!(distro.id(), &DistroId::Ubuntu);
+/// 
+/// assert_eq!(whatadistro::reconcile(&candidates, Reconcile::Strict), None);
+/// ```
+pub fn reconcile(candidates: &[(Source, Distro)], strategy: Reconcile) -> Option<Distro> {
+    let find = |filter: fn(&Source) -> bool| candidates.iter()
+        .find(|(source, _)| filter(source))
+        .map(|(_, distro)| distro.clone());
+
+    let overridden = find(|source| source == &Source::Override);
+    let os_release = find(Source::is_os_release);
+    let lsb_release = find(|source| source == &Source::LsbRelease);
+
+    match strategy {
+        Reconcile::PreferOsRelease => overridden.or(os_release).or(lsb_release),
+        Reconcile::PreferLsb       => overridden.or(lsb_release).or(os_release),
+
+        Reconcile::Strict => {
+            let (_, first) = candidates.first()?;
+
+            let agree = candidates.iter()
+                .all(|(_, distro)| distro.id() == first.id());
+
+            if !agree {
+                trace!("distro sources disagree, {} candidates found", candidates.len());
+
+                return None;
+            }
+
+            overridden.or(os_release).or(lsb_release)
+        }
+    }
+}
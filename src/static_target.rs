@@ -0,0 +1,54 @@
+use std::sync::RwLock;
+
+use crate::{Distro, DistroId, os_release};
+
+include!(concat!(env!("OUT_DIR"), "/static_target.rs"));
+
+/// Static target distro parsed by the first call
+static PARSED: RwLock<Option<Option<Distro>>> = RwLock::new(None);
+
+/// Get distro embedded at compile time
+/// 
+/// Content is validated by the build script and parsed once
+pub(crate) fn static_distro() -> Option<Distro> {
+    STATIC_OS_RELEASE?;
+
+    let parsed = match PARSED.read() {
+        Ok(parsed) => parsed.clone(),
+        Err(err) => err.into_inner().clone()
+    };
+
+    if let Some(distro) = parsed {
+        return distro;
+    }
+
+    let distro = parse();
+
+    match PARSED.write() {
+        Ok(mut parsed) => *parsed = Some(distro.clone()),
+        Err(err) => *err.into_inner() = Some(distro.clone())
+    }
+
+    distro
+}
+
+/// Parse static target distro
+/// 
+/// `NAME` field is optional for static targets
+/// and defaults to the known distro name
+fn parse() -> Option<Distro> {
+    let content = STATIC_OS_RELEASE?;
+
+    if let Some(distro) = Distro::from_os_release(content) {
+        return Some(distro);
+    }
+
+    let fields = os_release::parse(content);
+    let id = DistroId::from(fields.get("ID")?);
+
+    let name = id.name()
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"");
+
+    Distro::from_os_release(&format!("{content}\nNAME=\"{name}\""))
+}
@@ -0,0 +1,59 @@
+use crate::{Distro, DistroId, SystemInfo};
+
+/// DMI product names of the Steam Deck models
+/// (`Jupiter` is the LCD model, `Galileo` is the OLED one)
+const STEAM_DECK_PRODUCTS: &[&str] = &[
+    "Jupiter",
+    "Galileo"
+];
+
+/// Check if DMI data describes Steam Deck hardware
+fn is_steam_deck_hardware() -> bool {
+    let Some(dmi) = SystemInfo::dmi() else {
+        return false;
+    };
+
+    let Some(product) = dmi.product_name() else {
+        trace!("DMI product name is not available");
+
+        return false;
+    };
+
+    // Vendor is not checked when it's missing
+    let is_valve = dmi.sys_vendor()
+        .map(|vendor| vendor == "Valve")
+        .unwrap_or(true);
+
+    is_valve && STEAM_DECK_PRODUCTS.contains(&product)
+}
+
+impl Distro {
+    /// Check if the system is running on the Steam Deck
+    /// 
+    /// Hardware is detected using DMI product name, so it works on other
+    /// distros installed on the device as well. SteamOS builds for the Steam Deck
+    /// (`VARIANT_ID=steamdeck`) are recognized even if DMI data is not available
+    /// (e.g. inside of sandboxes)
+    /// 
+    /// ```
+    /// use whatadistro::Distro;
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME="SteamOS"
+    ///     ID=steamos
+    ///     ID_LIKE=arch
+    ///     VARIANT_ID=steamdeck
+    ///     VERSION_ID=3.6.19
+    /// "#).unwrap();
+    /// 
+    /// assert!(distro.is_similar("arch"));
+    /// assert!(distro.is_steam_deck());
+    /// ```
+    pub fn is_steam_deck(&self) -> bool {
+        if self.id() == &DistroId::SteamOS && self.field("VARIANT_ID") == Some("steamdeck") {
+            return true;
+        }
+
+        is_steam_deck_hardware()
+    }
+}
@@ -0,0 +1,131 @@
+use std::fmt::Display;
+
+use crate::DistroId;
+
+/// Maximal amount of suggestions stored in the error
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Get edit distance between two strings
+/// 
+/// Optimal string alignment distance: insertions, deletions,
+/// substitutions and transpositions of adjacent characters
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+
+    // Three last rows of the distances matrix
+    let mut before = vec![0; b.len() + 1];
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        current[0] = i;
+
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before[j - 2] + 1);
+            }
+        }
+
+        std::mem::swap(&mut before, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Error returned when the string is not a known distro id
+pub struct UnknownDistroError {
+    id: String,
+    suggestions: Vec<DistroId>
+}
+
+impl UnknownDistroError {
+    /// Create error for the given id, finding closest known ids
+    fn new(id: &str) -> Self {
+        let normalized = id.trim().to_ascii_lowercase().replace('_', "-");
+
+        // Allow one typo per three characters
+        let max_distance = ((normalized.chars().count() + 2) / 3).max(1);
+
+        let mut candidates = DistroId::KNOWN_IDS.iter()
+            .map(|(known_id, distro)| (edit_distance(&normalized, known_id), distro))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .collect::<Vec<_>>();
+
+        // Stable sort keeps KNOWN_IDS order for the same distance
+        candidates.sort_by_key(|(distance, _)| *distance);
+
+        let mut suggestions = Vec::new();
+
+        for (_, distro) in candidates {
+            if suggestions.len() >= MAX_SUGGESTIONS {
+                break;
+            }
+
+            if !suggestions.contains(distro) {
+                suggestions.push(distro.clone());
+            }
+        }
+
+        Self {
+            id: id.to_string(),
+            suggestions
+        }
+    }
+
+    #[inline]
+    /// Get the unknown id
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    #[inline]
+    /// Get known distros with similar ids, closest first
+    pub fn suggestions(&self) -> &[DistroId] {
+        &self.suggestions
+    }
+}
+
+impl Display for UnknownDistroError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown distro id: {}", self.id)?;
+
+        if let Some(suggestion) = self.suggestions.first() {
+            write!(f, ", did you mean {suggestion}?")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for UnknownDistroError {}
+
+impl DistroId {
+    /// Parse known distro id, refusing to create `DistroId::Other`
+    /// 
+    /// Useful to validate user input. Returned error
+    /// contains closest known ids to suggest
+    /// 
+    /// ```
+    /// use whatadistro::DistroId;
+    /// 
+    /// assert_eq!(DistroId::try_from_known("Fedora"), Ok(DistroId::Fedora));
+    /// 
+    /// let err = DistroId::try_from_known("fedoar").unwrap_err();
+    /// 
+    /// assert_eq!(err.suggestions(), &[DistroId::Fedora]);
+    /// assert_eq!(err.to_string(), "unknown distro id: fedoar, did you mean fedora?");
+    /// ```
+    pub fn try_from_known(id: &str) -> Result<Self, UnknownDistroError> {
+        Self::from_ascii_lowercase(id.trim())
+            .ok_or_else(|| UnknownDistroError::new(id))
+    }
+}
@@ -0,0 +1,150 @@
+use std::fmt::Display;
+use std::path::Path;
+
+use crate::{Distro, AnsiColor, InitSystem, FileSystem, RealFs, detect_init_system_with_fs};
+
+/// Bold text, used for labels when distro has no brand color
+const BOLD: &str = "1";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Compact multi-line system description
+/// 
+/// ```text
+/// Distro:  Arch Linux (rolling)
+/// Kernel:  6.9.1-arch1-1
+/// Arch:    x86_64
+/// Init:    systemd
+/// Desktop: KDE
+/// ```
+pub struct Summary {
+    distro: String,
+    kernel: Option<String>,
+    arch: &'static str,
+    init: InitSystem,
+    desktop: Option<String>,
+    color: Option<AnsiColor>,
+    colored: bool
+}
+
+impl Summary {
+    #[inline]
+    /// Color labels using the distro brand color (or bold text if there's none)
+    pub fn colored(mut self, colored: bool) -> Self {
+        self.colored = colored;
+
+        self
+    }
+
+    #[inline]
+    /// Get distro name and version
+    pub fn distro(&self) -> &str {
+        &self.distro
+    }
+
+    #[inline]
+    /// Get kernel release (e.g. `6.9.1-arch1-1`)
+    pub fn kernel(&self) -> Option<&str> {
+        self.kernel.as_deref()
+    }
+
+    #[inline]
+    /// Get CPU architecture the program is compiled for (e.g. `x86_64`)
+    pub fn arch(&self) -> &'static str {
+        self.arch
+    }
+
+    #[inline]
+    /// Get init system
+    pub fn init(&self) -> InitSystem {
+        self.init
+    }
+
+    #[inline]
+    /// Get current desktop environment (`XDG_CURRENT_DESKTOP` variable)
+    pub fn desktop(&self) -> Option<&str> {
+        self.desktop.as_deref()
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let color = self.color.clone()
+            .or_else(|| AnsiColor::parse(BOLD))
+            .filter(|_| self.colored);
+
+        let label = |name: &str| match &color {
+            Some(color) => color.paint(format!("{name}:")),
+            None => format!("{name}:")
+        };
+
+        let unknown = String::from("unknown");
+
+        writeln!(f, "{}  {}", label("Distro"), self.distro)?;
+        writeln!(f, "{}  {}", label("Kernel"), self.kernel.as_ref().unwrap_or(&unknown))?;
+        writeln!(f, "{}    {}", label("Arch"), self.arch)?;
+        writeln!(f, "{}    {}", label("Init"), self.init)?;
+        write!(f, "{} {}", label("Desktop"), self.desktop.as_ref().unwrap_or(&unknown))
+    }
+}
+
+impl Distro {
+    /// Get compact description of the system for diagnostic
+    /// dumps and bug reports
+    /// 
+    /// Kernel release, init system and desktop environment
+    /// are detected from the current system
+    /// 
+    /// ```
+    /// if let Some(distro) = whatadistro::identify() {
+    ///     println!("{}", distro.summary().colored(true));
+    /// }
+    /// ```
+    pub fn summary(&self) -> Summary {
+        self.summary_with_fs(&RealFs)
+    }
+
+    /// Get compact description of the system using files of the given filesystem
+    /// 
+    /// Desktop environment is still taken from the current process variables
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, InitSystem, MockFs};
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Arch Linux\nID=arch").unwrap();
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/proc/sys/kernel/osrelease", "6.9.1-arch1-1\n")
+    ///     .with_file("/proc/1/comm", "systemd\n");
+    /// 
+    /// let summary = distro.summary_with_fs(&fs);
+    /// 
+    /// assert_eq!(summary.kernel(), Some("6.9.1-arch1-1"));
+    /// assert_eq!(summary.init(), InitSystem::Systemd);
+    /// ```
+    pub fn summary_with_fs(&self, fs: &impl FileSystem) -> Summary {
+        let distro = match (self.pretty_name(), self.version()) {
+            (Some(pretty_name), _) => pretty_name.to_string(),
+            (None, Some(version)) => format!("{} {version}", self.name()),
+            (None, None) => self.name().to_string()
+        };
+
+        let distro = format!("{distro} ({})", self.release_channel());
+
+        let kernel = fs.read_to_string(Path::new("/proc/sys/kernel/osrelease")).ok()
+            .map(|kernel| kernel.trim().to_string());
+
+        let desktop = std::env::var("XDG_CURRENT_DESKTOP").ok()
+            .or_else(|| std::env::var("DESKTOP_SESSION").ok())
+            .filter(|desktop| !desktop.is_empty());
+
+        Summary {
+            distro,
+            kernel,
+            arch: std::env::consts::ARCH,
+            init: detect_init_system_with_fs(fs),
+            desktop,
+            color: self.brand_color(),
+            colored: false
+        }
+    }
+}
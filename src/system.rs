@@ -0,0 +1,397 @@
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use crate::{FileSystem, RealFs};
+use crate::envfile;
+
+/// Files storing the machine id, most preferred first
+const MACHINE_ID_FILES: &[&str] = &[
+    "/etc/machine-id",
+    "/var/lib/dbus/machine-id"
+];
+
+/// Files storing system locale as `LANG` variable
+const LOCALE_FILES: &[&str] = &[
+    "/etc/locale.conf",
+    "/etc/default/locale",
+    "/etc/sysconfig/i18n"
+];
+
+/// Package manager databases updated on every install, upgrade or removal
+const PACKAGE_DATABASES: &[&str] = &[
+    "/var/lib/dpkg/status",
+    "/var/lib/pacman/local",
+    "/var/lib/rpm/rpmdb.sqlite",
+    "/var/lib/rpm/Packages",
+    "/usr/lib/sysimage/rpm/rpmdb.sqlite",
+    "/usr/lib/sysimage/rpm/Packages.db",
+    "/lib/apk/db/installed"
+];
+
+#[cfg(unix)]
+extern "C" {
+    fn gethostname(name: *mut std::os::raw::c_char, len: usize) -> std::os::raw::c_int;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Information about the current machine
+pub struct SystemInfo;
+
+impl SystemInfo {
+    /// Get unique machine id
+    /// 
+    /// Machine id is a 32 characters long lowercase hex string.
+    /// Returns `None` if the id is missing, malformed or not
+    /// initialized yet (`uninitialized` value during the first boot)
+    /// 
+    /// ```
+    /// use whatadistro::SystemInfo;
+    /// 
+    /// if let Some(id) = SystemInfo::machine_id() {
+    ///     assert_eq!(id.len(), 32);
+    /// }
+    /// ```
+    pub fn machine_id() -> Option<String> {
+        Self::machine_id_with_fs(&RealFs)
+    }
+
+    /// Get unique machine id using files of the given filesystem
+    /// 
+    /// ```
+    /// use whatadistro::{SystemInfo, MockFs};
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/etc/machine-id", "uninitialized\n")
+    ///     .with_file("/var/lib/dbus/machine-id", "4c4c4544004d3910804cb4c04f4e3132\n");
+    /// 
+    /// assert_eq!(SystemInfo::machine_id_with_fs(&fs).as_deref(), Some("4c4c4544004d3910804cb4c04f4e3132"));
+    /// ```
+    pub fn machine_id_with_fs(fs: &impl FileSystem) -> Option<String> {
+        MACHINE_ID_FILES.iter().find_map(|path| {
+            let id = fs.read_to_string(Path::new(path)).ok()?;
+            let id = id.trim();
+
+            let valid = id.len() == 32 &&
+                id.bytes().all(|byte| matches!(byte, b'0'..=b'9' | b'a'..=b'f')) &&
+                id.bytes().any(|byte| byte != b'0');
+
+            if !valid {
+                trace!("{path} contains invalid machine id");
+
+                return None;
+            }
+
+            Some(id.to_string())
+        })
+    }
+
+    /// Get hostname of the machine
+    /// 
+    /// `/etc/hostname` file is used first, then the
+    /// kernel hostname returned by `gethostname`
+    /// 
+    /// ```
+    /// use whatadistro::SystemInfo;
+    /// 
+    /// if let Some(hostname) = SystemInfo::hostname() {
+    ///     println!("Hostname: {hostname}");
+    /// }
+    /// ```
+    pub fn hostname() -> Option<String> {
+        let hostname = Self::hostname_with_fs(&RealFs);
+
+        if hostname.is_none() {
+            trace!("/etc/hostname is missing or empty, using gethostname");
+        }
+
+        hostname.or_else(kernel_hostname)
+    }
+
+    /// Get hostname from the `/etc/hostname` file of the given filesystem
+    /// 
+    /// Kernel hostname is not used
+    /// 
+    /// ```
+    /// use whatadistro::{SystemInfo, MockFs};
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/etc/hostname", "# managed by cloud-init\nbuild-01\n");
+    /// 
+    /// assert_eq!(SystemInfo::hostname_with_fs(&fs).as_deref(), Some("build-01"));
+    /// assert_eq!(SystemInfo::hostname_with_fs(&MockFs::default()), None);
+    /// ```
+    pub fn hostname_with_fs(fs: &impl FileSystem) -> Option<String> {
+        fs.read_to_string(Path::new("/etc/hostname")).ok()
+            .and_then(|hostname| {
+                // File can contain comments
+                hostname.lines()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty() && !line.starts_with('#'))
+                    .map(String::from)
+            })
+    }
+
+    /// Get time passed since the system boot
+    /// 
+    /// ```
+    /// use whatadistro::SystemInfo;
+    /// 
+    /// if let Some(uptime) = SystemInfo::uptime() {
+    ///     println!("Up for {} minutes", uptime.as_secs() / 60);
+    /// }
+    /// ```
+    pub fn uptime() -> Option<Duration> {
+        Self::uptime_with_fs(&RealFs)
+    }
+
+    /// Get time passed since the system boot using files of the given filesystem
+    /// 
+    /// ```
+    /// use std::time::Duration;
+    /// 
+    /// use whatadistro::{SystemInfo, MockFs};
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/proc/uptime", "3600.50 14000.12");
+    /// 
+    /// assert_eq!(SystemInfo::uptime_with_fs(&fs), Some(Duration::from_secs_f64(3600.5)));
+    /// ```
+    pub fn uptime_with_fs(fs: &impl FileSystem) -> Option<Duration> {
+        let uptime = fs.read_to_string(Path::new("/proc/uptime")).ok()?;
+
+        // First value is the uptime, second is the idle time
+        let seconds = uptime.split_whitespace()
+            .next()?
+            .parse::<f64>()
+            .ok()?;
+
+        // Duration::from_secs_f64 panics on negative and too large values
+        if !seconds.is_finite() || seconds < 0.0 || seconds >= u64::MAX as f64 {
+            return None;
+        }
+
+        Some(Duration::from_secs_f64(seconds))
+    }
+
+    /// Get random id generated by the kernel on every boot
+    /// 
+    /// Id is a lowercase UUID string (`8-4-4-4-12` hex characters)
+    /// 
+    /// ```
+    /// use whatadistro::SystemInfo;
+    /// 
+    /// if let Some(id) = SystemInfo::boot_id() {
+    ///     assert_eq!(id.len(), 36);
+    /// }
+    /// ```
+    pub fn boot_id() -> Option<String> {
+        Self::boot_id_with_fs(&RealFs)
+    }
+
+    /// Get kernel boot id using files of the given filesystem
+    /// 
+    /// ```
+    /// use whatadistro::{SystemInfo, MockFs};
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/proc/sys/kernel/random/boot_id", "0F3C8A9E-5B2D-4E71-9C06-3A8D1B7E4F20\n");
+    /// 
+    /// assert_eq!(SystemInfo::boot_id_with_fs(&fs).as_deref(), Some("0f3c8a9e-5b2d-4e71-9c06-3a8d1b7e4f20"));
+    /// ```
+    pub fn boot_id_with_fs(fs: &impl FileSystem) -> Option<String> {
+        let id = fs.read_to_string(Path::new("/proc/sys/kernel/random/boot_id")).ok()?;
+        let id = id.trim().to_ascii_lowercase();
+
+        let valid = id.split('-')
+            .map(|part| part.len())
+            .eq([8, 4, 4, 4, 12]) && id.bytes().all(|byte| byte == b'-' || byte.is_ascii_hexdigit());
+
+        if !valid {
+            trace!("kernel boot id is malformed: {id}");
+
+            return None;
+        }
+
+        Some(id)
+    }
+
+    /// Get time of the last package install, upgrade or removal
+    /// 
+    /// Modification time of the package manager database is used
+    /// (`/var/lib/dpkg/status`, `/var/lib/pacman/local`, rpm and apk databases).
+    /// If there are multiple databases then the latest time is returned
+    /// 
+    /// ```
+    /// use whatadistro::SystemInfo;
+    /// 
+    /// if let Some(time) = SystemInfo::last_package_activity() {
+    ///     if let Ok(elapsed) = time.elapsed() {
+    ///         println!("Packages were changed {} days ago", elapsed.as_secs() / 86400);
+    ///     }
+    /// }
+    /// ```
+    pub fn last_package_activity() -> Option<SystemTime> {
+        Self::last_package_activity_with_fs(&RealFs)
+    }
+
+    /// Get time of the last package changes using files of the given filesystem
+    /// 
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// 
+    /// use whatadistro::{SystemInfo, MockFs};
+    /// 
+    /// let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/var/lib/dpkg/status", "Package: base-files")
+    ///     .with_modified("/var/lib/dpkg/status", time);
+    /// 
+    /// assert_eq!(SystemInfo::last_package_activity_with_fs(&fs), Some(time));
+    /// ```
+    pub fn last_package_activity_with_fs(fs: &impl FileSystem) -> Option<SystemTime> {
+        PACKAGE_DATABASES.iter()
+            .filter_map(|path| fs.modified(Path::new(path)).ok())
+            .max()
+    }
+
+    /// Get locale of the current process or system (`en_US.UTF-8`)
+    /// 
+    /// `LC_ALL` and `LANG` variables are used first, then `LANG` value
+    /// from `/etc/locale.conf` (`/etc/default/locale` on Debian)
+    /// 
+    /// ```
+    /// use whatadistro::SystemInfo;
+    /// 
+    /// if let Some(locale) = SystemInfo::locale() {
+    ///     println!("Locale: {locale}");
+    /// }
+    /// ```
+    pub fn locale() -> Option<String> {
+        let locale = ["LC_ALL", "LANG"].iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|locale| !locale.is_empty());
+
+        if locale.is_some() {
+            return locale;
+        }
+
+        trace!("locale variables are not set, using system locale");
+
+        Self::locale_with_fs(&RealFs)
+    }
+
+    /// Get system locale from files of the given filesystem
+    /// 
+    /// Environment variables are not used
+    /// 
+    /// ```
+    /// use whatadistro::{SystemInfo, MockFs};
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/etc/default/locale", "LANG=\"de_DE.UTF-8\"");
+    /// 
+    /// assert_eq!(SystemInfo::locale_with_fs(&fs).as_deref(), Some("de_DE.UTF-8"));
+    /// ```
+    pub fn locale_with_fs(fs: &impl FileSystem) -> Option<String> {
+        LOCALE_FILES.iter().find_map(|path| {
+            let mut fields = envfile::parse(&fs.read_to_string(Path::new(path)).ok()?);
+
+            fields.remove("LANG").filter(|locale| !locale.is_empty())
+        })
+    }
+
+    /// Get system timezone name (`Europe/Berlin`)
+    /// 
+    /// Name is taken from the `/etc/localtime` symlink target, then
+    /// from `/etc/timezone` (Debian) and `/etc/sysconfig/clock` (old RHEL)
+    /// 
+    /// ```
+    /// use whatadistro::SystemInfo;
+    /// 
+    /// if let Some(timezone) = SystemInfo::timezone() {
+    ///     println!("Timezone: {timezone}");
+    /// }
+    /// ```
+    pub fn timezone() -> Option<String> {
+        Self::timezone_with_fs(&RealFs)
+    }
+
+    /// Get system timezone name using files of the given filesystem
+    /// 
+    /// ```
+    /// use whatadistro::{SystemInfo, MockFs};
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/usr/share/zoneinfo/Europe/Berlin", "")
+    ///     .with_link("/etc/localtime", "../usr/share/zoneinfo/Europe/Berlin");
+    /// 
+    /// assert_eq!(SystemInfo::timezone_with_fs(&fs).as_deref(), Some("Europe/Berlin"));
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/etc/timezone", "Asia/Tokyo\n");
+    /// 
+    /// assert_eq!(SystemInfo::timezone_with_fs(&fs).as_deref(), Some("Asia/Tokyo"));
+    /// ```
+    pub fn timezone_with_fs(fs: &impl FileSystem) -> Option<String> {
+        // /etc/localtime -> /usr/share/zoneinfo/Europe/Berlin
+        let timezone = fs.read_link(Path::new("/etc/localtime")).ok()
+            .and_then(|target| {
+                let target = target.to_string_lossy().into_owned();
+                let (_, timezone) = target.split_once("zoneinfo/")?;
+
+                Some(timezone.trim_start_matches("posix/").trim_start_matches("right/").to_string())
+            })
+            .filter(|timezone| !timezone.is_empty());
+
+        if timezone.is_some() {
+            return timezone;
+        }
+
+        trace!("/etc/localtime is not a zoneinfo symlink, using /etc/timezone");
+
+        let timezone = fs.read_to_string(Path::new("/etc/timezone")).ok()
+            .and_then(|timezone| {
+                timezone.lines()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty() && !line.starts_with('#'))
+                    .map(String::from)
+            });
+
+        if timezone.is_some() {
+            return timezone;
+        }
+
+        envfile::parse(&fs.read_to_string(Path::new("/etc/sysconfig/clock")).ok()?)
+            .remove("ZONE")
+            .filter(|timezone| !timezone.is_empty())
+    }
+}
+
+#[cfg(unix)]
+/// Get hostname using the `gethostname` call
+fn kernel_hostname() -> Option<String> {
+    let mut buf = [0u8; 256];
+
+    // SAFETY: buf is valid for writes of its length
+    let result = unsafe { gethostname(buf.as_mut_ptr().cast(), buf.len()) };
+
+    if result != 0 {
+        return None;
+    }
+
+    let len = buf.iter().position(|byte| *byte == 0).unwrap_or(buf.len());
+
+    let hostname = String::from_utf8_lossy(&buf[..len]).trim().to_string();
+
+    if hostname.is_empty() {
+        return None;
+    }
+
+    Some(hostname)
+}
+
+#[cfg(not(unix))]
+fn kernel_hostname() -> Option<String> {
+    None
+}
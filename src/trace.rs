@@ -0,0 +1,62 @@
+//! Detection steps instrumentation
+//! 
+//! When `trace-hook` feature is enabled every detection step (file opened,
+//! field parsed, fallback used) emits an event. Events are dropped
+//! unless they're forwarded to your own logger using `set_handler`
+//! 
+//! ```
+//! use whatadistro::trace::{self, Event};
+//! 
+//! fn handler(event: &Event) {
+//!     eprintln!("[{}] {}", event.target, event.message);
+//! }
+//! 
+//! trace::set_handler(handler);
+//! 
+//! let _ = whatadistro::identify();
+//! ```
+
+use std::fmt::Arguments;
+use std::sync::RwLock;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Single detection step event
+pub struct Event {
+    /// Module which emitted the event (e.g. `whatadistro::virtualization`)
+    pub target: &'static str,
+
+    /// Human readable event description
+    pub message: String
+}
+
+static HANDLER: RwLock<Option<fn(&Event)>> = RwLock::new(None);
+
+/// Set handler called for every emitted event
+/// 
+/// Replaces previously set handler
+pub fn set_handler(handler: fn(&Event)) {
+    if let Ok(mut current) = HANDLER.write() {
+        *current = Some(handler);
+    }
+}
+
+/// Remove events handler, so events are dropped again
+pub fn clear_handler() {
+    if let Ok(mut current) = HANDLER.write() {
+        *current = None;
+    }
+}
+
+#[doc(hidden)]
+pub fn emit(target: &'static str, message: Arguments) {
+    let handler = HANDLER.read()
+        .ok()
+        .and_then(|handler| *handler);
+
+    if let Some(handler) = handler {
+        handler(&Event {
+            target,
+            message: message.to_string()
+        });
+    }
+}
@@ -0,0 +1,116 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Absolute URL from os-release fields (`HOME_URL`, `SUPPORT_URL`, etc.)
+/// 
+/// Only scheme, host and path are split. This is not the `url`
+/// crate type, use `as_str` to convert it into one
+pub struct Url {
+    url: String,
+    scheme_end: usize,
+    host_start: usize,
+    host_end: usize
+}
+
+impl Url {
+    /// Parse absolute URL (`scheme://host/path`)
+    /// 
+    /// ```
+    /// use whatadistro::Url;
+    /// 
+    /// let url = Url::parse("https://www.debian.org/support").unwrap();
+    /// 
+    /// assert_eq!(url.scheme(), "https");
+    /// assert_eq!(url.host(), "www.debian.org");
+    /// assert_eq!(url.path(), "/support");
+    /// 
+    /// assert!(Url::parse("www.debian.org").is_none());
+    /// ```
+    pub fn parse(url: impl AsRef<str>) -> Option<Self> {
+        let url = url.as_ref().trim();
+
+        let scheme_end = url.find("://")?;
+        let scheme = &url[..scheme_end];
+
+        let is_valid_scheme = scheme.starts_with(|char: char| char.is_ascii_alphabetic())
+            && scheme.chars().all(|char| char.is_ascii_alphanumeric() || "+-.".contains(char));
+
+        if !is_valid_scheme {
+            return None;
+        }
+
+        let host_start = scheme_end + 3;
+
+        let host_end = url[host_start..]
+            .find(['/', '?', '#'])
+            .map(|end| host_start + end)
+            .unwrap_or(url.len());
+
+        // Strip credentials and port from the host
+        let authority = &url[host_start..host_end];
+
+        let host_start = authority.rfind('@')
+            .map(|pos| host_start + pos + 1)
+            .unwrap_or(host_start);
+
+        let host = &url[host_start..host_end];
+
+        // IPv6 hosts are enclosed in square brackets and contain colons
+        let port_start = match host.find(']') {
+            Some(pos) if host.starts_with('[') => host[pos..].find(':').map(|port| pos + port),
+            _ => host.find(':')
+        };
+
+        let host_end = port_start
+            .map(|pos| host_start + pos)
+            .unwrap_or(host_end);
+
+        if host_start == host_end || url.contains(char::is_whitespace) {
+            return None;
+        }
+
+        Some(Self {
+            url: url.to_string(),
+            scheme_end,
+            host_start,
+            host_end
+        })
+    }
+
+    #[inline]
+    /// Get full URL string
+    pub fn as_str(&self) -> &str {
+        &self.url
+    }
+
+    #[inline]
+    /// Get URL scheme (e.g. `https`)
+    pub fn scheme(&self) -> &str {
+        &self.url[..self.scheme_end]
+    }
+
+    #[inline]
+    /// Get URL host (e.g. `www.debian.org`)
+    pub fn host(&self) -> &str {
+        &self.url[self.host_start..self.host_end]
+    }
+
+    /// Get URL path (e.g. `/support`). Empty if URL has no path
+    pub fn path(&self) -> &str {
+        let rest = &self.url[self.host_end..];
+
+        let path_start = rest.find('/').unwrap_or(rest.len());
+        let rest = &rest[path_start..];
+
+        let path_end = rest.find(['?', '#']).unwrap_or(rest.len());
+
+        &rest[..path_end]
+    }
+}
+
+impl Display for Url {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.url)
+    }
+}
@@ -0,0 +1,176 @@
+use std::fmt::Display;
+use std::path::Path;
+
+use crate::{Distro, DistroFamily, FileSystem, RealFs};
+
+/// Directories with desktop session files of the installed display managers
+const SESSION_DIRS: &[&str] = &[
+    "/usr/share/xsessions",
+    "/usr/share/wayland-sessions"
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Purpose of the distro installation
+pub enum DistroVariant {
+    /// Headless server installation
+    Server,
+
+    /// Desktop edition aimed at developers and professionals (Fedora Workstation)
+    Workstation,
+
+    /// Installation with a desktop environment
+    Desktop,
+
+    /// Cloud image
+    Cloud,
+
+    /// Image for IoT and edge devices
+    IoT,
+
+    /// Minimal or container base image
+    Minimal,
+
+    /// Unknown `VARIANT_ID` value
+    Unknown(String)
+}
+
+impl DistroVariant {
+    /// Get variant by its `VARIANT_ID` value
+    /// 
+    /// ```
+    /// use whatadistro::DistroVariant;
+    /// 
+    /// assert_eq!(DistroVariant::from_variant_id("server"), DistroVariant::Server);
+    /// assert_eq!(DistroVariant::from_variant_id("Kinoite"), DistroVariant::Desktop);
+    /// assert_eq!(DistroVariant::from_variant_id("container"), DistroVariant::Minimal);
+    /// assert_eq!(DistroVariant::from_variant_id("lab"), DistroVariant::Unknown(String::from("lab")));
+    /// ```
+    pub fn from_variant_id(variant_id: &str) -> Self {
+        let variant_id = variant_id.trim();
+
+        match variant_id.to_ascii_lowercase().as_str() {
+            "server" | "coreos"     => Self::Server,
+            "workstation"           => Self::Workstation,
+            "cloud"                 => Self::Cloud,
+            "iot" | "edge"          => Self::IoT,
+            "minimal" | "container" => Self::Minimal,
+
+            "desktop" | "kde" | "xfce" | "lxqt" | "mate" | "cinnamon" | "budgie" |
+            "silverblue" | "kinoite" | "sericea" | "onyx" | "cosmic-atomic" => Self::Desktop,
+
+            _ => Self::Unknown(variant_id.to_string())
+        }
+    }
+
+    #[inline]
+    /// Check if the variant is meant to run without a graphical session
+    /// 
+    /// ```
+    /// use whatadistro::DistroVariant;
+    /// 
+    /// assert!(DistroVariant::Server.is_headless());
+    /// assert!(!DistroVariant::Workstation.is_headless());
+    /// ```
+    pub fn is_headless(&self) -> bool {
+        matches!(self, Self::Server | Self::Cloud | Self::IoT | Self::Minimal)
+    }
+}
+
+impl Display for DistroVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Server      => write!(f, "server"),
+            Self::Workstation => write!(f, "workstation"),
+            Self::Desktop     => write!(f, "desktop"),
+            Self::Cloud       => write!(f, "cloud"),
+            Self::IoT         => write!(f, "iot"),
+            Self::Minimal     => write!(f, "minimal"),
+
+            Self::Unknown(variant_id) => write!(f, "{variant_id}")
+        }
+    }
+}
+
+/// Check if dpkg package is installed
+fn dpkg_installed(fs: &impl FileSystem, package: &str) -> bool {
+    fs.exists(&Path::new("/var/lib/dpkg/info").join(format!("{package}.list")))
+}
+
+/// Check if any desktop session is installed
+fn has_desktop_session(fs: &impl FileSystem) -> bool {
+    SESSION_DIRS.iter().any(|dir| {
+        let Ok(entries) = fs.read_dir(Path::new(dir)) else {
+            return false;
+        };
+
+        entries.iter().any(|entry| {
+            entry.extension().map(|extension| extension == "desktop").unwrap_or(false)
+        })
+    })
+}
+
+impl Distro {
+    /// Get variant of the distro installation
+    /// 
+    /// `VARIANT_ID` field is used if it's set. Otherwise Ubuntu
+    /// `ubuntu-server` and `ubuntu-desktop` metapackages and installed
+    /// desktop sessions (`/usr/share/xsessions`) are checked.
+    /// Returns `None` if the variant can't be guessed
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, DistroVariant};
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME="Fedora Linux"
+    ///     ID=fedora
+    ///     VARIANT="Server Edition"
+    ///     VARIANT_ID=server
+    /// "#).unwrap();
+    /// 
+    /// assert_eq!(distro.variant(), Some(DistroVariant::Server));
+    /// ```
+    pub fn variant(&self) -> Option<DistroVariant> {
+        self.variant_with_fs(&RealFs)
+    }
+
+    /// Get variant of the distro installation using files of the given filesystem
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, DistroVariant, MockFs};
+    /// 
+    /// let distro = Distro::from_os_release("NAME=Ubuntu\nID=ubuntu\nID_LIKE=debian").unwrap();
+    /// 
+    /// let fs = MockFs::default()
+    ///     .with_file("/var/lib/dpkg/info/ubuntu-server.list", "/.\n");
+    /// 
+    /// assert_eq!(distro.variant_with_fs(&fs), Some(DistroVariant::Server));
+    /// assert_eq!(distro.variant_with_fs(&MockFs::default()), None);
+    /// ```
+    pub fn variant_with_fs(&self, fs: &impl FileSystem) -> Option<DistroVariant> {
+        if let Some(variant_id) = self.field("VARIANT_ID").filter(|value| !value.trim().is_empty()) {
+            return Some(DistroVariant::from_variant_id(variant_id));
+        }
+
+        if self.family() == DistroFamily::Debian {
+            if dpkg_installed(fs, "ubuntu-server") {
+                trace!("ubuntu-server metapackage is installed");
+
+                return Some(DistroVariant::Server);
+            }
+
+            if dpkg_installed(fs, "ubuntu-desktop") || dpkg_installed(fs, "ubuntu-desktop-minimal") {
+                trace!("ubuntu-desktop metapackage is installed");
+
+                return Some(DistroVariant::Desktop);
+            }
+        }
+
+        if has_desktop_session(fs) {
+            trace!("desktop session is installed");
+
+            return Some(DistroVariant::Desktop);
+        }
+
+        None
+    }
+}
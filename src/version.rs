@@ -0,0 +1,122 @@
+use std::cmp::Ordering;
+
+use crate::{Distro, DistroId};
+
+/// Parse numeric version components (`9.3` -> `[9, 3]`)
+/// 
+/// Non-numeric suffixes of components are ignored (`22.04.3-lts` -> `[22, 4, 3]`)
+pub(crate) fn parse_version(version: &str) -> Option<Vec<u64>> {
+    let components = version.trim()
+        .split('.')
+        .map(|component| {
+            let end = component.find(|char: char| !char.is_ascii_digit())
+                .unwrap_or(component.len());
+
+            component[..end].parse::<u64>().ok()
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    if components.is_empty() {
+        return None;
+    }
+
+    Some(components)
+}
+
+/// Compare only as many components as the constraint version has,
+/// so `9.3` is equal to `9` but less than `9.4`
+fn compare(version: &[u64], constraint: &[u64]) -> Ordering {
+    for (i, expected) in constraint.iter().enumerate() {
+        let actual = version.get(i).copied().unwrap_or(0);
+
+        match actual.cmp(expected) {
+            Ordering::Equal => continue,
+
+            ordering => return ordering
+        }
+    }
+
+    Ordering::Equal
+}
+
+/// Check if version satisfies all the comma separated constraints
+/// (e.g. `>=8, <10`). Returns `None` if constraints are invalid
+pub(crate) fn matches_constraint(version: &[u64], constraints: &str) -> Option<bool> {
+    for constraint in constraints.split(',') {
+        let constraint = constraint.trim();
+
+        if constraint.is_empty() {
+            continue;
+        }
+
+        let (operator, constraint) = [">=", "<=", "==", "!=", ">", "<", "="].iter()
+            .find_map(|operator| constraint.strip_prefix(operator).map(|rest| (*operator, rest)))
+            .unwrap_or(("=", constraint));
+
+        let ordering = compare(version, &parse_version(constraint)?);
+
+        let matches = match operator {
+            ">=" => ordering != Ordering::Less,
+            "<=" => ordering != Ordering::Greater,
+            ">"  => ordering == Ordering::Greater,
+            "<"  => ordering == Ordering::Less,
+            "!=" => ordering != Ordering::Equal,
+
+            _ => ordering == Ordering::Equal
+        };
+
+        if !matches {
+            return Some(false);
+        }
+    }
+
+    Some(true)
+}
+
+impl Distro {
+    /// Compare distro with given one and check its version
+    /// 
+    /// Version is taken from the `VERSION_ID` or `VERSION` fields, or from
+    /// the `PLATFORM_ID` field if they're missing. Constraints are comma
+    /// separated comparisons (`>=`, `<=`, `>`, `<`, `=`, `!=`) and only specified version
+    /// components are compared, so `9.3` satisfies both `=9` and `<9.4`.
+    /// Returns `false` for invalid constraints and unparsable versions
+    /// 
+    /// ```
+    /// use whatadistro::Distro;
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME="Rocky Linux"
+    ///     ID="rocky"
+    ///     ID_LIKE="rhel centos fedora"
+    ///     VERSION_ID="9.3"
+    ///     PLATFORM_ID="platform:el9"
+    /// "#).unwrap();
+    /// 
+    /// assert!(distro.is_similar_versioned("rhel", ">=9"));
+    /// assert!(distro.is_similar_versioned("rhel", ">=8, <10"));
+    /// assert!(!distro.is_similar_versioned("rhel", "=8"));
+    /// assert!(!distro.is_similar_versioned("arch", ">=9"));
+    /// ```
+    pub fn is_similar_versioned<T: Into<DistroId>>(&self, other: T, constraints: &str) -> bool {
+        if !self.is_similar(other) {
+            return false;
+        }
+
+        let version = self.version_number()
+            .and_then(parse_version)
+            .or_else(|| {
+                let major = self.platform_id()?.major_version()?;
+
+                Some(vec![major as u64])
+            });
+
+        let Some(version) = version else {
+            trace!("distro version is unknown, version constraints can't be checked");
+
+            return false;
+        };
+
+        matches_constraint(&version, constraints).unwrap_or(false)
+    }
+}
@@ -0,0 +1,277 @@
+use std::path::Path;
+use std::fmt::Display;
+
+use crate::{SystemInfo, FileSystem, RealFs};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Virtualization technology the system is running on
+pub enum Virtualization {
+    /// No virtualization detected
+    BareMetal,
+
+    /// Linux KVM
+    Kvm,
+
+    /// QEMU in software emulation mode (TCG)
+    Qemu,
+
+    /// Amazon EC2 (Nitro)
+    Amazon,
+
+    /// Google Compute Engine
+    Google,
+
+    /// VMware
+    VMware,
+
+    /// Microsoft Hyper-V
+    HyperV,
+
+    /// Oracle VirtualBox
+    VirtualBox,
+
+    /// Xen
+    Xen,
+
+    /// Bochs
+    Bochs,
+
+    /// Parallels
+    Parallels,
+
+    /// FreeBSD bhyve
+    Bhyve,
+
+    /// Apple Virtualization framework
+    Apple,
+
+    /// Hypervisor is present but couldn't be identified
+    Unknown
+}
+
+impl Virtualization {
+    #[inline]
+    /// Check if the system is running inside a virtual machine
+    pub fn is_virtual(&self) -> bool {
+        self != &Self::BareMetal
+    }
+
+    /// Match DMI vendor / product strings
+    fn from_dmi(value: &str) -> Option<Self> {
+        const VENDORS: &[(&str, Virtualization)] = &[
+            ("KVM",                  Virtualization::Kvm),
+            ("OpenStack",            Virtualization::Kvm),
+            ("KubeVirt",             Virtualization::Kvm),
+            ("Amazon EC2",           Virtualization::Amazon),
+            ("QEMU",                 Virtualization::Qemu),
+            ("VMware",               Virtualization::VMware),
+            ("VMW",                  Virtualization::VMware),
+            ("innotek GmbH",         Virtualization::VirtualBox),
+            ("VirtualBox",           Virtualization::VirtualBox),
+            ("Oracle Corporation",   Virtualization::VirtualBox),
+            ("Xen",                  Virtualization::Xen),
+            ("Bochs",                Virtualization::Bochs),
+            ("Parallels",            Virtualization::Parallels),
+            ("BHYVE",                Virtualization::Bhyve),
+            ("Hyper-V",              Virtualization::HyperV),
+            ("Google",               Virtualization::Google),
+            ("Apple Virtualization", Virtualization::Apple)
+        ];
+
+        VENDORS.iter()
+            .find(|(vendor, _)| value.starts_with(vendor))
+            .map(|(_, virt)| *virt)
+    }
+
+    /// Match hypervisor vendor signature reported by CPUID
+    fn from_cpuid_vendor(vendor: &str) -> Self {
+        match vendor {
+            "KVMKVMKVM"    => Self::Kvm,
+            "TCGTCGTCGTCG" => Self::Qemu,
+            "VMwareVMware" => Self::VMware,
+            "Microsoft Hv" => Self::HyperV,
+            "VBoxVBoxVBox" => Self::VirtualBox,
+            "XenVMMXenVMM" => Self::Xen,
+            "bhyve bhyve " => Self::Bhyve,
+            " lrpepyh  vr" => Self::Parallels,
+            "prl hyperv  " => Self::Parallels,
+
+            _ => Self::Unknown
+        }
+    }
+}
+
+impl Display for Virtualization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BareMetal  => write!(f, "none"),
+            Self::Kvm        => write!(f, "kvm"),
+            Self::Qemu       => write!(f, "qemu"),
+            Self::Amazon     => write!(f, "amazon"),
+            Self::Google     => write!(f, "google"),
+            Self::VMware     => write!(f, "vmware"),
+            Self::HyperV     => write!(f, "microsoft"),
+            Self::VirtualBox => write!(f, "oracle"),
+            Self::Xen        => write!(f, "xen"),
+            Self::Bochs      => write!(f, "bochs"),
+            Self::Parallels  => write!(f, "parallels"),
+            Self::Bhyve      => write!(f, "bhyve"),
+            Self::Apple      => write!(f, "apple"),
+            Self::Unknown    => write!(f, "vm-other")
+        }
+    }
+}
+
+/// Detect virtualization using DMI data
+fn detect_dmi(fs: &impl FileSystem) -> Option<Virtualization> {
+    let dmi = SystemInfo::dmi_with_fs(fs)?;
+
+    let values = [
+        ("product_name", dmi.product_name()),
+        ("sys_vendor", dmi.sys_vendor()),
+        ("board_vendor", dmi.board_vendor()),
+        ("bios_vendor", dmi.bios_vendor()),
+        ("product_version", dmi.product_version())
+    ];
+
+    for (name, value) in values {
+        if let Some(virt) = value.and_then(Virtualization::from_dmi) {
+            trace!("virtualization detected from DMI {name}: {virt}");
+
+            return Some(virt);
+        }
+    }
+
+    // Hyper-V doesn't use its own name in DMI vendors
+    if dmi.sys_vendor() == Some("Microsoft Corporation") && dmi.product_name() == Some("Virtual Machine") {
+        trace!("virtualization detected from DMI: Hyper-V");
+
+        return Some(Virtualization::HyperV);
+    }
+
+    None
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(unused_unsafe)]
+/// Detect virtualization using CPUID hypervisor bit and vendor leaf
+fn detect_cpuid() -> Option<Virtualization> {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::__cpuid;
+
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::__cpuid;
+
+    // SAFETY: CPUID instruction is available on every x86_64 CPU
+    // and on every x86 CPU capable of running modern linux kernels
+    let features = unsafe { __cpuid(1) };
+
+    // Hypervisor present bit
+    if features.ecx & (1 << 31) == 0 {
+        return None;
+    }
+
+    // SAFETY: hypervisor leaf is reserved and always available when hypervisor bit is set
+    let leaf = unsafe { __cpuid(0x4000_0000) };
+
+    let vendor = [leaf.ebx, leaf.ecx, leaf.edx]
+        .iter()
+        .flat_map(|reg| reg.to_le_bytes())
+        .collect::<Vec<u8>>();
+
+    let vendor = String::from_utf8_lossy(&vendor);
+
+    trace!("hypervisor vendor reported by CPUID: {vendor}");
+
+    Some(Virtualization::from_cpuid_vendor(vendor.trim_end_matches('\0')))
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+#[inline]
+fn detect_cpuid() -> Option<Virtualization> {
+    None
+}
+
+/// Detect virtualization using legacy Xen and `/proc/cpuinfo` interfaces
+fn detect_procfs(fs: &impl FileSystem) -> Option<Virtualization> {
+    if let Ok(hypervisor) = fs.read_to_string(Path::new("/sys/hypervisor/type")) {
+        if hypervisor.trim() == "xen" {
+            trace!("virtualization detected from /sys/hypervisor/type: xen");
+
+            return Some(Virtualization::Xen);
+        }
+    }
+
+    let cpuinfo = fs.read_to_string(Path::new("/proc/cpuinfo")).ok()?;
+
+    let has_hypervisor_flag = cpuinfo.lines()
+        .filter(|line| line.starts_with("flags"))
+        .any(|line| line.split_whitespace().any(|flag| flag == "hypervisor"));
+
+    if has_hypervisor_flag {
+        trace!("hypervisor flag found in /proc/cpuinfo");
+
+        return Some(Virtualization::Unknown);
+    }
+
+    None
+}
+
+/// Detect virtualization technology the system is running on
+/// 
+/// Modeled on `systemd-detect-virt`: CPUID hypervisor vendor leaf is checked first,
+/// then DMI vendor strings, and then `/sys/hypervisor` and `/proc/cpuinfo`
+/// 
+/// ```
+/// let virt = whatadistro::detect_virtualization();
+/// 
+/// if virt.is_virtual() {
+///     println!("Running in a virtual machine: {virt}");
+/// }
+/// ```
+pub fn detect_virtualization() -> Virtualization {
+    detect(&RealFs, detect_cpuid())
+}
+
+#[inline]
+/// Detect virtualization technology using files of the given filesystem
+/// 
+/// Works like `detect_virtualization()` but CPUID is not checked
+/// because it describes the current machine
+/// 
+/// ```
+/// use whatadistro::{MockFs, Virtualization};
+/// 
+/// let fs = MockFs::default()
+///     .with_file("/sys/class/dmi/id/sys_vendor", "QEMU\n");
+/// 
+/// assert_eq!(whatadistro::detect_virtualization_with_fs(&fs), Virtualization::Qemu);
+/// assert_eq!(whatadistro::detect_virtualization_with_fs(&MockFs::default()), Virtualization::BareMetal);
+/// ```
+pub fn detect_virtualization_with_fs(fs: &impl FileSystem) -> Virtualization {
+    detect(fs, None)
+}
+
+/// Detect virtualization using CPUID result and the filesystem
+fn detect(fs: &impl FileSystem, cpuid: Option<Virtualization>) -> Virtualization {
+    if let Some(virt) = cpuid.filter(|virt| virt != &Virtualization::Unknown) {
+        // Cloud providers use KVM under the hood, DMI data is more precise here
+        if virt == Virtualization::Kvm {
+            if let Some(dmi @ (Virtualization::Amazon | Virtualization::Google)) = detect_dmi(fs) {
+                return dmi;
+            }
+        }
+
+        return virt;
+    }
+
+    let virt = detect_dmi(fs)
+        .or(cpuid)
+        .or_else(|| detect_procfs(fs));
+
+    if virt.is_none() {
+        trace!("no virtualization detected, falling back to bare metal");
+    }
+
+    virt.unwrap_or(Virtualization::BareMetal)
+}
@@ -0,0 +1,183 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use crate::Distro;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Non-fatal anomaly found while parsing os-release file
+/// 
+/// Line numbers start from 1
+pub enum ParseWarning {
+    /// Key is specified multiple times, the last value is used
+    DuplicateKey {
+        key: String,
+        line: usize
+    },
+
+    /// Unquoted value contains whitespaces (`NAME=Arch Linux`)
+    UnquotedWhitespace {
+        key: String,
+        line: usize
+    },
+
+    /// Unknown escape sequence in the double quoted value, kept as is
+    UnknownEscape {
+        key: String,
+        line: usize,
+        sequence: char
+    },
+
+    /// Quoted value doesn't have closing quote
+    UnterminatedQuote {
+        key: String,
+        line: usize
+    },
+
+    /// Line is not a comment and not a `KEY=value` pair, it's ignored
+    InvalidLine {
+        line: usize
+    }
+}
+
+impl ParseWarning {
+    /// Get number of the line with the anomaly
+    pub fn line(&self) -> usize {
+        match self {
+            Self::DuplicateKey { line, .. } |
+            Self::UnquotedWhitespace { line, .. } |
+            Self::UnknownEscape { line, .. } |
+            Self::UnterminatedQuote { line, .. } |
+            Self::InvalidLine { line } => *line
+        }
+    }
+}
+
+impl Display for ParseWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DuplicateKey { key, line }       => write!(f, "line {line}: duplicate key {key}"),
+            Self::UnquotedWhitespace { key, line } => write!(f, "line {line}: unquoted value of {key} contains whitespaces"),
+            Self::UnterminatedQuote { key, line }  => write!(f, "line {line}: value of {key} doesn't have closing quote"),
+            Self::InvalidLine { line }             => write!(f, "line {line}: invalid line"),
+
+            Self::UnknownEscape { key, line, sequence } => {
+                write!(f, "line {line}: unknown escape sequence \\{sequence} in value of {key}")
+            }
+        }
+    }
+}
+
+/// Find non-fatal anomalies in the os-release file content
+pub(crate) fn lint(content: &str) -> Vec<ParseWarning> {
+    let mut warnings = Vec::new();
+    let mut keys = HashSet::new();
+
+    for (i, line) in content.lines().enumerate() {
+        let line_number = i + 1;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            warnings.push(ParseWarning::InvalidLine { line: line_number });
+
+            continue;
+        };
+
+        let key = key.trim();
+
+        if key.is_empty() || !key.bytes().all(|byte| byte.is_ascii_alphanumeric() || byte == b'_') {
+            warnings.push(ParseWarning::InvalidLine { line: line_number });
+
+            continue;
+        }
+
+        if !keys.insert(key) {
+            warnings.push(ParseWarning::DuplicateKey {
+                key: key.to_string(),
+                line: line_number
+            });
+        }
+
+        let value = value.trim();
+
+        let quote = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => quote,
+
+            _ => {
+                if value.contains(char::is_whitespace) {
+                    warnings.push(ParseWarning::UnquotedWhitespace {
+                        key: key.to_string(),
+                        line: line_number
+                    });
+                }
+
+                continue;
+            }
+        };
+
+        let inner = &value[1..];
+
+        if !inner.ends_with(quote) {
+            warnings.push(ParseWarning::UnterminatedQuote {
+                key: key.to_string(),
+                line: line_number
+            });
+        }
+
+        if quote == '\'' {
+            continue;
+        }
+
+        let mut chars = inner.chars();
+
+        while let Some(char) = chars.next() {
+            if char != '\\' {
+                continue;
+            }
+
+            match chars.next() {
+                Some('"' | '\\' | '$' | '`') => (),
+
+                // Trailing backslash is kept as is
+                None => (),
+
+                Some(sequence) => warnings.push(ParseWarning::UnknownEscape {
+                    key: key.to_string(),
+                    line: line_number,
+                    sequence
+                })
+            }
+        }
+    }
+
+    warnings
+}
+
+impl Distro {
+    #[inline]
+    /// Get non-fatal anomalies found while parsing the distro file
+    /// 
+    /// ```
+    /// use whatadistro::{Distro, ParseWarning};
+    /// 
+    /// let distro = Distro::from_os_release(r#"
+    ///     NAME=Arch Linux
+    ///     ID=arch
+    ///     ID=archlinux
+    ///     HOME_URL="https:\/\/archlinux.org"
+    /// "#).unwrap();
+    /// 
+    /// assert_eq!(distro.warnings(), &[
+    ///     ParseWarning::UnquotedWhitespace { key: String::from("NAME"), line: 2 },
+    ///     ParseWarning::DuplicateKey { key: String::from("ID"), line: 4 },
+    ///     ParseWarning::UnknownEscape { key: String::from("HOME_URL"), line: 5, sequence: '/' },
+    ///     ParseWarning::UnknownEscape { key: String::from("HOME_URL"), line: 5, sequence: '/' }
+    /// ]);
+    /// ```
+    pub fn warnings(&self) -> &[ParseWarning] {
+        &self.warnings
+    }
+}
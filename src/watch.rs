@@ -0,0 +1,220 @@
+use std::ffi::CString;
+use std::fs::File;
+use std::io::Read;
+use std::os::raw::{c_char, c_int};
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::io::{AsRawFd, FromRawFd};
+use std::path::Path;
+
+use crate::{Distro, identify};
+use crate::confidence::LEGACY_FILES;
+
+const IN_CLOEXEC: c_int = 0o2000000;
+
+const IN_MODIFY: u32      = 0x0000_0002;
+const IN_CLOSE_WRITE: u32 = 0x0000_0008;
+const IN_MOVED_TO: u32    = 0x0000_0080;
+const IN_CREATE: u32      = 0x0000_0100;
+const IN_DELETE: u32      = 0x0000_0200;
+
+/// Size of the `inotify_event` struct without the name
+const EVENT_HEADER_SIZE: usize = 16;
+
+/// Directories containing os-release, lsb-release and legacy release files
+/// 
+/// Directories are watched instead of the files themselves because
+/// package managers replace os-release files by renaming new ones over them
+const WATCH_DIRS: &[&str] = &["/etc", "/usr/lib"];
+
+/// Drop-in directories merged over the os-release files
+/// 
+/// They're watched if they exist, or when they're created later
+const DROP_IN_DIRS: &[&str] = &["/etc/os-release.d", "/usr/lib/os-release.d"];
+
+/// Names of the files in `WATCH_DIRS` which are read by `identify()`
+const WATCHED_FILES: &[&str] = &["os-release", "os-release.d", "lsb-release"];
+
+const WATCH_MASK: u32 = IN_MODIFY | IN_CLOSE_WRITE | IN_MOVED_TO | IN_CREATE | IN_DELETE;
+
+extern "C" {
+    fn inotify_init1(flags: c_int) -> c_int;
+    fn inotify_add_watch(fd: c_int, pathname: *const c_char, mask: u32) -> c_int;
+}
+
+/// Inotify instance watching files read by the distro identification
+struct Inotify {
+    file: File,
+
+    /// Watch descriptors of the drop-in directories
+    drop_ins: Vec<c_int>
+}
+
+impl Inotify {
+    /// Create inotify instance watching identification inputs
+    fn new() -> std::io::Result<Self> {
+        // SAFETY: inotify_init1 doesn't take any pointers
+        let fd = unsafe { inotify_init1(IN_CLOEXEC) };
+
+        if fd < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        let mut inotify = Self {
+            // SAFETY: fd is a valid file descriptor owned only by this File
+            file: unsafe { File::from_raw_fd(fd) },
+            drop_ins: Vec::new()
+        };
+
+        let watched = WATCH_DIRS.iter()
+            .filter(|dir| inotify.add_watch(dir).is_some())
+            .count();
+
+        if watched == 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        inotify.watch_drop_ins();
+
+        Ok(inotify)
+    }
+
+    /// Watch given directory, returning watch descriptor
+    fn add_watch(&self, dir: &str) -> Option<c_int> {
+        let path = CString::new(Path::new(dir).as_os_str().as_bytes()).ok()?;
+
+        // SAFETY: path is a valid nul-terminated string living until the end of the call
+        let wd = unsafe {
+            inotify_add_watch(self.file.as_raw_fd(), path.as_ptr(), WATCH_MASK)
+        };
+
+        if wd < 0 {
+            return None;
+        }
+
+        trace!("watching {dir} for distro identification changes");
+
+        Some(wd)
+    }
+
+    /// Watch existing drop-in directories
+    /// 
+    /// Watching the same directory again returns the same descriptor
+    fn watch_drop_ins(&mut self) {
+        for dir in DROP_IN_DIRS {
+            if let Some(wd) = self.add_watch(dir) {
+                if !self.drop_ins.contains(&wd) {
+                    self.drop_ins.push(wd);
+                }
+            }
+        }
+    }
+
+    /// Wait for a change of the files read by the distro identification
+    fn wait(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+        loop {
+            let read = self.file.read(buf)?;
+
+            let mut changed = false;
+            let mut drop_ins_created = false;
+
+            for (wd, name) in events(&buf[..read]) {
+                if self.drop_ins.contains(&wd) {
+                    changed = true;
+                }
+
+                else if WATCHED_FILES.iter().any(|file| file.as_bytes() == name) || is_legacy_file(name) {
+                    drop_ins_created |= name == b"os-release.d";
+                    changed = true;
+                }
+            }
+
+            if drop_ins_created {
+                self.watch_drop_ins();
+            }
+
+            if changed {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Check if the file name is one of the legacy release files in `/etc`
+fn is_legacy_file(name: &[u8]) -> bool {
+    LEGACY_FILES.iter().any(|(path, _, _)| {
+        Path::new(path).file_name().map(|file| file.as_bytes() == name).unwrap_or(false)
+    })
+}
+
+/// Iterate over watch descriptors and file names of the inotify events
+fn events(events: &[u8]) -> impl Iterator<Item = (c_int, &[u8])> {
+    let mut offset = 0;
+
+    std::iter::from_fn(move || {
+        if offset + EVENT_HEADER_SIZE > events.len() {
+            return None;
+        }
+
+        let mut wd = [0; 4];
+        let mut len = [0; 4];
+
+        wd.copy_from_slice(&events[offset..offset + 4]);
+        len.copy_from_slice(&events[offset + 12..offset + 16]);
+
+        let len = u32::from_ne_bytes(len) as usize;
+        let name_start = offset + EVENT_HEADER_SIZE;
+        let name_end = name_start.saturating_add(len).min(events.len());
+
+        let name = &events[name_start..name_end];
+        let name = name.split(|byte| *byte == 0).next().unwrap_or_default();
+
+        offset = name_end;
+
+        Some((c_int::from_ne_bytes(wd), name))
+    })
+}
+
+impl Distro {
+    /// Watch os-release files for changes
+    /// 
+    /// os-release files, their drop-in directories, lsb-release and legacy
+    /// release files are watched. Package databases are not watched
+    /// because they change on every package installation.
+    /// Blocks current thread and calls `callback` with the newly identified distro
+    /// every time identification changes (e.g. after a major distro upgrade or an ostree rebase).
+    /// Watching stops when `callback` returns `false` or when inotify fails
+    /// 
+    /// ```no_run
+    /// std::thread::spawn(|| {
+    ///     whatadistro::Distro::watch(|distro| {
+    ///         println!("Distro changed: {}", distro.name());
+    /// 
+    ///         true
+    ///     })
+    /// });
+    /// ```
+    pub fn watch(mut callback: impl FnMut(&Distro) -> bool) -> std::io::Result<()> {
+        let mut inotify = Inotify::new()?;
+        let mut current = identify();
+
+        let mut buf = [0; 4096];
+
+        loop {
+            inotify.wait(&mut buf)?;
+
+            let distro = identify();
+
+            trace!("release files changed, identifying distro again");
+
+            if distro != current {
+                if let Some(distro) = &distro {
+                    if !callback(distro) {
+                        return Ok(());
+                    }
+                }
+
+                current = distro;
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use whatadistro::{Distro, DistroId, DistroFamily, FedoraVariant, DistroVariant, ReleaseModel};
+
+/// Real-world os-release files and distros they must be classified as
+const FIXTURES: &[(&str, DistroId, DistroFamily)] = &[
+    ("opensuse-tumbleweed", DistroId::OpenSUSE, DistroFamily::RedHat),
+    ("opensuse-leap",       DistroId::OpenSUSE, DistroFamily::RedHat),
+    ("pop",                 DistroId::Ubuntu,   DistroFamily::Debian),
+    ("lmde",                DistroId::LMDE,     DistroFamily::Debian),
+    ("manjaro-arm",         DistroId::Manjaro,  DistroFamily::Arch),
+    ("devuan",              DistroId::Devuan,   DistroFamily::Debian),
+    ("mx",                  DistroId::MXLinux,  DistroFamily::Debian),
+    ("antix",               DistroId::AntiX,    DistroFamily::Debian),
+    ("kali",                DistroId::Kali,     DistroFamily::Debian),
+    ("fedora-coreos",       DistroId::Fedora,   DistroFamily::RedHat),
+    ("fedora-kinoite",      DistroId::Fedora,   DistroFamily::RedHat)
+];
+
+fn fixture(name: &str) -> Distro {
+    let path = format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"));
+
+    let content = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("failed to read {path}: {err}"));
+
+    Distro::from_os_release(&content)
+        .unwrap_or_else(|| panic!("failed to parse {path}"))
+}
+
+#[test]
+fn fixtures_are_classified() {
+    for (name, id, family) in FIXTURES {
+        let distro = fixture(name);
+
+        assert_eq!(distro.id(), id, "{name} is parsed into wrong distro");
+        assert_eq!(distro.id().family(), *family, "{name} belongs to wrong family");
+    }
+}
+
+#[test]
+fn best_match_follows_id_like() {
+    let candidates = [DistroId::Fedora, DistroId::Ubuntu, DistroId::Debian];
+
+    // Pop!_OS is recognized as Ubuntu
+    assert_eq!(fixture("pop").best_match(&candidates), Some(DistroId::Ubuntu));
+
+    // LMDE is based on Debian, not Ubuntu like the regular Mint
+    assert_eq!(fixture("lmde").best_match(&candidates), Some(DistroId::Debian));
+
+    // ID_LIKE entries are preferred over other family members
+    assert_eq!(fixture("manjaro-arm").best_match(&[DistroId::SteamOS, DistroId::Arch]), Some(DistroId::Arch));
+    assert_eq!(fixture("manjaro-arm").best_match(&candidates), None);
+}
+
+#[test]
+fn ids_are_normalized() {
+    for (id, expected) in [
+        ("opensuse_tumbleweed",      DistroId::OpenSUSE),
+        ("openSUSE-Tumbleweed",      DistroId::OpenSUSE),
+        ("manjaro_arm",              DistroId::Manjaro),
+        ("LinuxMint",                DistroId::Mint),
+        ("linuxmint_debian_edition", DistroId::LMDE),
+        ("MXLinux",                  DistroId::MXLinux),
+        ("POP",                      DistroId::Ubuntu)
+    ] {
+        assert_eq!(DistroId::from(id), expected, "{id} is parsed into wrong distro");
+    }
+}
+
+#[test]
+fn unknown_ids_are_kept() {
+    for id in ["void", "opensuse-", "pop-os", "arch_linux"] {
+        assert_eq!(DistroId::from(id).as_str(), id);
+        assert_eq!(DistroId::from(id).code(), 0);
+    }
+}
+
+#[test]
+fn fedora_variants_are_recognized() {
+    let fixtures = [
+        ("fedora-coreos", FedoraVariant::CoreOS, DistroVariant::Server),
+        ("fedora-kinoite", FedoraVariant::Kinoite, DistroVariant::Desktop)
+    ];
+
+    for (name, variant, distro_variant) in fixtures {
+        let distro = fixture(name);
+
+        assert_eq!(distro.fedora_variant(), Some(variant), "{name} has wrong variant");
+        assert_eq!(distro.variant(), Some(distro_variant), "{name} has wrong distro variant");
+        assert_eq!(distro.release_model(), ReleaseModel::ImageBased, "{name} has wrong release model");
+    }
+}
+
+#[test]
+fn ids_match_globs() {
+    let distro = Distro::from_os_release("NAME=\"RHEL Workstation\"\nID=rhel-workstation\nID_LIKE=\"fedora\"").unwrap();
+
+    for pattern in ["*", "rhel-*", "RHEL*", "*work*", "rhel-w?rkstation", "fed*", "**a"] {
+        assert!(distro.matches_glob(pattern), "{pattern} doesn't match");
+    }
+
+    for pattern in ["", "rhel", "rhel-?", "*-server", "fedora?", "?rhel*"] {
+        assert!(!distro.matches_glob(pattern), "{pattern} matches");
+    }
+}
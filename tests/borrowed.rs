@@ -0,0 +1,131 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use whatadistro::{Distro, DistroRef, DistroId};
+
+/// System allocator counting allocations of the current thread
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+/// Count allocations made by the function on the current thread
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+
+    f();
+
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn borrowed_matches_owned() {
+    let dir = format!("{}/tests/fixtures", env!("CARGO_MANIFEST_DIR"));
+
+    for entry in std::fs::read_dir(&dir).unwrap() {
+        let path = entry.unwrap().path();
+        let content = std::fs::read_to_string(&path).unwrap();
+
+        let owned = Distro::from_os_release(&content).unwrap();
+        let borrowed = DistroRef::from_os_release(&content).unwrap();
+
+        assert_eq!(borrowed.name(), owned.name(), "{path:?} name differs");
+        assert_eq!(&borrowed.id(), owned.id(), "{path:?} id differs");
+        assert_eq!(borrowed.version_id().as_deref(), owned.version_id(), "{path:?} version differs");
+
+        for (key, value) in borrowed.fields() {
+            assert_eq!(owned.field(key), borrowed.field(key).as_deref(), "{path:?} {key} differs");
+            assert!(owned.field(key).is_some(), "{path:?} {key}={value} is missing");
+        }
+
+        assert_eq!(Distro::from(borrowed), owned, "{path:?} conversion differs");
+    }
+}
+
+#[test]
+fn escapes_are_unquoted() {
+    let distro = DistroRef::from_os_release("NAME=\"Test \\\"OS\\\"\"\nID=test").unwrap();
+
+    assert_eq!(distro.name(), "Test \"OS\"");
+    assert_eq!(distro.field("NAME").as_deref(), Some("Test \"OS\""));
+}
+
+#[test]
+fn last_value_wins() {
+    let distro = DistroRef::from_os_release("NAME=First\nID=test\nNAME=Second").unwrap();
+
+    assert_eq!(distro.name(), "Second");
+    assert_eq!(distro.field("NAME").as_deref(), Some("Second"));
+    assert_eq!(DistroRef::from_os_release("ID=test"), None);
+}
+
+#[test]
+fn similarity_matches_owned() {
+    let dir = format!("{}/tests/fixtures", env!("CARGO_MANIFEST_DIR"));
+
+    let mut others = DistroId::ALL.to_vec();
+
+    others.push(DistroId::from("nobara"));
+    others.push(DistroId::from("Nobara"));
+
+    for entry in std::fs::read_dir(&dir).unwrap() {
+        let path = entry.unwrap().path();
+        let content = std::fs::read_to_string(&path).unwrap();
+
+        let owned = Distro::from_os_release(&content).unwrap();
+        let borrowed = DistroRef::from_os_release(&content).unwrap();
+
+        for other in &others {
+            assert_eq!(borrowed.is_similar(other), owned.is_similar(other), "{path:?} similarity to {other:?} differs");
+        }
+    }
+
+    let content = "NAME=Nobara\nID=nobara\nID_LIKE=\"fedora unknown\"";
+
+    let owned = Distro::from_os_release(content).unwrap();
+    let borrowed = DistroRef::from_os_release(content).unwrap();
+
+    for other in &others {
+        assert_eq!(borrowed.is_similar(other), owned.is_similar(other), "similarity to {other:?} differs");
+    }
+}
+
+#[test]
+fn similarity_does_not_allocate() {
+    let content = "NAME=Nobara\nID=nobara\nID_LIKE=\"fedora unknown\"";
+    let distro = DistroRef::from_os_release(content).unwrap();
+
+    let unknown = DistroId::from("unknown");
+    let other = DistroId::from("other");
+
+    assert_eq!(allocations(|| {
+        assert!(distro.is_similar(DistroId::Fedora));
+        assert!(distro.is_similar(&unknown));
+        assert!(!distro.is_similar(&other));
+        assert!(!distro.is_similar(DistroId::RHEL));
+        assert!(distro.raw_similar_ids().eq(["fedora", "unknown"]));
+    }), 0);
+
+    let distro = DistroRef::from_os_release("NAME=\"Linux Mint\"\nID=linuxmint").unwrap();
+
+    assert_eq!(allocations(|| {
+        assert_eq!(distro.id(), DistroId::Mint);
+        assert!(distro.is_similar(DistroId::Ubuntu));
+    }), 0);
+}
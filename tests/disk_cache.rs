@@ -0,0 +1,30 @@
+use whatadistro::{identify, identify_with_disk_cache, clear_disk_cache};
+
+#[test]
+fn cache_round_trip() {
+    let dir = std::env::temp_dir().join(format!("whatadistro-cache-{}", std::process::id()));
+
+    std::env::set_var("XDG_CACHE_HOME", &dir);
+
+    let expected = identify();
+
+    let summary = |distro: Option<whatadistro::Distro>| distro.map(|distro| {
+        (distro.id().clone(), distro.name().to_string(), distro.version().map(String::from), distro.confidence())
+    });
+
+    // First call writes the cache, second one reads it
+    assert_eq!(summary(identify_with_disk_cache()), summary(expected.clone()));
+
+    if expected.is_some() {
+        assert!(dir.join("whatadistro.cache").exists());
+    }
+
+    assert_eq!(summary(identify_with_disk_cache()), summary(expected));
+
+    clear_disk_cache().unwrap();
+    clear_disk_cache().unwrap();
+
+    assert!(!dir.join("whatadistro.cache").exists());
+
+    let _ = std::fs::remove_dir_all(dir);
+}
@@ -0,0 +1,20 @@
+use whatadistro::Distro;
+
+#[test]
+fn upgrade_targets_have_eol_dates() {
+    for (name, id, version) in [("Ubuntu", "ubuntu", "16.04"), ("Debian", "debian", "9"), ("Fedora", "fedora", "36")] {
+        let mut version = version.to_string();
+
+        loop {
+            let distro = Distro::from_os_release(&format!("NAME={name}\nID={id}\nVERSION_ID=\"{version}\"")).unwrap();
+
+            assert!(distro.eol_date().is_some(), "{name} {version} has no end of life date");
+
+            let Some(upgrade) = distro.suggested_upgrade() else {
+                break;
+            };
+
+            version = upgrade.version_id().to_string();
+        }
+    }
+}
@@ -0,0 +1,51 @@
+#![cfg(all(unix, feature = "detect-exec", feature = "system-info"))]
+
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use whatadistro::Runtime;
+
+/// Install fake `python3` command printing its version after the delay
+fn fake_python(delay: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("whatadistro-exec-{}", std::process::id()));
+    let script = dir.join("python3");
+
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(&script, format!("#!/bin/sh\nsleep {delay}\necho 'Python 3.12.4'\n")).unwrap();
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    dir
+}
+
+#[test]
+fn slow_commands_respect_time_budget() {
+    let dir = fake_python("0.5");
+
+    // Only one test lives in this binary, so PATH can be changed safely
+    std::env::set_var("PATH", format!("{}:{}", dir.display(), std::env::var("PATH").unwrap_or_default()));
+
+    assert_eq!(Runtime::Python3.detect_version().as_deref(), Some("3.12.4"));
+    assert_eq!(whatadistro::without_exec(|| Runtime::Python3.detect_version()), None);
+
+    let started = Instant::now();
+
+    let version = whatadistro::with_timeout(Duration::from_millis(100), || {
+        Runtime::Python3.detect_version()
+    });
+
+    assert_eq!(version, None);
+    assert!(started.elapsed() < Duration::from_millis(400), "command wasn't killed in time");
+
+    // Exhausted budget doesn't spawn commands at all
+    let started = Instant::now();
+
+    let version = whatadistro::with_timeout(Duration::ZERO, || {
+        Runtime::Python3.detect_version()
+    });
+
+    assert_eq!(version, None);
+    assert!(started.elapsed() < Duration::from_millis(50));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,72 @@
+//! Check that every combination of the granular features builds
+//! 
+//! Run with `cargo test --test feature_matrix -- --ignored`
+
+use std::process::Command;
+
+/// Granular features the combinations are made of
+const FEATURES: &[&str] = &[
+    "detect-exec",
+    "packages",
+    "system-info",
+    "desktop",
+    "containers",
+    "audit"
+];
+
+#[test]
+fn capabilities_report_manifest_features() {
+    let manifest = include_str!("../Cargo.toml");
+
+    let features = manifest.split("[features]")
+        .nth(1)
+        .and_then(|features| features.split("\n[").next())
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| line.split_once(" = ["))
+        .map(|(name, _)| name.trim())
+        .filter(|name| *name != "default")
+        .collect::<Vec<_>>();
+
+    for feature in FEATURES {
+        assert!(features.contains(feature), "{feature} is missing in Cargo.toml");
+    }
+
+    for feature in whatadistro::capabilities().features() {
+        assert!(features.contains(&feature), "{feature} is not a cargo feature");
+    }
+
+    assert_eq!(whatadistro::capabilities().supports_exec(), cfg!(feature = "detect-exec"));
+}
+
+#[test]
+#[ignore = "runs cargo check for every features combination"]
+fn feature_combinations_build() {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let target_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/target/feature-matrix");
+
+    let mut failed = Vec::new();
+
+    for mask in 0..1_u32 << FEATURES.len() {
+        let features = FEATURES.iter()
+            .enumerate()
+            .filter(|(i, _)| mask & (1 << i) != 0)
+            .map(|(_, feature)| *feature)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let status = Command::new(&cargo)
+            .args(["check", "--quiet", "--all-targets", "--no-default-features"])
+            .args(["--features", &features, "--target-dir", target_dir])
+            .env("RUSTFLAGS", "-D warnings")
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .status()
+            .unwrap_or_else(|err| panic!("failed to run cargo: {err}"));
+
+        if !status.success() {
+            failed.push(features);
+        }
+    }
+
+    assert!(failed.is_empty(), "failed to build with features: {failed:?}");
+}
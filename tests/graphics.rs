@@ -0,0 +1,37 @@
+#![cfg(feature = "desktop")]
+
+use whatadistro::{Distro, GpuVendor, GraphicsApi};
+
+/// Ubuntu based os-release files and expected NVIDIA driver packages
+const UBUNTU_RELEASES: &[(&str, &str, &str)] = &[
+    ("NAME=Ubuntu\nID=ubuntu\nVERSION_ID=\"24.04\"\nVERSION_CODENAME=noble\nUBUNTU_CODENAME=noble", "nvidia-driver-550", "libnvidia-gl-550"),
+    ("NAME=Ubuntu\nID=ubuntu\nVERSION_ID=\"22.04\"\nVERSION_CODENAME=jammy\nUBUNTU_CODENAME=jammy", "nvidia-driver-535", "libnvidia-gl-535"),
+    ("NAME=Ubuntu\nID=ubuntu\nVERSION_ID=\"20.04\"\nVERSION_CODENAME=focal\nUBUNTU_CODENAME=focal", "nvidia-driver-535", "libnvidia-gl-535"),
+    ("NAME=Ubuntu\nID=ubuntu\nVERSION_ID=\"18.04\"\nVERSION_CODENAME=bionic\nUBUNTU_CODENAME=bionic", "nvidia-driver-470", "libnvidia-gl-470"),
+
+    // Linux Mint uses its own codenames, Ubuntu base is set in UBUNTU_CODENAME
+    ("NAME=\"Linux Mint\"\nID=linuxmint\nVERSION_ID=\"22\"\nVERSION_CODENAME=wilma\nUBUNTU_CODENAME=noble", "nvidia-driver-550", "libnvidia-gl-550"),
+    ("NAME=\"Linux Mint\"\nID=linuxmint\nVERSION_ID=\"21.3\"\nVERSION_CODENAME=virginia\nUBUNTU_CODENAME=jammy", "nvidia-driver-535", "libnvidia-gl-535"),
+
+    // Unknown releases use the newest branch
+    ("NAME=Ubuntu\nID=ubuntu\nVERSION_ID=\"24.10\"\nVERSION_CODENAME=oracular\nUBUNTU_CODENAME=oracular", "nvidia-driver-550", "libnvidia-gl-550"),
+    ("NAME=Ubuntu\nID=ubuntu", "nvidia-driver-550", "libnvidia-gl-550")
+];
+
+#[test]
+fn ubuntu_nvidia_driver_depends_on_release() {
+    for (os_release, driver, libs) in UBUNTU_RELEASES {
+        let distro = Distro::from_os_release(os_release).unwrap();
+
+        assert_eq!(distro.graphics_driver_package(GpuVendor::Nvidia), Some(*driver), "{os_release}");
+
+        for api in [GraphicsApi::Vulkan, GraphicsApi::OpenGL] {
+            let packages = distro.graphics_packages(api, GpuVendor::Nvidia, true);
+
+            assert!(packages.contains(&libs.to_string()), "{os_release}: {packages:?}");
+            assert!(packages.contains(&format!("{libs}:i386")), "{os_release}: {packages:?}");
+        }
+
+        assert!(!distro.graphics_packages(GraphicsApi::Vulkan, GpuVendor::Amd, false).contains(&libs.to_string()));
+    }
+}
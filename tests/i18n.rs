@@ -0,0 +1,18 @@
+#![cfg(feature = "i18n")]
+
+use whatadistro::DistroFamily;
+
+#[test]
+fn family_names_are_translated() {
+    for family in DistroFamily::ALL {
+        assert_eq!(family.localized_name("en_GB"), family.name());
+        assert_eq!(family.localized_name("ja_JP"), family.name(), "{family:?} unsupported language is not English");
+
+        for locale in ["de_DE.UTF-8", "fr-FR", "ru_RU", "zh_CN.UTF-8"] {
+            assert_ne!(family.localized_name(locale), family.name(), "{family:?} is not translated to {locale}");
+        }
+    }
+
+    assert_eq!(DistroFamily::Arch.localized_name("de-AT"), "Arch-basiert");
+    assert_eq!(DistroFamily::RedHat.localized_name("ru_RU.UTF-8@cyrillic"), "на базе Red Hat");
+}
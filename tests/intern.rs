@@ -0,0 +1,50 @@
+use whatadistro::OtherId;
+
+/// Check if two ids share the same heap allocation
+fn shared(a: &OtherId, b: &OtherId) -> bool {
+    a.as_str().as_ptr() == b.as_str().as_ptr()
+}
+
+#[test]
+fn equal_ids_are_shared() {
+    let a = OtherId::new("void");
+    let b = OtherId::new("void");
+
+    assert_eq!(a, b);
+    assert!(shared(&a, &b));
+    assert!(!shared(&a, &OtherId::new("voidlinux")));
+}
+
+#[test]
+fn interning_stops_at_cap() {
+    // Run in a fresh thread to start from an empty table
+    std::thread::spawn(|| {
+        let first = OtherId::new("id-0");
+
+        for i in 1..OtherId::MAX_INTERNED {
+            OtherId::new(&format!("id-{i}"));
+        }
+
+        // Ids interned before the cap are still shared
+        assert!(shared(&first, &OtherId::new("id-0")));
+
+        // New ids are allocated separately but still compare equal
+        let a = OtherId::new("after-cap");
+        let b = OtherId::new("after-cap");
+
+        assert_eq!(a, b);
+        assert!(!shared(&a, &b));
+    }).join().unwrap();
+}
+
+#[test]
+fn threads_intern_separately() {
+    let a = OtherId::new("nobara");
+
+    let b = std::thread::spawn(|| OtherId::new("nobara"))
+        .join()
+        .unwrap();
+
+    assert_eq!(a, b);
+    assert!(!shared(&a, &b));
+}
@@ -0,0 +1,143 @@
+use whatadistro::{Distro, DistroId, AnsiColor, Cpe, Date};
+use whatadistro::{cmdline, envfile, security};
+
+#[cfg(feature = "desktop")]
+use whatadistro::{GraphicsApi, GpuVendor};
+
+#[cfg(feature = "system-info")]
+use whatadistro::MountEntry;
+
+#[cfg(feature = "packages")]
+use whatadistro::repos;
+
+/// Tricky fragments random inputs are built from
+const FRAGMENTS: &[&str] = &[
+    "ID=", "ID_LIKE=", "NAME=", "VERSION=", "VERSION_ID=", "VERSION_CODENAME=", "PLATFORM_ID=",
+    "CPE_NAME=", "SUPPORT_END=", "ANSI_COLOR=", "VARIANT_ID=", "DISTRIB_ID=", "DISTRIB_RELEASE=",
+    "\"", "'", "\\", "$", "`", "=", "\n", "\r", "\t", " ", "#", "(", ")", ",", ":", ";", ".", "-", "_",
+    "[", "]", "*", "\0", "é", "🦀", "\u{FFFD}", "0", "9", "18446744073709551616", "-1", "1.", ".1",
+    "arch", "debian", "fedora", "ubuntu", "opensuse-leap", "cpe:/o:", "cpe:2.3:o:", "platform:el",
+    "deb ", "Types: deb", "URIs: ", "Suites: ", "[options]", "[core]", "Include = ", "baseurl=",
+    "enabled=", "SELINUX=", "SELINUXTYPE=", "38;2;", "1;32", "quiet", "root=", "\"--\""
+];
+
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        self.0
+    }
+
+    fn string(&mut self) -> String {
+        let len = self.next() % 48;
+
+        (0..len)
+            .map(|_| FRAGMENTS[(self.next() % FRAGMENTS.len() as u64) as usize])
+            .collect()
+    }
+
+    fn bytes(&mut self) -> Vec<u8> {
+        let len = self.next() % 256;
+
+        (0..len).map(|_| self.next() as u8).collect()
+    }
+}
+
+#[test]
+fn random_inputs_never_panic() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+    for i in 0..5_000 {
+        let content = if i % 4 == 0 {
+            String::from_utf8_lossy(&rng.bytes()).into_owned()
+        }
+
+        else {
+            rng.string()
+        };
+
+        if let Some(distro) = Distro::from_os_release(&content) {
+            let _ = distro.name();
+            let _ = distro.similar_ids();
+            let _ = distro.vendor_fields().count();
+            let _ = distro.cpe();
+            let _ = distro.support_end();
+            let _ = distro.codename();
+            let _ = distro.release_channel();
+            let _ = distro.eol_date();
+            let _ = distro.warnings();
+            let _ = distro.version_number();
+            let _ = distro.release_model();
+            let _ = distro.family();
+            let _ = distro.platform_id();
+            let _ = distro.is_similar_versioned(distro.id().clone(), &content);
+            let _ = distro.matches_glob(&content);
+
+            #[cfg(feature = "desktop")]
+            let _ = distro.graphics_packages(GraphicsApi::OpenGL, GpuVendor::Nvidia, true);
+        }
+
+        let _ = Distro::from_os_release_bytes(&rng.bytes());
+        let _ = Distro::from_lsb_release(&content);
+        let _ = DistroId::from(&content);
+        let _ = AnsiColor::parse(&content);
+        let _ = Cpe::parse(&content);
+        let _ = Date::parse(&content);
+        let _ = envfile::parse(&content);
+        let _ = cmdline::Cmdline::parse(&content);
+        let _ = security::SelinuxStatus::from_files(Some(&content), Some(&content));
+
+        #[cfg(feature = "packages")]
+        {
+            let _ = repos::parse_apt_sources(&content);
+            let _ = repos::parse_apt_deb822(&content);
+            let _ = repos::parse_repo_file(&content);
+            let _ = repos::parse_pacman_conf(&content);
+        }
+
+        #[cfg(feature = "system-info")]
+        let _ = MountEntry::from_mounts(&content, "/etc");
+
+        #[cfg(feature = "audit")]
+        let _ = whatadistro::audit::parse_versioned(&content);
+    }
+}
+
+#[test]
+fn long_lines_are_skipped() {
+    let content = format!("NAME=Debian\nID=debian\nVERSION_ID={}\n", "1".repeat(1024 * 1024));
+
+    let distro = Distro::from_os_release(&content).unwrap();
+
+    assert_eq!(distro.id(), &DistroId::Debian);
+    assert_eq!(distro.version_id(), None);
+}
+
+#[test]
+fn embedded_nuls_cut_values() {
+    let distro = Distro::from_os_release_bytes(b"NAME=Arch\0garbage\nID=arch\n\0\0\0").unwrap();
+
+    assert_eq!(distro.id(), &DistroId::Arch);
+    assert_eq!(distro.name(), "Arch");
+}
+
+#[test]
+fn invalid_keys_are_skipped() {
+    let distro = Distro::from_os_release("NAME=Fedora\nID=fedora\n\0KEY=value\nexport PATH=/bin\n=empty").unwrap();
+
+    assert_eq!(distro.field("\0KEY"), None);
+    assert_eq!(distro.field("export PATH"), None);
+    assert_eq!(distro.field(""), None);
+}
+
+#[test]
+fn invalid_utf8_is_replaced() {
+    let distro = Distro::from_os_release_bytes(b"NAME=\"\xc3\x28 Linux\"\nID=\xfe\xff").unwrap();
+
+    assert_eq!(distro.name(), "\u{FFFD}( Linux");
+    assert_eq!(distro.id(), &DistroId::Other("\u{FFFD}\u{FFFD}".into()));
+}
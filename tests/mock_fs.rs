@@ -0,0 +1,204 @@
+use std::path::PathBuf;
+
+use whatadistro::{MockFs, FileSystem, DistroId, Confidence, identify_with_fs};
+
+#[test]
+fn os_release_is_preferred() {
+    let fs = MockFs::default()
+        .with_file("/etc/os-release", "NAME=Debian\nID=debian")
+        .with_file("/usr/lib/os-release", "NAME=Arch\nID=arch")
+        .with_file("/etc/arch-release", "");
+
+    let distro = identify_with_fs(&fs).unwrap();
+
+    assert_eq!(distro.id(), &DistroId::Debian);
+    assert_eq!(distro.confidence(), Confidence::Exact);
+    assert!(!distro.detection_source().unwrap().is_symlink());
+}
+
+#[test]
+fn dangling_symlink_falls_back() {
+    let fs = MockFs::default()
+        .with_link("/etc/os-release", "/usr/lib/os-release.new")
+        .with_file("/usr/lib/os-release", "NAME=Fedora\nID=fedora");
+
+    let distro = identify_with_fs(&fs).unwrap();
+
+    assert_eq!(distro.id(), &DistroId::Fedora);
+    assert_eq!(distro.detection_source().unwrap().path().to_str(), Some("/usr/lib/os-release"));
+}
+
+#[test]
+fn lsb_release_is_used() {
+    let fs = MockFs::default()
+        .with_file("/etc/lsb-release", "DISTRIB_ID=Ubuntu\nDISTRIB_RELEASE=22.04\nDISTRIB_CODENAME=jammy");
+
+    let distro = identify_with_fs(&fs).unwrap();
+
+    assert_eq!(distro.id(), &DistroId::Ubuntu);
+    assert_eq!(distro.version_id(), Some("22.04"));
+}
+
+#[test]
+fn legacy_files_are_used() {
+    let fs = MockFs::default()
+        .with_file("/etc/debian_version", "12.7\n");
+
+    let distro = identify_with_fs(&fs).unwrap();
+
+    assert_eq!(distro.id(), &DistroId::Debian);
+    assert_eq!(distro.confidence(), Confidence::Heuristic);
+}
+
+#[test]
+fn package_database_is_used() {
+    let fs = MockFs::default()
+        .with_file("/var/lib/pacman/local/ALPM_DB_VERSION", "9\n");
+
+    let distro = identify_with_fs(&fs).unwrap();
+
+    assert_eq!(distro.id(), &DistroId::Arch);
+    assert_eq!(distro.confidence(), Confidence::Heuristic);
+
+    // Legacy release files are more specific
+    let fs = fs.with_file("/etc/redhat-release", "Red Hat Enterprise Linux release 9.4\n")
+        .with_file("/var/lib/rpm/rpmdb.sqlite", "");
+
+    assert_eq!(identify_with_fs(&fs).unwrap().id(), &DistroId::RHEL);
+}
+
+#[test]
+fn drop_ins_are_merged() {
+    let fs = MockFs::default()
+        .with_file("/etc/os-release", "NAME=Debian\nID=debian\nVERSION_ID=12")
+        .with_file("/etc/os-release.d/20-version.conf", "VERSION_ID=12.7")
+        .with_file("/etc/os-release.d/10-vendor.conf", "NAME=\"Acme Appliance\"\nVERSION_ID=11")
+        .with_file("/etc/os-release.d/README", "ID=ubuntu");
+
+    let distro = identify_with_fs(&fs).unwrap();
+
+    assert_eq!(distro.id(), &DistroId::Debian);
+    assert_eq!(distro.name(), "Acme Appliance");
+    assert_eq!(distro.version_id(), Some("12.7"));
+
+    assert_eq!(distro.detection_source().unwrap().drop_ins(), &[
+        PathBuf::from("/etc/os-release.d/10-vendor.conf"),
+        PathBuf::from("/etc/os-release.d/20-version.conf")
+    ]);
+}
+
+#[test]
+fn distroless_images_are_detected() {
+    // gcr.io/distroless/static-debian12 keeps per-package files in dpkg/status.d
+    let fs = MockFs::default()
+        .with_file("/etc/os-release", "PRETTY_NAME=\"Distroless\"\nNAME=\"Debian GNU/Linux\"\nID=\"debian\"\nVERSION_ID=\"12\"\nHOME_URL=\"https://github.com/GoogleContainerTools/distroless\"")
+        .with_file("/var/lib/dpkg/status.d/base", "Package: base-files");
+
+    let distro = identify_with_fs(&fs).unwrap();
+
+    assert!(distro.is_distroless_with_fs(&fs));
+    assert_eq!(distro.purl_namespace().as_deref(), Some("deb/debian"));
+
+    // Immutable distros don't have package databases by design
+    let fs = MockFs::default()
+        .with_file("/etc/os-release", "NAME=\"Flatcar Container Linux by Kinvolk\"\nID=flatcar");
+
+    let distro = identify_with_fs(&fs).unwrap();
+
+    assert!(!distro.is_distroless_with_fs(&fs));
+    assert_eq!(distro.purl_namespace(), None);
+}
+
+#[test]
+fn symlink_loops_fail() {
+    let fs = MockFs::default()
+        .with_link("/etc/os-release", "os-release.d")
+        .with_link("/etc/os-release.d", "/etc/os-release");
+
+    assert!(fs.read_to_string("/etc/os-release".as_ref()).is_err());
+    assert!(!fs.exists("/etc/os-release".as_ref()));
+    assert_eq!(identify_with_fs(&fs), None);
+}
+
+#[cfg(feature = "system-info")]
+#[test]
+fn dmi_is_read_from_fs() {
+    use whatadistro::SystemInfo;
+
+    let fs = MockFs::default()
+        .with_file("/sys/class/dmi/id/sys_vendor", "Amazon EC2\n")
+        .with_file("/sys/class/dmi/id/product_name", "m5.large\n")
+        .with_file("/sys/class/dmi/id/board_vendor", "  \n");
+
+    let dmi = SystemInfo::dmi_with_fs(&fs).unwrap();
+
+    assert_eq!(dmi.sys_vendor(), Some("Amazon EC2"));
+    assert_eq!(dmi.product_name(), Some("m5.large"));
+    assert_eq!(dmi.board_vendor(), None);
+
+    assert_eq!(SystemInfo::dmi_with_fs(&MockFs::default()), None);
+}
+
+#[cfg(feature = "system-info")]
+#[test]
+fn virtualization_is_read_from_fs() {
+    use whatadistro::{Virtualization, detect_virtualization_with_fs};
+
+    let dmi = |vendor: &str| MockFs::default()
+        .with_file("/sys/class/dmi/id/sys_vendor", vendor);
+
+    assert_eq!(detect_virtualization_with_fs(&dmi("QEMU\n")), Virtualization::Qemu);
+    assert_eq!(detect_virtualization_with_fs(&dmi("innotek GmbH\n")), Virtualization::VirtualBox);
+    assert_eq!(detect_virtualization_with_fs(&dmi("Dell Inc.\n")), Virtualization::BareMetal);
+
+    let fs = MockFs::default()
+        .with_file("/sys/hypervisor/type", "xen\n");
+
+    assert_eq!(detect_virtualization_with_fs(&fs), Virtualization::Xen);
+
+    let fs = MockFs::default()
+        .with_file("/proc/cpuinfo", "processor\t: 0\nflags\t\t: fpu vme de pse hypervisor lahf_lm\n");
+
+    assert_eq!(detect_virtualization_with_fs(&fs), Virtualization::Unknown);
+
+    let fs = MockFs::default()
+        .with_file("/proc/cpuinfo", "processor\t: 0\nflags\t\t: fpu vme de pse lahf_lm\n");
+
+    assert_eq!(detect_virtualization_with_fs(&fs), Virtualization::BareMetal);
+}
+
+#[test]
+fn directories_are_listed() {
+    let fs = MockFs::default()
+        .with_file("/sys/class/drm/card0/device/vendor", "0x1002\n")
+        .with_file("/sys/class/drm/card0/device/device", "0x73bf\n")
+        .with_file("/sys/class/drm/version", "drm 1.1.0\n");
+
+    assert!(fs.exists("/sys/class/drm/card0".as_ref()));
+    assert!(!fs.exists("/sys/class/drm/card1".as_ref()));
+
+    assert_eq!(fs.read_dir("/sys/class/drm".as_ref()).unwrap(), [
+        PathBuf::from("/sys/class/drm/card0"),
+        PathBuf::from("/sys/class/drm/version")
+    ]);
+}
+
+#[test]
+fn sources_are_read_from_fs() {
+    use whatadistro::{Source, identify_all_with_fs};
+
+    let fs = MockFs::default()
+        .with_file("/etc/os-release", "NAME=\"Linux Mint\"\nID=linuxmint")
+        .with_file("/usr/lib/os-release", "NAME=\"Linux Mint\"\nID=linuxmint")
+        .with_file("/etc/lsb-release", "DISTRIB_ID=Ubuntu\nDISTRIB_RELEASE=24.04");
+
+    let sources = identify_all_with_fs(&fs).into_iter()
+        .map(|(source, distro)| (source, distro.id().clone()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(sources, [
+        (Source::EtcOsRelease, DistroId::Mint),
+        (Source::UsrLibOsRelease, DistroId::Mint),
+        (Source::LsbRelease, DistroId::Ubuntu)
+    ]);
+}
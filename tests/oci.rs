@@ -0,0 +1,103 @@
+#![cfg(feature = "oci")]
+
+use whatadistro::DistroId;
+
+/// Build tar entry with the given name, type and content
+fn entry(name: &str, kind: u8, content: &[u8]) -> Vec<u8> {
+    let mut header = [0u8; 512];
+
+    header[..name.len()].copy_from_slice(name.as_bytes());
+    header[100..107].copy_from_slice(b"0000644");
+    header[124..135].copy_from_slice(format!("{:011o}", content.len()).as_bytes());
+    header[156] = kind;
+    header[257..263].copy_from_slice(b"ustar\0");
+
+    let mut entry = header.to_vec();
+
+    entry.extend_from_slice(content);
+    entry.resize(entry.len() + (512 - content.len() % 512) % 512, 0);
+
+    entry
+}
+
+#[test]
+fn etc_os_release_is_preferred() {
+    let mut tar = entry("./usr/lib/os-release", b'0', b"NAME=Debian\nID=debian");
+
+    tar.extend(entry("./etc/os-release", b'0', b"NAME=Ubuntu\nID=ubuntu"));
+    tar.extend([0; 1024]);
+
+    let distro = whatadistro::identify_tar(tar.as_slice()).unwrap().unwrap();
+
+    assert_eq!(distro.id(), &DistroId::Ubuntu);
+}
+
+#[test]
+fn symlinked_os_release_is_resolved() {
+    let mut tar = entry("etc/os-release", b'2', b"");
+
+    tar.extend(entry("usr/lib/os-release", b'0', b"NAME=Fedora\nID=fedora"));
+    tar.extend([0; 1024]);
+
+    let distro = whatadistro::identify_tar(tar.as_slice()).unwrap().unwrap();
+
+    assert_eq!(distro.id(), &DistroId::Fedora);
+}
+
+#[test]
+fn stored_gzip_layer() {
+    let tar = entry("etc/os-release", b'0', b"NAME=Arch\nID=arch");
+
+    // gzip header and a single stored deflate block
+    let mut gzip = vec![0x1f, 0x8b, 8, 0, 0, 0, 0, 0, 0, 3, 1];
+
+    gzip.extend((tar.len() as u16).to_le_bytes());
+    gzip.extend((!(tar.len() as u16)).to_le_bytes());
+    gzip.extend(&tar);
+    gzip.extend([0; 8]);
+
+    let distro = whatadistro::identify_tar(gzip.as_slice()).unwrap().unwrap();
+
+    assert_eq!(distro.id(), &DistroId::Arch);
+}
+
+#[test]
+fn missing_os_release() {
+    let tar = entry("etc/hostname", b'0', b"localhost");
+
+    assert_eq!(whatadistro::identify_tar(tar.as_slice()).unwrap(), None);
+}
+
+#[cfg(unix)]
+#[test]
+fn unpacked_rootfs_symlinks_are_not_followed() {
+    use std::os::unix::fs::symlink;
+
+    let dir = std::env::temp_dir().join(format!("whatadistro-oci-{}", std::process::id()));
+    let host = dir.join("host");
+    let rootfs = dir.join("bundle/rootfs");
+
+    std::fs::create_dir_all(host.join("os-release.d")).unwrap();
+    std::fs::create_dir_all(rootfs.join("etc")).unwrap();
+    std::fs::create_dir_all(rootfs.join("usr/lib")).unwrap();
+
+    std::fs::write(host.join("os-release"), "NAME=Fedora\nID=fedora").unwrap();
+    std::fs::write(host.join("os-release.d/50-host.conf"), "VARIANT_ID=host").unwrap();
+
+    symlink(host.join("os-release"), rootfs.join("etc/os-release")).unwrap();
+    symlink(host.join("os-release"), rootfs.join("usr/lib/os-release")).unwrap();
+
+    assert_eq!(whatadistro::identify_oci_layout(dir.join("bundle")).unwrap(), None);
+
+    std::fs::remove_file(rootfs.join("usr/lib/os-release")).unwrap();
+    std::fs::write(rootfs.join("usr/lib/os-release"), "NAME=Debian\nID=debian").unwrap();
+
+    symlink(host.join("os-release.d"), rootfs.join("usr/lib/os-release.d")).unwrap();
+
+    let distro = whatadistro::identify_oci_layout(dir.join("bundle")).unwrap().unwrap();
+
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(distro.id(), &DistroId::Debian);
+    assert_eq!(distro.field("VARIANT_ID"), None);
+}
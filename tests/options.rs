@@ -0,0 +1,30 @@
+use whatadistro::{Distro, DistroId, IdentifyOptions};
+
+#[test]
+fn extra_id_like_extends_parents() {
+    // Only one test lives in this binary, so the override can be changed safely
+    whatadistro::set_override(Distro::from_os_release("NAME=Pop!_OS\nID=pop\nID_LIKE=\"ubuntu debian\"").unwrap());
+
+    let options = IdentifyOptions::default()
+        .extra_id_like(DistroId::Debian)
+        .extra_id_like(DistroId::Arch);
+
+    let distro = whatadistro::identify_with(&options).unwrap();
+
+    assert_eq!(distro.id_like_raw(), &[DistroId::Ubuntu, DistroId::Debian, DistroId::Arch]);
+    assert_eq!(distro.field("ID_LIKE"), Some("ubuntu debian arch"));
+
+    whatadistro::set_override(Distro::from_os_release("NAME=Linux\nID=linux").unwrap());
+
+    let options = IdentifyOptions::default()
+        .override_id("linux")
+        .extra_id_like(DistroId::Debian);
+
+    let distro = whatadistro::identify_with(&options).unwrap();
+
+    assert_eq!(distro.id_like_raw(), &[DistroId::Debian]);
+    assert_eq!(distro.primary_parent(), Some(&DistroId::Debian));
+    assert!(distro.similar_ids().contains(&DistroId::Debian));
+
+    whatadistro::clear_override();
+}
@@ -0,0 +1,16 @@
+use whatadistro::{IdentifyError, OperatingSystem};
+
+#[cfg(target_os = "linux")]
+#[test]
+fn linux_is_supported() {
+    assert_ne!(whatadistro::try_identify().err(), Some(IdentifyError::UnsupportedPlatform));
+    assert!(!matches!(OperatingSystem::current(), Ok(OperatingSystem::Other(_))));
+}
+
+#[cfg(all(not(target_os = "linux"), not(feature = "static-target")))]
+#[test]
+fn other_platforms_are_unsupported() {
+    assert_eq!(whatadistro::identify(), None);
+    assert_eq!(whatadistro::try_identify(), Err(IdentifyError::UnsupportedPlatform));
+    assert!(!matches!(OperatingSystem::current(), Ok(OperatingSystem::Linux(_))));
+}
@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+
+use whatadistro::{Distro, DistroId, DistroFamily};
+
+/// Simple xorshift generator to produce reproducible pseudo-random inputs
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        self.0
+    }
+
+    fn id(&mut self) -> DistroId {
+        // Mix known ids and arbitrary ones
+        if self.next() % 2 == 0 {
+            return DistroId::ALL[(self.next() % DistroId::ALL.len() as u64) as usize].clone();
+        }
+
+        let len = 1 + self.next() % 12;
+
+        let id = (0..len)
+            .map(|_| char::from(b"abcdefghijklmnopqrstuvwxyz0123456789-"[(self.next() % 37) as usize]))
+            .collect::<String>();
+
+        DistroId::from(id)
+    }
+}
+
+#[test]
+fn similarity_is_reflexive() {
+    let mut rng = Rng(0x853c_49e6_748f_ea9b);
+
+    for _ in 0..10_000 {
+        let id = rng.id();
+
+        assert!(id.is_similar(&id), "{id:?} is not similar to itself");
+        assert_eq!(id.list_similar().first(), Some(&id), "{id:?} is not the first similar distro");
+    }
+}
+
+#[test]
+fn similarity_is_symmetric() {
+    let mut rng = Rng(0xda3e_39cb_94b9_5bdb);
+
+    for _ in 0..10_000 {
+        let a = rng.id();
+        let b = rng.id();
+
+        assert_eq!(a.is_similar(&b), b.is_similar(&a), "{a:?} and {b:?} similarity is not symmetric");
+    }
+}
+
+#[test]
+fn similarity_follows_families() {
+    for a in DistroId::ALL {
+        let similar = a.list_similar();
+
+        assert_eq!(similar.iter().collect::<HashSet<_>>().len(), similar.len(), "{a:?} similar distros have duplicates");
+
+        for b in DistroId::ALL {
+            let same_family = a.family() == b.family() && a.family() != DistroFamily::Other;
+
+            assert_eq!(a.is_similar(b), a == b || same_family, "{a:?} and {b:?} similarity doesn't match their families");
+        }
+
+        for b in &similar {
+            assert_eq!(a.family(), b.family(), "{a:?} is similar to {b:?} from another family");
+        }
+    }
+}
+
+#[test]
+fn known_variants_round_trip() {
+    for id in DistroId::ALL {
+        assert_eq!(&DistroId::from(id.to_string()), id, "{id:?} doesn't round-trip through Display");
+        assert_eq!(&DistroId::from(id.as_str()), id, "{id:?} doesn't round-trip through as_str");
+        assert_eq!(&id.to_string().parse::<DistroId>().unwrap(), id, "{id:?} doesn't round-trip through FromStr");
+    }
+
+    let families = DistroFamily::ALL.iter()
+        .map(ToString::to_string)
+        .collect::<HashSet<_>>();
+
+    assert_eq!(families.len(), DistroFamily::ALL.len(), "family names must be unique");
+
+    for family in DistroFamily::ALL {
+        assert!(DistroId::ALL.iter().any(|id| id.family() == *family), "{family:?} has no known distros");
+    }
+}
+
+#[test]
+fn parsed_distros_match_themselves() {
+    for id in DistroId::ALL {
+        let distro = Distro::from_os_release(&format!("NAME=\"{}\"\nID={}", id.name(), id.as_str()))
+            .unwrap_or_else(|| panic!("failed to parse {id:?}"));
+
+        assert!(distro.is_similar(id), "{id:?} distro is not similar to its id");
+        assert_eq!(distro.best_match(DistroId::ALL).as_ref(), Some(id), "{id:?} distro doesn't match itself best");
+
+        if let Some(best) = distro.best_match(&id.list_similar()[1..]) {
+            assert!(distro.is_similar(&best), "{id:?} best match {best:?} is not similar");
+        }
+    }
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "packages")]
+
+use whatadistro::repos;
+
+#[test]
+fn pacman_stock_header_is_not_a_repo() {
+    let repos = repos::parse_pacman_conf(r#"
+[options]
+HoldPkg     = pacman glibc
+Architecture = auto
+
+#
+# REPOSITORIES
+#   - can be defined here or included from another file
+#   - pacman will search repositories in the order defined here
+#
+# Repository entries are of the format:
+#       [repo-name]
+#       Server = ServerName
+#       Include = IncludePath
+#
+# The header [repo-name] is crucial - it must be present and
+# uncommented to enable the repo.
+#
+
+#[core-testing]
+#Include = /etc/pacman.d/mirrorlist
+
+[core]
+Include = /etc/pacman.d/mirrorlist
+
+# An example of a custom package repository.  See the pacman manpage for
+# tips on creating your own repositories.
+#[custom]
+#SigLevel = Optional TrustAll
+#Server = file:///home/custompkgs
+"#);
+
+    let names = repos.iter().map(|repo| repo.name()).collect::<Vec<_>>();
+
+    assert_eq!(names, ["core-testing", "core", "custom"]);
+    assert!(!repos[0].is_enabled());
+    assert!(repos[1].is_enabled());
+    assert_eq!(repos[2].url(), Some("file:///home/custompkgs"));
+}
@@ -0,0 +1,118 @@
+use std::borrow::Cow;
+
+use whatadistro::{DistroId, DistroFamily};
+
+/// Simple xorshift generator to produce reproducible pseudo-random inputs
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        self.0
+    }
+
+    fn string(&mut self) -> String {
+        const PARTS: &[&str] = &[
+            "a", "e", "n", "s", "x", "A", "Z", "0", "9", "-", "_", ".", " ", "é", "ü",
+            "arch", "debian", "suse", "open", "mint", "linux", "tumbleweed", "rhel"
+        ];
+
+        let len = self.next() % 6;
+
+        (0..len)
+            .map(|_| PARTS[(self.next() % PARTS.len() as u64) as usize])
+            .collect()
+    }
+}
+
+#[test]
+fn known_ids_round_trip() {
+    for (alias, id) in DistroId::KNOWN_IDS {
+        assert_eq!(&DistroId::from(alias), id, "{alias} is parsed into wrong distro");
+
+        let canonical = id.known_id()
+            .unwrap_or_else(|| panic!("{id:?} doesn't have canonical id"));
+
+        assert_eq!(&DistroId::from(canonical), id, "{canonical} doesn't round-trip");
+        assert_eq!(&DistroId::from(id.to_string()), id, "{id:?} doesn't round-trip through Display");
+        assert_eq!(id.as_str(), id.to_string());
+    }
+}
+
+#[test]
+fn known_ids_are_case_insensitive() {
+    for (alias, id) in DistroId::KNOWN_IDS {
+        assert_eq!(&DistroId::from(alias.to_ascii_uppercase()), id);
+    }
+}
+
+#[test]
+fn arbitrary_ids_round_trip() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..10_000 {
+        let str = rng.string();
+        let id = DistroId::from(&str);
+
+        assert_eq!(DistroId::from(id.to_string()), id, "{str:?} doesn't round-trip");
+        assert_eq!(DistroId::from(id.as_str()), id, "{str:?} doesn't round-trip");
+
+        if let DistroId::Other(other) = &id {
+            assert_eq!(other, &str);
+            assert_eq!(id.known_id(), None);
+        }
+    }
+}
+
+#[test]
+fn string_types_convert_equally() {
+    for (alias, id) in DistroId::KNOWN_IDS {
+        let string = alias.to_string();
+
+        assert_eq!(&DistroId::from(string.as_str()), id);
+        assert_eq!(&DistroId::from(&string), id);
+        assert_eq!(&DistroId::from(Cow::Borrowed(*alias)), id);
+        assert_eq!(&DistroId::from(Cow::<str>::Owned(string.clone())), id);
+        assert_eq!(&string.parse::<DistroId>().unwrap(), id);
+        assert_eq!(&DistroId::from(id), id);
+    }
+}
+
+#[test]
+fn family_graph_is_consistent() {
+    let dot = DistroFamily::to_dot();
+    let json = DistroFamily::to_json();
+
+    for id in DistroId::ALL {
+        let node = format!("\"{}\" [label=", id.as_str());
+        let entry = format!("{{\"id\":\"{}\",", id.as_str());
+
+        assert_eq!(dot.matches(&node).count(), 1, "{id:?} must be exported once");
+        assert_eq!(json.matches(&entry).count(), 1, "{id:?} must be exported once");
+
+        if let Some(parent) = id.parent() {
+            assert_eq!(parent.family(), id.family(), "{id:?} parent belongs to another family");
+        }
+    }
+}
+
+#[test]
+fn known_ids_are_parsed_strictly() {
+    for (alias, id) in DistroId::KNOWN_IDS {
+        assert_eq!(&DistroId::try_from_known(alias).unwrap(), id);
+    }
+
+    for (typo, expected) in [("ubunut", DistroId::Ubuntu), ("archh", DistroId::Arch), ("opensuse_lep", DistroId::OpenSUSE), ("gento", DistroId::Gentoo)] {
+        let err = DistroId::try_from_known(typo).unwrap_err();
+
+        assert_eq!(err.suggestions().first(), Some(&expected), "wrong suggestion for {typo}");
+    }
+
+    let err = DistroId::try_from_known("definitely-not-a-distro").unwrap_err();
+
+    assert!(err.suggestions().is_empty());
+    assert_eq!(err.id(), "definitely-not-a-distro");
+}
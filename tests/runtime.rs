@@ -0,0 +1,35 @@
+#![cfg(feature = "system-info")]
+
+use whatadistro::{Distro, Runtime, MockFs};
+
+#[test]
+fn glibc_soname_version() {
+    let fs = MockFs::default()
+        .with_file("/usr/lib64/libc-2.17.so", "")
+        .with_link("/usr/lib64/libc.so.6", "libc-2.17.so");
+
+    assert_eq!(Runtime::Glibc.detect_version_with_fs(&fs).as_deref(), Some("2.17"));
+}
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+#[test]
+fn glibc_detected_without_commands() {
+    let distro = Distro::from_os_release("NAME=Debian\nID=debian").unwrap();
+
+    let requirements = distro.runtime_requirements(&[(Runtime::Glibc, ">=2.17")]);
+
+    assert_eq!(requirements[0].is_satisfied(), Some(true));
+    assert!(requirements[0].version().is_some());
+}
+
+#[cfg(not(feature = "detect-exec"))]
+#[test]
+fn python_unknown_without_commands() {
+    let distro = Distro::from_os_release("NAME=Debian\nID=debian").unwrap();
+
+    let requirements = distro.runtime_requirements(&[(Runtime::Python3, ">=3.8")]);
+
+    assert_eq!(requirements[0].is_satisfied(), None);
+    assert_eq!(requirements[0].version(), None);
+    assert_eq!(requirements[0].package(), Some("python3"));
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "trace-hook")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use whatadistro::MockFs;
+use whatadistro::trace::{self, Event};
+
+static EVENTS: AtomicUsize = AtomicUsize::new(0);
+
+fn handler(event: &Event) {
+    assert!(event.target.starts_with("whatadistro"));
+
+    EVENTS.fetch_add(1, Ordering::SeqCst);
+}
+
+#[test]
+fn events_are_forwarded_to_handler() {
+    let fs = MockFs::default()
+        .with_file("/etc/arch-release", "");
+
+    trace::set_handler(handler);
+
+    let _ = whatadistro::identify_with_fs(&fs);
+
+    let events = EVENTS.load(Ordering::SeqCst);
+
+    assert!(events > 0);
+
+    trace::clear_handler();
+
+    let _ = whatadistro::identify_with_fs(&fs);
+
+    assert_eq!(EVENTS.load(Ordering::SeqCst), events);
+}